@@ -186,12 +186,9 @@ fn main() {
                     implot::Plot::new("A histogram").build(plot_ui, |_| {
                         PlotHistogram::new("Histogram")
                             .with_flags(PlotHistogramFlags::HORIZONTAL)
-                            .plot(
-                                &[0.5, 0.5, 1.5, 1.5, 1.5, 2.5, 3.5, 3.5, 5.5],
-                                implot::PlotBin::Auto(PlotBinMethod::Sturges),
-                                Some(0.3),
-                                None,
-                            );
+                            .with_bins(implot::PlotBin::Auto(PlotBinMethod::Sturges))
+                            .with_bar_scale(0.3)
+                            .plot(&[0.5, 0.5, 1.5, 1.5, 1.5, 2.5, 3.5, 3.5, 5.5]);
                     });
                 });
 
@@ -0,0 +1,75 @@
+//! Derive macros for implot. Use these through the `derive` feature of the `implot` crate
+//! rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Derives `implot::Plottable` for a struct with named fields. Every `f64` field annotated
+/// with `#[plot]` can then be plotted straight from a slice of the struct, without copying it
+/// into a separate vector first. For each such field `name`, an associated function
+/// `name_strided(data: &[Self]) -> implot::StridedData` is generated as well.
+#[proc_macro_derive(Plottable, attributes(plot))]
+pub fn derive_plottable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(
+                    name,
+                    "Plottable can only be derived for structs with named fields",
+                )
+                .to_compile_error()
+                .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(name, "Plottable can only be derived for structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let plot_fields: Vec<_> = fields
+        .iter()
+        .filter(|field| field.attrs.iter().any(|attr| attr.path().is_ident("plot")))
+        .filter_map(|field| field.ident.clone())
+        .collect();
+    let field_names: Vec<_> = plot_fields.iter().map(|field| field.to_string()).collect();
+    let accessors: Vec<_> = plot_fields
+        .iter()
+        .map(|field| format_ident!("{}_strided", field))
+        .collect();
+
+    let expanded = quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #(
+                /// Strided view of this field across the given slice, for plotting.
+                pub fn #accessors(data: &[Self]) -> implot::StridedData<'_> {
+                    // A plain struct field is at the same offset in every element
+                    unsafe { implot::StridedData::from_field(data, |item| &item.#plot_fields) }
+                }
+            )*
+        }
+
+        impl #impl_generics implot::Plottable for #name #type_generics #where_clause {
+            fn plot_fields() -> &'static [&'static str] {
+                &[#(#field_names),*]
+            }
+
+            fn plot_field<'a>(data: &'a [Self], field: &str) -> Option<implot::StridedData<'a>> {
+                match field {
+                    #(
+                        #field_names => Some(Self::#accessors(data)),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    };
+    expanded.into()
+}
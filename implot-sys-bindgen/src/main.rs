@@ -102,11 +102,13 @@ fn main() {
         .default_enum_style(bindgen::EnumVariation::Rust {
             non_exhaustive: false,
         })
-        .rustified_enum("ImPlotCol_")
-        // See https://github.com/rust-lang/rust-bindgen/issues/1188
+        // IM_ASSERT panics in implot_rs_assert_failed, which has to be able to unwind back into
+        // Rust through the ImPlot functions
+        .override_abi(bindgen::Abi::CUnwind, "ImPlot_.*")
+        // See https://github.com/rust-lang/rust-bindgen/issues/1188. These are defined per target
+        // in implot-sys' lib.rs instead.
         .blocklist_type("time_t")
-        .raw_line("pub type time_t = libc::time_t;")
-        .raw_line("pub type tm = libc::tm;")
+        .blocklist_type("tm")
         .generate()
         .expect("Unable to generate bindings");
 
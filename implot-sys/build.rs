@@ -14,6 +14,11 @@ const CPP_FILES: &[&str] = &[
 
 const IMPLOT_INCLUDE_DIRECTORIES: &[&str] = &["third-party/cimplot/implot/"];
 
+// Header redefining IM_ASSERT to call back into Rust, so that failed asserts turn into panics
+// with some context instead of aborts. It is force-included so that it is seen before imgui.h
+// defines its fallback IM_ASSERT.
+const ASSERT_HEADER: &str = "include/implot_rs_assert.h";
+
 fn assert_file_exists(path: &str) -> io::Result<()> {
     match fs::metadata(path) {
         Ok(_) => Ok(()),
@@ -57,6 +62,25 @@ fn main() -> io::Result<()> {
     build.flag_if_supported("-Wno-return-type-c-linkage");
     build.flag_if_supported("-Wno-unused-parameter");
     build.flag_if_supported("-std=c++11");
+
+    // The assert handler panics, which unwinds through the C++ frames that asserted.
+    assert_file_exists(ASSERT_HEADER)?;
+    if build.get_compiler().is_like_msvc() {
+        build.flag(&format!("/FI{}", ASSERT_HEADER));
+        build.flag("/EHsc");
+    } else {
+        build.flag("-include").flag(ASSERT_HEADER);
+        build.flag_if_supported("-fexceptions");
+    }
+    println!("cargo:rerun-if-changed={}", ASSERT_HEADER);
+
+    // em++ is clang based, so the flags above apply, but it only lets exceptions (and hence
+    // panics from the assert handler) propagate through C++ frames when catching is enabled,
+    // as it is for Rust code on this target.
+    if env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("emscripten") {
+        build.flag("-sDISABLE_EXCEPTION_CATCHING=0");
+    }
+
     for path in CPP_FILES {
         assert_file_exists(path)?;
         build.file(path);
@@ -0,0 +1,16 @@
+// Force-included into every ImPlot translation unit by build.rs. Routes IM_ASSERT failures to
+// implot_rs_assert_failed() in implot-sys/src/lib.rs, which panics with the failed expression,
+// its location and the current plot instead of abort()ing without any context.
+#pragma once
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+void implot_rs_assert_failed(const char* expr, const char* file, int line);
+
+#ifdef __cplusplus
+}
+#endif
+
+#define IM_ASSERT(_EXPR) ((_EXPR) ? (void)0 : implot_rs_assert_failed(#_EXPR, __FILE__, __LINE__))
@@ -1,8 +1,6 @@
 /* automatically generated by rust-bindgen 0.69.4 */
 
 pub use imgui_sys::*;
-pub type time_t = libc::time_t;
-pub type tm = libc::tm;
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -8849,32 +8847,32 @@ extern "C" {
 extern "C" {
     pub fn ImPlotInputMap_destroy(self_: *mut ImPlotInputMap);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CreateContext() -> *mut ImPlotContext;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_DestroyContext(ctx: *mut ImPlotContext);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetCurrentContext() -> *mut ImPlotContext;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetCurrentContext(ctx: *mut ImPlotContext);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetImGuiContext(ctx: *mut ImGuiContext);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginPlot(
         title_id: *const ::std::os::raw::c_char,
         size: ImVec2,
         flags: ImPlotFlags,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_EndPlot();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginSubplots(
         title_id: *const ::std::os::raw::c_char,
         rows: ::std::os::raw::c_int,
@@ -8885,33 +8883,33 @@ extern "C" {
         col_ratios: *mut f32,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_EndSubplots();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxis(
         axis: ImAxis,
         label: *const ::std::os::raw::c_char,
         flags: ImPlotAxisFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxisLimits(axis: ImAxis, v_min: f64, v_max: f64, cond: ImPlotCond);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxisLinks(axis: ImAxis, link_min: *mut f64, link_max: *mut f64);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxisFormat_Str(axis: ImAxis, fmt: *const ::std::os::raw::c_char);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxisFormat_PlotFormatter(
         axis: ImAxis,
         formatter: ImPlotFormatter,
         data: *mut ::std::os::raw::c_void,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxisTicks_doublePtr(
         axis: ImAxis,
         values: *const f64,
@@ -8920,7 +8918,7 @@ extern "C" {
         keep_default: bool,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxisTicks_double(
         axis: ImAxis,
         v_min: f64,
@@ -8930,10 +8928,10 @@ extern "C" {
         keep_default: bool,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxisScale_PlotScale(axis: ImAxis, scale: ImPlotScale);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxisScale_PlotTransform(
         axis: ImAxis,
         forward: ImPlotTransform,
@@ -8941,13 +8939,13 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxisLimitsConstraints(axis: ImAxis, v_min: f64, v_max: f64);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxisZoomConstraints(axis: ImAxis, z_min: f64, z_max: f64);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxes(
         x_label: *const ::std::os::raw::c_char,
         y_label: *const ::std::os::raw::c_char,
@@ -8955,28 +8953,28 @@ extern "C" {
         y_flags: ImPlotAxisFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupAxesLimits(x_min: f64, x_max: f64, y_min: f64, y_max: f64, cond: ImPlotCond);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupLegend(location: ImPlotLocation, flags: ImPlotLegendFlags);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupMouseText(location: ImPlotLocation, flags: ImPlotMouseTextFlags);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupFinish();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetNextAxisLimits(axis: ImAxis, v_min: f64, v_max: f64, cond: ImPlotCond);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetNextAxisLinks(axis: ImAxis, link_min: *mut f64, link_max: *mut f64);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetNextAxisToFit(axis: ImAxis);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetNextAxesLimits(
         x_min: f64,
         x_max: f64,
@@ -8985,10 +8983,10 @@ extern "C" {
         cond: ImPlotCond,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetNextAxesToFit();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_FloatPtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f32,
@@ -9000,7 +8998,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_doublePtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f64,
@@ -9012,7 +9010,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_S8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -9024,7 +9022,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_U8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -9036,7 +9034,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_S16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -9048,7 +9046,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_U16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -9060,7 +9058,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_S32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -9072,7 +9070,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_U32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -9084,7 +9082,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_S64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -9096,7 +9094,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_U64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -9108,7 +9106,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_FloatPtrFloatPtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -9119,7 +9117,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_doublePtrdoublePtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -9130,7 +9128,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_S8PtrS8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -9141,7 +9139,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_U8PtrU8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -9152,7 +9150,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_S16PtrS16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -9163,7 +9161,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_U16PtrU16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -9174,7 +9172,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_S32PtrS32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -9185,7 +9183,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_U32PtrU32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -9196,7 +9194,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_S64PtrS64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -9207,7 +9205,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLine_U64PtrU64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -9218,7 +9216,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotLineG(
         label_id: *const ::std::os::raw::c_char,
         getter: ImPlotPoint_getter,
@@ -9227,7 +9225,7 @@ extern "C" {
         flags: ImPlotLineFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_FloatPtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f32,
@@ -9239,7 +9237,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_doublePtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f64,
@@ -9251,7 +9249,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_S8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -9263,7 +9261,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_U8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -9275,7 +9273,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_S16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -9287,7 +9285,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_U16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -9299,7 +9297,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_S32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -9311,7 +9309,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_U32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -9323,7 +9321,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_S64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -9335,7 +9333,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_U64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -9347,7 +9345,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_FloatPtrFloatPtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -9358,7 +9356,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_doublePtrdoublePtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -9369,7 +9367,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_S8PtrS8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -9380,7 +9378,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_U8PtrU8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -9391,7 +9389,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_S16PtrS16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -9402,7 +9400,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_U16PtrU16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -9413,7 +9411,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_S32PtrS32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -9424,7 +9422,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_U32PtrU32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -9435,7 +9433,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_S64PtrS64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -9446,7 +9444,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatter_U64PtrU64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -9457,7 +9455,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotScatterG(
         label_id: *const ::std::os::raw::c_char,
         getter: ImPlotPoint_getter,
@@ -9466,7 +9464,7 @@ extern "C" {
         flags: ImPlotScatterFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_FloatPtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f32,
@@ -9478,7 +9476,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_doublePtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f64,
@@ -9490,7 +9488,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_S8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -9502,7 +9500,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_U8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -9514,7 +9512,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_S16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -9526,7 +9524,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_U16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -9538,7 +9536,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_S32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -9550,7 +9548,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_U32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -9562,7 +9560,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_S64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -9574,7 +9572,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_U64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -9586,7 +9584,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_FloatPtrFloatPtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -9597,7 +9595,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_doublePtrdoublePtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -9608,7 +9606,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_S8PtrS8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -9619,7 +9617,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_U8PtrU8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -9630,7 +9628,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_S16PtrS16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -9641,7 +9639,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_U16PtrU16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -9652,7 +9650,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_S32PtrS32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -9663,7 +9661,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_U32PtrU32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -9674,7 +9672,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_S64PtrS64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -9685,7 +9683,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairs_U64PtrU64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -9696,7 +9694,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStairsG(
         label_id: *const ::std::os::raw::c_char,
         getter: ImPlotPoint_getter,
@@ -9705,7 +9703,7 @@ extern "C" {
         flags: ImPlotStairsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_FloatPtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f32,
@@ -9718,7 +9716,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_doublePtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f64,
@@ -9731,7 +9729,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -9744,7 +9742,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -9757,7 +9755,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -9770,7 +9768,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -9783,7 +9781,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -9796,7 +9794,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -9809,7 +9807,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -9822,7 +9820,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -9835,7 +9833,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_FloatPtrFloatPtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -9847,7 +9845,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_doublePtrdoublePtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -9859,7 +9857,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S8PtrS8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -9871,7 +9869,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U8PtrU8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -9883,7 +9881,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S16PtrS16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -9895,7 +9893,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U16PtrU16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -9907,7 +9905,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S32PtrS32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -9919,7 +9917,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U32PtrU32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -9931,7 +9929,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S64PtrS64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -9943,7 +9941,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U64PtrU64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -9955,7 +9953,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_FloatPtrFloatPtrFloatPtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -9967,7 +9965,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_doublePtrdoublePtrdoublePtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -9979,7 +9977,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S8PtrS8PtrS8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -9991,7 +9989,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U8PtrU8PtrU8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -10003,7 +10001,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S16PtrS16PtrS16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -10015,7 +10013,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U16PtrU16PtrU16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -10027,7 +10025,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S32PtrS32PtrS32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -10039,7 +10037,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U32PtrU32PtrU32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -10051,7 +10049,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_S64PtrS64PtrS64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -10063,7 +10061,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShaded_U64PtrU64PtrU64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -10075,7 +10073,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotShadedG(
         label_id: *const ::std::os::raw::c_char,
         getter1: ImPlotPoint_getter,
@@ -10086,7 +10084,7 @@ extern "C" {
         flags: ImPlotShadedFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_FloatPtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f32,
@@ -10098,7 +10096,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_doublePtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f64,
@@ -10110,7 +10108,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_S8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -10122,7 +10120,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_U8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -10134,7 +10132,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_S16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -10146,7 +10144,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_U16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -10158,7 +10156,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_S32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -10170,7 +10168,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_U32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -10182,7 +10180,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_S64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -10194,7 +10192,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_U64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -10206,7 +10204,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_FloatPtrFloatPtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -10218,7 +10216,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_doublePtrdoublePtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -10230,7 +10228,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_S8PtrS8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -10242,7 +10240,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_U8PtrU8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -10254,7 +10252,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_S16PtrS16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -10266,7 +10264,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_U16PtrU16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -10278,7 +10276,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_S32PtrS32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -10290,7 +10288,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_U32PtrU32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -10302,7 +10300,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_S64PtrS64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -10314,7 +10312,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBars_U64PtrU64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -10326,7 +10324,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarsG(
         label_id: *const ::std::os::raw::c_char,
         getter: ImPlotPoint_getter,
@@ -10336,7 +10334,7 @@ extern "C" {
         flags: ImPlotBarsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarGroups_FloatPtr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const f32,
@@ -10347,7 +10345,7 @@ extern "C" {
         flags: ImPlotBarGroupsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarGroups_doublePtr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const f64,
@@ -10358,7 +10356,7 @@ extern "C" {
         flags: ImPlotBarGroupsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarGroups_S8Ptr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -10369,7 +10367,7 @@ extern "C" {
         flags: ImPlotBarGroupsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarGroups_U8Ptr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -10380,7 +10378,7 @@ extern "C" {
         flags: ImPlotBarGroupsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarGroups_S16Ptr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -10391,7 +10389,7 @@ extern "C" {
         flags: ImPlotBarGroupsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarGroups_U16Ptr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -10402,7 +10400,7 @@ extern "C" {
         flags: ImPlotBarGroupsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarGroups_S32Ptr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -10413,7 +10411,7 @@ extern "C" {
         flags: ImPlotBarGroupsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarGroups_U32Ptr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -10424,7 +10422,7 @@ extern "C" {
         flags: ImPlotBarGroupsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarGroups_S64Ptr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -10435,7 +10433,7 @@ extern "C" {
         flags: ImPlotBarGroupsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotBarGroups_U64Ptr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -10446,7 +10444,7 @@ extern "C" {
         flags: ImPlotBarGroupsFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_FloatPtrFloatPtrFloatPtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -10458,7 +10456,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_doublePtrdoublePtrdoublePtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -10470,7 +10468,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_S8PtrS8PtrS8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -10482,7 +10480,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_U8PtrU8PtrU8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -10494,7 +10492,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_S16PtrS16PtrS16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -10506,7 +10504,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_U16PtrU16PtrU16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -10518,7 +10516,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_S32PtrS32PtrS32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -10530,7 +10528,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_U32PtrU32PtrU32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -10542,7 +10540,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_S64PtrS64PtrS64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -10554,7 +10552,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_U64PtrU64PtrU64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -10566,7 +10564,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_FloatPtrFloatPtrFloatPtrFloatPtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -10579,7 +10577,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_doublePtrdoublePtrdoublePtrdoublePtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -10592,7 +10590,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_S8PtrS8PtrS8PtrS8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -10605,7 +10603,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_U8PtrU8PtrU8PtrU8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -10618,7 +10616,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_S16PtrS16PtrS16PtrS16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -10631,7 +10629,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_U16PtrU16PtrU16PtrU16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -10644,7 +10642,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_S32PtrS32PtrS32PtrS32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -10657,7 +10655,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_U32PtrU32PtrU32PtrU32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -10670,7 +10668,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_S64PtrS64PtrS64PtrS64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -10683,7 +10681,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotErrorBars_U64PtrU64PtrU64PtrU64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -10696,7 +10694,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_FloatPtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f32,
@@ -10709,7 +10707,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_doublePtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const f64,
@@ -10722,7 +10720,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_S8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -10735,7 +10733,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_U8PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -10748,7 +10746,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_S16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -10761,7 +10759,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_U16PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -10774,7 +10772,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_S32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -10787,7 +10785,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_U32PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -10800,7 +10798,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_S64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -10813,7 +10811,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_U64PtrInt(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -10826,7 +10824,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_FloatPtrFloatPtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -10838,7 +10836,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_doublePtrdoublePtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -10850,7 +10848,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_S8PtrS8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -10862,7 +10860,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_U8PtrU8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -10874,7 +10872,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_S16PtrS16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -10886,7 +10884,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_U16PtrU16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -10898,7 +10896,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_S32PtrS32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -10910,7 +10908,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_U32PtrU32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -10922,7 +10920,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_S64PtrS64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -10934,7 +10932,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotStems_U64PtrU64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -10946,7 +10944,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotInfLines_FloatPtr(
         label_id: *const ::std::os::raw::c_char,
         values: *const f32,
@@ -10956,7 +10954,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotInfLines_doublePtr(
         label_id: *const ::std::os::raw::c_char,
         values: *const f64,
@@ -10966,7 +10964,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotInfLines_S8Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -10976,7 +10974,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotInfLines_U8Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -10986,7 +10984,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotInfLines_S16Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -10996,7 +10994,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotInfLines_U16Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -11006,7 +11004,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotInfLines_S32Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -11016,7 +11014,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotInfLines_U32Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -11026,7 +11024,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotInfLines_S64Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -11036,7 +11034,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotInfLines_U64Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -11046,7 +11044,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_FloatPtrPlotFormatter(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const f32,
@@ -11060,7 +11058,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_doublePtrPlotFormatter(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const f64,
@@ -11074,7 +11072,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_S8PtrPlotFormatter(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -11088,7 +11086,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_U8PtrPlotFormatter(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -11102,7 +11100,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_S16PtrPlotFormatter(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -11116,7 +11114,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_U16PtrPlotFormatter(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -11130,7 +11128,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_S32PtrPlotFormatter(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -11144,7 +11142,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_U32PtrPlotFormatter(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -11158,7 +11156,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_S64PtrPlotFormatter(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -11172,7 +11170,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_U64PtrPlotFormatter(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -11186,7 +11184,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_FloatPtrStr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const f32,
@@ -11199,7 +11197,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_doublePtrStr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const f64,
@@ -11212,7 +11210,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_S8PtrStr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -11225,7 +11223,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_U8PtrStr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -11238,7 +11236,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_S16PtrStr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -11251,7 +11249,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_U16PtrStr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -11264,7 +11262,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_S32PtrStr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -11277,7 +11275,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_U32PtrStr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -11290,7 +11288,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_S64PtrStr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -11303,7 +11301,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotPieChart_U64PtrStr(
         label_ids: *const *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -11316,7 +11314,7 @@ extern "C" {
         flags: ImPlotPieChartFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHeatmap_FloatPtr(
         label_id: *const ::std::os::raw::c_char,
         values: *const f32,
@@ -11330,7 +11328,7 @@ extern "C" {
         flags: ImPlotHeatmapFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHeatmap_doublePtr(
         label_id: *const ::std::os::raw::c_char,
         values: *const f64,
@@ -11344,7 +11342,7 @@ extern "C" {
         flags: ImPlotHeatmapFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHeatmap_S8Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -11358,7 +11356,7 @@ extern "C" {
         flags: ImPlotHeatmapFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHeatmap_U8Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -11372,7 +11370,7 @@ extern "C" {
         flags: ImPlotHeatmapFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHeatmap_S16Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -11386,7 +11384,7 @@ extern "C" {
         flags: ImPlotHeatmapFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHeatmap_U16Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -11400,7 +11398,7 @@ extern "C" {
         flags: ImPlotHeatmapFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHeatmap_S32Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -11414,7 +11412,7 @@ extern "C" {
         flags: ImPlotHeatmapFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHeatmap_U32Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -11428,7 +11426,7 @@ extern "C" {
         flags: ImPlotHeatmapFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHeatmap_S64Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -11442,7 +11440,7 @@ extern "C" {
         flags: ImPlotHeatmapFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHeatmap_U64Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -11456,7 +11454,7 @@ extern "C" {
         flags: ImPlotHeatmapFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram_FloatPtr(
         label_id: *const ::std::os::raw::c_char,
         values: *const f32,
@@ -11467,7 +11465,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram_doublePtr(
         label_id: *const ::std::os::raw::c_char,
         values: *const f64,
@@ -11478,7 +11476,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram_S8Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS8,
@@ -11489,7 +11487,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram_U8Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU8,
@@ -11500,7 +11498,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram_S16Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS16,
@@ -11511,7 +11509,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram_U16Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU16,
@@ -11522,7 +11520,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram_S32Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS32,
@@ -11533,7 +11531,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram_U32Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU32,
@@ -11544,7 +11542,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram_S64Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImS64,
@@ -11555,7 +11553,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram_U64Ptr(
         label_id: *const ::std::os::raw::c_char,
         values: *const ImU64,
@@ -11566,7 +11564,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram2D_FloatPtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -11578,7 +11576,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram2D_doublePtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -11590,7 +11588,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram2D_S8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -11602,7 +11600,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram2D_U8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -11614,7 +11612,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram2D_S16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -11626,7 +11624,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram2D_U16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -11638,7 +11636,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram2D_S32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -11650,7 +11648,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram2D_U32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -11662,7 +11660,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram2D_S64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -11674,7 +11672,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotHistogram2D_U64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -11686,7 +11684,7 @@ extern "C" {
         flags: ImPlotHistogramFlags,
     ) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigital_FloatPtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f32,
@@ -11697,7 +11695,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigital_doublePtr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const f64,
@@ -11708,7 +11706,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigital_S8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS8,
@@ -11719,7 +11717,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigital_U8Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU8,
@@ -11730,7 +11728,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigital_S16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS16,
@@ -11741,7 +11739,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigital_U16Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU16,
@@ -11752,7 +11750,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigital_S32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS32,
@@ -11763,7 +11761,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigital_U32Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU32,
@@ -11774,7 +11772,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigital_S64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImS64,
@@ -11785,7 +11783,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigital_U64Ptr(
         label_id: *const ::std::os::raw::c_char,
         xs: *const ImU64,
@@ -11796,7 +11794,7 @@ extern "C" {
         stride: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDigitalG(
         label_id: *const ::std::os::raw::c_char,
         getter: ImPlotPoint_getter,
@@ -11805,7 +11803,7 @@ extern "C" {
         flags: ImPlotDigitalFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotImage(
         label_id: *const ::std::os::raw::c_char,
         user_texture_id: ImTextureID,
@@ -11817,7 +11815,7 @@ extern "C" {
         flags: ImPlotImageFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotText(
         text: *const ::std::os::raw::c_char,
         x: f64,
@@ -11826,10 +11824,10 @@ extern "C" {
         flags: ImPlotTextFlags,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotDummy(label_id: *const ::std::os::raw::c_char, flags: ImPlotDummyFlags);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_DragPoint(
         id: ::std::os::raw::c_int,
         x: *mut f64,
@@ -11842,7 +11840,7 @@ extern "C" {
         held: *mut bool,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_DragLineX(
         id: ::std::os::raw::c_int,
         x: *mut f64,
@@ -11854,7 +11852,7 @@ extern "C" {
         held: *mut bool,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_DragLineY(
         id: ::std::os::raw::c_int,
         y: *mut f64,
@@ -11866,7 +11864,7 @@ extern "C" {
         held: *mut bool,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_DragRect(
         id: ::std::os::raw::c_int,
         x1: *mut f64,
@@ -11880,7 +11878,7 @@ extern "C" {
         held: *mut bool,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Annotation_Bool(
         x: f64,
         y: f64,
@@ -11890,7 +11888,7 @@ extern "C" {
         round: bool,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Annotation_Str(
         x: f64,
         y: f64,
@@ -11901,25 +11899,25 @@ extern "C" {
         ...
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_TagX_Bool(x: f64, col: ImVec4, round: bool);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_TagX_Str(x: f64, col: ImVec4, fmt: *const ::std::os::raw::c_char, ...);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_TagY_Bool(y: f64, col: ImVec4, round: bool);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_TagY_Str(y: f64, col: ImVec4, fmt: *const ::std::os::raw::c_char, ...);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetAxis(axis: ImAxis);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetAxes(x_axis: ImAxis, y_axis: ImAxis);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PixelsToPlot_Vec2(
         pOut: *mut ImPlotPoint,
         pix: ImVec2,
@@ -11927,7 +11925,7 @@ extern "C" {
         y_axis: ImAxis,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PixelsToPlot_Float(
         pOut: *mut ImPlotPoint,
         x: f32,
@@ -11936,7 +11934,7 @@ extern "C" {
         y_axis: ImAxis,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotToPixels_PlotPoInt(
         pOut: *mut ImVec2,
         plt: ImPlotPoint,
@@ -11944,7 +11942,7 @@ extern "C" {
         y_axis: ImAxis,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PlotToPixels_double(
         pOut: *mut ImVec2,
         x: f64,
@@ -11953,130 +11951,130 @@ extern "C" {
         y_axis: ImAxis,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetPlotPos(pOut: *mut ImVec2);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetPlotSize(pOut: *mut ImVec2);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetPlotMousePos(pOut: *mut ImPlotPoint, x_axis: ImAxis, y_axis: ImAxis);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetPlotLimits(pOut: *mut ImPlotRect, x_axis: ImAxis, y_axis: ImAxis);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_IsPlotHovered() -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_IsAxisHovered(axis: ImAxis) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_IsSubplotsHovered() -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_IsPlotSelected() -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetPlotSelection(pOut: *mut ImPlotRect, x_axis: ImAxis, y_axis: ImAxis);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CancelPlotSelection();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_HideNextItem(hidden: bool, cond: ImPlotCond);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginAlignedPlots(
         group_id: *const ::std::os::raw::c_char,
         vertical: bool,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_EndAlignedPlots();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginLegendPopup(
         label_id: *const ::std::os::raw::c_char,
         mouse_button: ImGuiMouseButton,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_EndLegendPopup();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_IsLegendEntryHovered(label_id: *const ::std::os::raw::c_char) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginDragDropTargetPlot() -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginDragDropTargetAxis(axis: ImAxis) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginDragDropTargetLegend() -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_EndDragDropTarget();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginDragDropSourcePlot(flags: ImGuiDragDropFlags) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginDragDropSourceAxis(axis: ImAxis, flags: ImGuiDragDropFlags) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginDragDropSourceItem(
         label_id: *const ::std::os::raw::c_char,
         flags: ImGuiDragDropFlags,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_EndDragDropSource();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetStyle() -> *mut ImPlotStyle;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_StyleColorsAuto(dst: *mut ImPlotStyle);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_StyleColorsClassic(dst: *mut ImPlotStyle);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_StyleColorsDark(dst: *mut ImPlotStyle);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_StyleColorsLight(dst: *mut ImPlotStyle);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PushStyleColor_U32(idx: ImPlotCol, col: ImU32);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PushStyleColor_Vec4(idx: ImPlotCol, col: ImVec4);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PopStyleColor(count: ::std::os::raw::c_int);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PushStyleVar_Float(idx: ImPlotStyleVar, val: f32);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PushStyleVar_Int(idx: ImPlotStyleVar, val: ::std::os::raw::c_int);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PushStyleVar_Vec2(idx: ImPlotStyleVar, val: ImVec2);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PopStyleVar(count: ::std::os::raw::c_int);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetNextLineStyle(col: ImVec4, weight: f32);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetNextFillStyle(col: ImVec4, alpha_mod: f32);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetNextMarkerStyle(
         marker: ImPlotMarker,
         size: f32,
@@ -12085,19 +12083,19 @@ extern "C" {
         outline: ImVec4,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetNextErrorBarStyle(col: ImVec4, size: f32, weight: f32);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetLastItemColor(pOut: *mut ImVec4);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetStyleColorName(idx: ImPlotCol) -> *const ::std::os::raw::c_char;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetMarkerName(idx: ImPlotMarker) -> *const ::std::os::raw::c_char;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_AddColormap_Vec4Ptr(
         name: *const ::std::os::raw::c_char,
         cols: *const ImVec4,
@@ -12105,7 +12103,7 @@ extern "C" {
         qual: bool,
     ) -> ImPlotColormap;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_AddColormap_U32Ptr(
         name: *const ::std::os::raw::c_char,
         cols: *const ImU32,
@@ -12113,41 +12111,41 @@ extern "C" {
         qual: bool,
     ) -> ImPlotColormap;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetColormapCount() -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetColormapName(cmap: ImPlotColormap) -> *const ::std::os::raw::c_char;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetColormapIndex(name: *const ::std::os::raw::c_char) -> ImPlotColormap;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PushColormap_PlotColormap(cmap: ImPlotColormap);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PushColormap_Str(name: *const ::std::os::raw::c_char);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PopColormap(count: ::std::os::raw::c_int);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_NextColormapColor(pOut: *mut ImVec4);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetColormapSize(cmap: ImPlotColormap) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetColormapColor(
         pOut: *mut ImVec4,
         idx: ::std::os::raw::c_int,
         cmap: ImPlotColormap,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SampleColormap(pOut: *mut ImVec4, t: f32, cmap: ImPlotColormap);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ColormapScale(
         label: *const ::std::os::raw::c_char,
         scale_min: f64,
@@ -12158,7 +12156,7 @@ extern "C" {
         cmap: ImPlotColormap,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ColormapSlider(
         label: *const ::std::os::raw::c_char,
         t: *mut f32,
@@ -12167,230 +12165,230 @@ extern "C" {
         cmap: ImPlotColormap,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ColormapButton(
         label: *const ::std::os::raw::c_char,
         size: ImVec2,
         cmap: ImPlotColormap,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BustColorCache(plot_title_id: *const ::std::os::raw::c_char);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetInputMap() -> *mut ImPlotInputMap;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_MapInputDefault(dst: *mut ImPlotInputMap);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_MapInputReverse(dst: *mut ImPlotInputMap);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ItemIcon_Vec4(col: ImVec4);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ItemIcon_U32(col: ImU32);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ColormapIcon(cmap: ImPlotColormap);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetPlotDrawList() -> *mut ImDrawList;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PushPlotClipRect(expand: f32);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_PopPlotClipRect();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowStyleSelector(label: *const ::std::os::raw::c_char) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowColormapSelector(label: *const ::std::os::raw::c_char) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowInputMapSelector(label: *const ::std::os::raw::c_char) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowStyleEditor(ref_: *mut ImPlotStyle);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowUserGuide();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowMetricsWindow(p_popen: *mut bool);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowDemoWindow(p_open: *mut bool);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImLog10_Float(x: f32) -> f32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImLog10_double(x: f64) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSinh_Float(x: f32) -> f32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSinh_double(x: f64) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImAsinh_Float(x: f32) -> f32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImAsinh_double(x: f64) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap_Float(x: f32, x0: f32, x1: f32, y0: f32, y1: f32) -> f32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap_double(x: f64, x0: f64, x1: f64, y0: f64, y1: f64) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap_S8(x: ImS8, x0: ImS8, x1: ImS8, y0: ImS8, y1: ImS8) -> ImS8;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap_U8(x: ImU8, x0: ImU8, x1: ImU8, y0: ImU8, y1: ImU8) -> ImU8;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap_S16(x: ImS16, x0: ImS16, x1: ImS16, y0: ImS16, y1: ImS16) -> ImS16;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap_U16(x: ImU16, x0: ImU16, x1: ImU16, y0: ImU16, y1: ImU16) -> ImU16;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap_S32(x: ImS32, x0: ImS32, x1: ImS32, y0: ImS32, y1: ImS32) -> ImS32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap_U32(x: ImU32, x0: ImU32, x1: ImU32, y0: ImU32, y1: ImU32) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap_S64(x: ImS64, x0: ImS64, x1: ImS64, y0: ImS64, y1: ImS64) -> ImS64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap_U64(x: ImU64, x0: ImU64, x1: ImU64, y0: ImU64, y1: ImU64) -> ImU64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap01_Float(x: f32, x0: f32, x1: f32) -> f32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap01_double(x: f64, x0: f64, x1: f64) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap01_S8(x: ImS8, x0: ImS8, x1: ImS8) -> ImS8;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap01_U8(x: ImU8, x0: ImU8, x1: ImU8) -> ImU8;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap01_S16(x: ImS16, x0: ImS16, x1: ImS16) -> ImS16;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap01_U16(x: ImU16, x0: ImU16, x1: ImU16) -> ImU16;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap01_S32(x: ImS32, x0: ImS32, x1: ImS32) -> ImS32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap01_U32(x: ImU32, x0: ImU32, x1: ImU32) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap01_S64(x: ImS64, x0: ImS64, x1: ImS64) -> ImS64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImRemap01_U64(x: ImU64, x0: ImU64, x1: ImU64) -> ImU64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImPosMod(
         l: ::std::os::raw::c_int,
         r: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImNan(val: f64) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImNanOrInf(val: f64) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImConstrainNan(val: f64) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImConstrainInf(val: f64) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImConstrainLog(val: f64) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImConstrainTime(val: f64) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImAlmostEqual(v1: f64, v2: f64, ulp: ::std::os::raw::c_int) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinArray_FloatPtr(values: *const f32, count: ::std::os::raw::c_int) -> f32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinArray_doublePtr(values: *const f64, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinArray_S8Ptr(values: *const ImS8, count: ::std::os::raw::c_int) -> ImS8;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinArray_U8Ptr(values: *const ImU8, count: ::std::os::raw::c_int) -> ImU8;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinArray_S16Ptr(values: *const ImS16, count: ::std::os::raw::c_int) -> ImS16;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinArray_U16Ptr(values: *const ImU16, count: ::std::os::raw::c_int) -> ImU16;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinArray_S32Ptr(values: *const ImS32, count: ::std::os::raw::c_int) -> ImS32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinArray_U32Ptr(values: *const ImU32, count: ::std::os::raw::c_int) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinArray_S64Ptr(values: *const ImS64, count: ::std::os::raw::c_int) -> ImS64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinArray_U64Ptr(values: *const ImU64, count: ::std::os::raw::c_int) -> ImU64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMaxArray_FloatPtr(values: *const f32, count: ::std::os::raw::c_int) -> f32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMaxArray_doublePtr(values: *const f64, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMaxArray_S8Ptr(values: *const ImS8, count: ::std::os::raw::c_int) -> ImS8;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMaxArray_U8Ptr(values: *const ImU8, count: ::std::os::raw::c_int) -> ImU8;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMaxArray_S16Ptr(values: *const ImS16, count: ::std::os::raw::c_int) -> ImS16;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMaxArray_U16Ptr(values: *const ImU16, count: ::std::os::raw::c_int) -> ImU16;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMaxArray_S32Ptr(values: *const ImS32, count: ::std::os::raw::c_int) -> ImS32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMaxArray_U32Ptr(values: *const ImU32, count: ::std::os::raw::c_int) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMaxArray_S64Ptr(values: *const ImS64, count: ::std::os::raw::c_int) -> ImS64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMaxArray_U64Ptr(values: *const ImU64, count: ::std::os::raw::c_int) -> ImU64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinMaxArray_FloatPtr(
         values: *const f32,
         count: ::std::os::raw::c_int,
@@ -12398,7 +12396,7 @@ extern "C" {
         max_out: *mut f32,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinMaxArray_doublePtr(
         values: *const f64,
         count: ::std::os::raw::c_int,
@@ -12406,7 +12404,7 @@ extern "C" {
         max_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinMaxArray_S8Ptr(
         values: *const ImS8,
         count: ::std::os::raw::c_int,
@@ -12414,7 +12412,7 @@ extern "C" {
         max_out: *mut ImS8,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinMaxArray_U8Ptr(
         values: *const ImU8,
         count: ::std::os::raw::c_int,
@@ -12422,7 +12420,7 @@ extern "C" {
         max_out: *mut ImU8,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinMaxArray_S16Ptr(
         values: *const ImS16,
         count: ::std::os::raw::c_int,
@@ -12430,7 +12428,7 @@ extern "C" {
         max_out: *mut ImS16,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinMaxArray_U16Ptr(
         values: *const ImU16,
         count: ::std::os::raw::c_int,
@@ -12438,7 +12436,7 @@ extern "C" {
         max_out: *mut ImU16,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinMaxArray_S32Ptr(
         values: *const ImS32,
         count: ::std::os::raw::c_int,
@@ -12446,7 +12444,7 @@ extern "C" {
         max_out: *mut ImS32,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinMaxArray_U32Ptr(
         values: *const ImU32,
         count: ::std::os::raw::c_int,
@@ -12454,7 +12452,7 @@ extern "C" {
         max_out: *mut ImU32,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinMaxArray_S64Ptr(
         values: *const ImS64,
         count: ::std::os::raw::c_int,
@@ -12462,7 +12460,7 @@ extern "C" {
         max_out: *mut ImS64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMinMaxArray_U64Ptr(
         values: *const ImU64,
         count: ::std::os::raw::c_int,
@@ -12470,133 +12468,133 @@ extern "C" {
         max_out: *mut ImU64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSum_FloatPtr(values: *const f32, count: ::std::os::raw::c_int) -> f32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSum_doublePtr(values: *const f64, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSum_S8Ptr(values: *const ImS8, count: ::std::os::raw::c_int) -> ImS8;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSum_U8Ptr(values: *const ImU8, count: ::std::os::raw::c_int) -> ImU8;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSum_S16Ptr(values: *const ImS16, count: ::std::os::raw::c_int) -> ImS16;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSum_U16Ptr(values: *const ImU16, count: ::std::os::raw::c_int) -> ImU16;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSum_S32Ptr(values: *const ImS32, count: ::std::os::raw::c_int) -> ImS32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSum_U32Ptr(values: *const ImU32, count: ::std::os::raw::c_int) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSum_S64Ptr(values: *const ImS64, count: ::std::os::raw::c_int) -> ImS64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImSum_U64Ptr(values: *const ImU64, count: ::std::os::raw::c_int) -> ImU64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMean_FloatPtr(values: *const f32, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMean_doublePtr(values: *const f64, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMean_S8Ptr(values: *const ImS8, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMean_U8Ptr(values: *const ImU8, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMean_S16Ptr(values: *const ImS16, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMean_U16Ptr(values: *const ImU16, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMean_S32Ptr(values: *const ImS32, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMean_U32Ptr(values: *const ImU32, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMean_S64Ptr(values: *const ImS64, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMean_U64Ptr(values: *const ImU64, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImStdDev_FloatPtr(values: *const f32, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImStdDev_doublePtr(values: *const f64, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImStdDev_S8Ptr(values: *const ImS8, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImStdDev_U8Ptr(values: *const ImU8, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImStdDev_S16Ptr(values: *const ImS16, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImStdDev_U16Ptr(values: *const ImU16, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImStdDev_S32Ptr(values: *const ImS32, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImStdDev_U32Ptr(values: *const ImU32, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImStdDev_S64Ptr(values: *const ImS64, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImStdDev_U64Ptr(values: *const ImU64, count: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImMixU32(a: ImU32, b: ImU32, s: ImU32) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImLerpU32(colors: *const ImU32, size: ::std::os::raw::c_int, t: f32) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImAlphaU32(col: ImU32, alpha: f32) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImOverlaps_Float(min_a: f32, max_a: f32, min_b: f32, max_b: f32) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImOverlaps_double(min_a: f64, max_a: f64, min_b: f64, max_b: f64) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImOverlaps_S8(min_a: ImS8, max_a: ImS8, min_b: ImS8, max_b: ImS8) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImOverlaps_U8(min_a: ImU8, max_a: ImU8, min_b: ImU8, max_b: ImU8) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImOverlaps_S16(min_a: ImS16, max_a: ImS16, min_b: ImS16, max_b: ImS16) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImOverlaps_U16(min_a: ImU16, max_a: ImU16, min_b: ImU16, max_b: ImU16) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImOverlaps_S32(min_a: ImS32, max_a: ImS32, min_b: ImS32, max_b: ImS32) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImOverlaps_U32(min_a: ImU32, max_a: ImU32, min_b: ImU32, max_b: ImU32) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImOverlaps_S64(min_a: ImS64, max_a: ImS64, min_b: ImS64, max_b: ImS64) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ImOverlaps_U64(min_a: ImU64, max_a: ImU64, min_b: ImU64, max_b: ImU64) -> bool;
 }
 extern "C" {
@@ -13161,100 +13159,100 @@ extern "C" {
 extern "C" {
     pub fn ImPlotNextItemData_Reset(self_: *mut ImPlotNextItemData);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Initialize(ctx: *mut ImPlotContext);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ResetCtxForNextPlot(ctx: *mut ImPlotContext);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ResetCtxForNextAlignedPlots(ctx: *mut ImPlotContext);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ResetCtxForNextSubplot(ctx: *mut ImPlotContext);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetPlot(title: *const ::std::os::raw::c_char) -> *mut ImPlotPlot;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetCurrentPlot() -> *mut ImPlotPlot;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BustPlotCache();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowPlotContextMenu(plot: *mut ImPlotPlot);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SetupLock();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SubplotNextCell();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowSubplotsContextMenu(subplot: *mut ImPlotSubplot);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BeginItem(
         label_id: *const ::std::os::raw::c_char,
         flags: ImPlotItemFlags,
         recolor_from: ImPlotCol,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_EndItem();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_RegisterOrGetItem(
         label_id: *const ::std::os::raw::c_char,
         flags: ImPlotItemFlags,
         just_created: *mut bool,
     ) -> *mut ImPlotItem;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetItem(label_id: *const ::std::os::raw::c_char) -> *mut ImPlotItem;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetCurrentItem() -> *mut ImPlotItem;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_BustItemCache();
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_AnyAxesInputLocked(axes: *mut ImPlotAxis, count: ::std::os::raw::c_int) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_AllAxesInputLocked(axes: *mut ImPlotAxis, count: ::std::os::raw::c_int) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_AnyAxesHeld(axes: *mut ImPlotAxis, count: ::std::os::raw::c_int) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_AnyAxesHovered(axes: *mut ImPlotAxis, count: ::std::os::raw::c_int) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FitThisFrame() -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FitPointX(x: f64);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FitPointY(y: f64);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FitPoint(p: ImPlotPoint);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_RangesOverlap(r1: ImPlotRange, r2: ImPlotRange) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowAxisContextMenu(
         axis: *mut ImPlotAxis,
         equal_axis: *mut ImPlotAxis,
         time_allowed: bool,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetLocationPos(
         pOut: *mut ImVec2,
         outer_rect: ImRect,
@@ -13263,7 +13261,7 @@ extern "C" {
         pad: ImVec2,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalcLegendSize(
         pOut: *mut ImVec2,
         items: *mut ImPlotItemGroup,
@@ -13272,14 +13270,14 @@ extern "C" {
         vertical: bool,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ClampLegendRect(
         legend_rect: *mut ImRect,
         outer_rect: ImRect,
         pad: ImVec2,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowLegendEntries(
         items: *mut ImPlotItemGroup,
         legend_bb: ImRect,
@@ -13290,7 +13288,7 @@ extern "C" {
         DrawList: *mut ImDrawList,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowAltLegend(
         title_id: *const ::std::os::raw::c_char,
         vertical: bool,
@@ -13298,10 +13296,10 @@ extern "C" {
         interactable: bool,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowLegendContextMenu(legend: *mut ImPlotLegend, visible: bool) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_LabelAxisValue(
         axis: ImPlotAxis,
         value: f64,
@@ -13310,25 +13308,25 @@ extern "C" {
         round: bool,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetItemData() -> *const ImPlotNextItemData;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_IsColorAuto_Vec4(col: ImVec4) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_IsColorAuto_PlotCol(idx: ImPlotCol) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetAutoColor(pOut: *mut ImVec4, idx: ImPlotCol);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetStyleColorVec4(pOut: *mut ImVec4, idx: ImPlotCol);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetStyleColorU32(idx: ImPlotCol) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_AddTextVertical(
         DrawList: *mut ImDrawList,
         pos: ImVec2,
@@ -13337,7 +13335,7 @@ extern "C" {
         text_end: *const ::std::os::raw::c_char,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_AddTextCentered(
         DrawList: *mut ImDrawList,
         top_center: ImVec2,
@@ -13346,19 +13344,19 @@ extern "C" {
         text_end: *const ::std::os::raw::c_char,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalcTextSizeVertical(pOut: *mut ImVec2, text: *const ::std::os::raw::c_char);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalcTextColor_Vec4(bg: ImVec4) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalcTextColor_U32(bg: ImU32) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalcHoverColor(col: ImU32) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ClampLabelPos(
         pOut: *mut ImVec2,
         pos: ImVec2,
@@ -13367,16 +13365,16 @@ extern "C" {
         Max: ImVec2,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetColormapColorU32(idx: ::std::os::raw::c_int, cmap: ImPlotColormap) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_NextColormapColorU32() -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_SampleColormapU32(t: f32, cmap: ImPlotColormap) -> ImU32;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_RenderColorBar(
         colors: *const ImU32,
         size: ::std::os::raw::c_int,
@@ -13387,25 +13385,25 @@ extern "C" {
         continuous: bool,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_NiceNum(x: f64, round: bool) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_OrderOfMagnitude(val: f64) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_OrderToPrecision(order: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Precision(val: f64) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_RoundTo(val: f64, prec: ::std::os::raw::c_int) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Intersection(pOut: *mut ImVec2, a1: ImVec2, a2: ImVec2, b1: ImVec2, b2: ImVec2);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FillRange_Vector_Float_Ptr(
         buffer: *mut ImVector_float,
         n: ::std::os::raw::c_int,
@@ -13413,7 +13411,7 @@ extern "C" {
         vmax: f32,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FillRange_Vector_double_Ptr(
         buffer: *mut ImVector_double,
         n: ::std::os::raw::c_int,
@@ -13421,7 +13419,7 @@ extern "C" {
         vmax: f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FillRange_Vector_S8_Ptr(
         buffer: *mut ImVector_ImS8,
         n: ::std::os::raw::c_int,
@@ -13429,7 +13427,7 @@ extern "C" {
         vmax: ImS8,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FillRange_Vector_U8_Ptr(
         buffer: *mut ImVector_ImU8,
         n: ::std::os::raw::c_int,
@@ -13437,7 +13435,7 @@ extern "C" {
         vmax: ImU8,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FillRange_Vector_S16_Ptr(
         buffer: *mut ImVector_ImS16,
         n: ::std::os::raw::c_int,
@@ -13445,7 +13443,7 @@ extern "C" {
         vmax: ImS16,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FillRange_Vector_U16_Ptr(
         buffer: *mut ImVector_ImU16,
         n: ::std::os::raw::c_int,
@@ -13453,7 +13451,7 @@ extern "C" {
         vmax: ImU16,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FillRange_Vector_S32_Ptr(
         buffer: *mut ImVector_ImS32,
         n: ::std::os::raw::c_int,
@@ -13461,7 +13459,7 @@ extern "C" {
         vmax: ImS32,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FillRange_Vector_U32_Ptr(
         buffer: *mut ImVector_ImU32,
         n: ::std::os::raw::c_int,
@@ -13469,7 +13467,7 @@ extern "C" {
         vmax: ImU32,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FillRange_Vector_S64_Ptr(
         buffer: *mut ImVector_ImS64,
         n: ::std::os::raw::c_int,
@@ -13477,7 +13475,7 @@ extern "C" {
         vmax: ImS64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FillRange_Vector_U64_Ptr(
         buffer: *mut ImVector_ImU64,
         n: ::std::os::raw::c_int,
@@ -13485,7 +13483,7 @@ extern "C" {
         vmax: ImU64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalculateBins_FloatPtr(
         values: *const f32,
         count: ::std::os::raw::c_int,
@@ -13495,7 +13493,7 @@ extern "C" {
         width_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalculateBins_doublePtr(
         values: *const f64,
         count: ::std::os::raw::c_int,
@@ -13505,7 +13503,7 @@ extern "C" {
         width_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalculateBins_S8Ptr(
         values: *const ImS8,
         count: ::std::os::raw::c_int,
@@ -13515,7 +13513,7 @@ extern "C" {
         width_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalculateBins_U8Ptr(
         values: *const ImU8,
         count: ::std::os::raw::c_int,
@@ -13525,7 +13523,7 @@ extern "C" {
         width_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalculateBins_S16Ptr(
         values: *const ImS16,
         count: ::std::os::raw::c_int,
@@ -13535,7 +13533,7 @@ extern "C" {
         width_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalculateBins_U16Ptr(
         values: *const ImU16,
         count: ::std::os::raw::c_int,
@@ -13545,7 +13543,7 @@ extern "C" {
         width_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalculateBins_S32Ptr(
         values: *const ImS32,
         count: ::std::os::raw::c_int,
@@ -13555,7 +13553,7 @@ extern "C" {
         width_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalculateBins_U32Ptr(
         values: *const ImU32,
         count: ::std::os::raw::c_int,
@@ -13565,7 +13563,7 @@ extern "C" {
         width_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalculateBins_S64Ptr(
         values: *const ImS64,
         count: ::std::os::raw::c_int,
@@ -13575,7 +13573,7 @@ extern "C" {
         width_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CalculateBins_U64Ptr(
         values: *const ImU64,
         count: ::std::os::raw::c_int,
@@ -13585,28 +13583,28 @@ extern "C" {
         width_out: *mut f64,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_IsLeapYear(year: ::std::os::raw::c_int) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetDaysInMonth(
         year: ::std::os::raw::c_int,
         month: ::std::os::raw::c_int,
     ) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_MkGmtTime(pOut: *mut ImPlotTime, ptm: *mut tm);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetGmtTime(t: ImPlotTime, ptm: *mut tm) -> *mut tm;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_MkLocTime(pOut: *mut ImPlotTime, ptm: *mut tm);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetLocTime(t: ImPlotTime, ptm: *mut tm) -> *mut tm;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_MakeTime(
         pOut: *mut ImPlotTime,
         year: ::std::os::raw::c_int,
@@ -13618,10 +13616,10 @@ extern "C" {
         us: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_GetYear(t: ImPlotTime) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_AddTime(
         pOut: *mut ImPlotTime,
         t: ImPlotTime,
@@ -13629,23 +13627,23 @@ extern "C" {
         count: ::std::os::raw::c_int,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FloorTime(pOut: *mut ImPlotTime, t: ImPlotTime, unit: ImPlotTimeUnit);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CeilTime(pOut: *mut ImPlotTime, t: ImPlotTime, unit: ImPlotTimeUnit);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_RoundTime(pOut: *mut ImPlotTime, t: ImPlotTime, unit: ImPlotTimeUnit);
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_CombineDateTime(
         pOut: *mut ImPlotTime,
         date_part: ImPlotTime,
         time_part: ImPlotTime,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FormatTime(
         t: ImPlotTime,
         buffer: *mut ::std::os::raw::c_char,
@@ -13654,7 +13652,7 @@ extern "C" {
         use_24_hr_clk: bool,
     ) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FormatDate(
         t: ImPlotTime,
         buffer: *mut ::std::os::raw::c_char,
@@ -13663,7 +13661,7 @@ extern "C" {
         use_iso_8601: bool,
     ) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_FormatDateTime(
         t: ImPlotTime,
         buffer: *mut ::std::os::raw::c_char,
@@ -13671,7 +13669,7 @@ extern "C" {
         fmt: ImPlotDateTimeSpec,
     ) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowDatePicker(
         id: *const ::std::os::raw::c_char,
         level: *mut ::std::os::raw::c_int,
@@ -13680,28 +13678,28 @@ extern "C" {
         t2: *const ImPlotTime,
     ) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_ShowTimePicker(id: *const ::std::os::raw::c_char, t: *mut ImPlotTime) -> bool;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_TransformForward_Log10(v: f64, noname1: *mut ::std::os::raw::c_void) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_TransformInverse_Log10(v: f64, noname1: *mut ::std::os::raw::c_void) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_TransformForward_SymLog(v: f64, noname1: *mut ::std::os::raw::c_void) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_TransformInverse_SymLog(v: f64, noname1: *mut ::std::os::raw::c_void) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_TransformForward_Logit(v: f64, noname1: *mut ::std::os::raw::c_void) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_TransformInverse_Logit(v: f64, noname1: *mut ::std::os::raw::c_void) -> f64;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Formatter_Default(
         value: f64,
         buff: *mut ::std::os::raw::c_char,
@@ -13709,7 +13707,7 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     ) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Formatter_Logit(
         value: f64,
         buff: *mut ::std::os::raw::c_char,
@@ -13717,7 +13715,7 @@ extern "C" {
         noname1: *mut ::std::os::raw::c_void,
     ) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Formatter_Time(
         noname1: f64,
         buff: *mut ::std::os::raw::c_char,
@@ -13725,7 +13723,7 @@ extern "C" {
         data: *mut ::std::os::raw::c_void,
     ) -> ::std::os::raw::c_int;
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Locator_Default(
         ticker: *mut ImPlotTicker,
         range: ImPlotRange,
@@ -13735,7 +13733,7 @@ extern "C" {
         formatter_data: *mut ::std::os::raw::c_void,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Locator_Time(
         ticker: *mut ImPlotTicker,
         range: ImPlotRange,
@@ -13745,7 +13743,7 @@ extern "C" {
         formatter_data: *mut ::std::os::raw::c_void,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Locator_Log10(
         ticker: *mut ImPlotTicker,
         range: ImPlotRange,
@@ -13755,7 +13753,7 @@ extern "C" {
         formatter_data: *mut ::std::os::raw::c_void,
     );
 }
-extern "C" {
+extern "C-unwind" {
     pub fn ImPlot_Locator_SymLog(
         ticker: *mut ImPlotTicker,
         range: ImPlotRange,
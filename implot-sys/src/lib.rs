@@ -6,9 +6,39 @@
 #[cfg(test)]
 use imgui_sys;
 
+use std::convert::TryFrom;
+use std::ffi::CStr;
 use std::ops::Range;
+use std::os::raw::{c_char, c_int};
+use std::slice;
 include!("bindings.rs");
 
+// The C time types used by ImPlot's time axes, which bindgen can't generate portably. libc has
+// them for native targets, but not for emscripten, where they are defined to match emscripten's
+// (musl based) libc.
+#[cfg(not(target_os = "emscripten"))]
+pub use libc::{time_t, tm};
+
+#[cfg(target_os = "emscripten")]
+pub type time_t = i64;
+
+#[cfg(target_os = "emscripten")]
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct tm {
+    pub tm_sec: c_int,
+    pub tm_min: c_int,
+    pub tm_hour: c_int,
+    pub tm_mday: c_int,
+    pub tm_mon: c_int,
+    pub tm_year: c_int,
+    pub tm_wday: c_int,
+    pub tm_yday: c_int,
+    pub tm_isdst: c_int,
+    pub tm_gmtoff: std::os::raw::c_long,
+    pub tm_zone: *const c_char,
+}
+
 impl From<Range<f64>> for ImPlotRange {
     fn from(from: Range<f64>) -> Self {
         ImPlotRange {
@@ -45,6 +75,412 @@ impl From<ImVec2> for ImPlotRange {
     }
 }
 
+// Geometry helpers mirroring the inline C++ members of ImPlotRange and ImPlotRect, done in
+// Rust so they don't need a mutable pointer and a trip through the C API for trivial math.
+impl ImPlotRange {
+    /// Creates a range from `min` to `max`.
+    #[inline]
+    pub fn new(min: f64, max: f64) -> Self {
+        ImPlotRange { Min: min, Max: max }
+    }
+
+    /// Returns true if `value` lies within the range, bounds included.
+    #[inline]
+    pub fn contains(&self, value: f64) -> bool {
+        value >= self.Min && value <= self.Max
+    }
+
+    /// Length of the range.
+    #[inline]
+    pub fn size(&self) -> f64 {
+        self.Max - self.Min
+    }
+
+    /// Center of the range.
+    #[inline]
+    pub fn center(&self) -> f64 {
+        (self.Min + self.Max) * 0.5
+    }
+
+    /// Clamps `value` into the range.
+    #[inline]
+    pub fn clamp(&self, value: f64) -> f64 {
+        if value < self.Min {
+            self.Min
+        } else if value > self.Max {
+            self.Max
+        } else {
+            value
+        }
+    }
+
+    /// Overlap of two ranges, `None` if they don't overlap.
+    pub fn intersect(&self, other: &ImPlotRange) -> Option<ImPlotRange> {
+        let min = self.Min.max(other.Min);
+        let max = self.Max.min(other.Max);
+        if min <= max {
+            Some(ImPlotRange::new(min, max))
+        } else {
+            None
+        }
+    }
+
+    /// Smallest range containing both ranges.
+    pub fn union(&self, other: &ImPlotRange) -> ImPlotRange {
+        ImPlotRange::new(self.Min.min(other.Min), self.Max.max(other.Max))
+    }
+
+    /// Grows the range by `fraction` of its size on each side, e.g. `0.1` adds 10% padding
+    /// below and above. Negative fractions shrink the range.
+    pub fn expand(&self, fraction: f64) -> ImPlotRange {
+        let padding = self.size() * fraction;
+        ImPlotRange::new(self.Min - padding, self.Max + padding)
+    }
+}
+
+impl ImPlotRect {
+    /// Creates a rectangle from its X and Y ranges.
+    #[inline]
+    pub fn new<X: Into<ImPlotRange>, Y: Into<ImPlotRange>>(x: X, y: Y) -> Self {
+        ImPlotRect {
+            X: x.into(),
+            Y: y.into(),
+        }
+    }
+
+    /// Returns true if `point` lies within the rectangle, edges included.
+    #[inline]
+    pub fn contains(&self, point: ImPlotPoint) -> bool {
+        self.X.contains(point.x) && self.Y.contains(point.y)
+    }
+
+    /// Width and height of the rectangle.
+    #[inline]
+    pub fn size(&self) -> ImPlotPoint {
+        ImPlotPoint {
+            x: self.X.size(),
+            y: self.Y.size(),
+        }
+    }
+
+    /// Center of the rectangle.
+    #[inline]
+    pub fn center(&self) -> ImPlotPoint {
+        ImPlotPoint {
+            x: self.X.center(),
+            y: self.Y.center(),
+        }
+    }
+
+    /// Corner with the smallest coordinates.
+    #[inline]
+    pub fn min(&self) -> ImPlotPoint {
+        ImPlotPoint {
+            x: self.X.Min,
+            y: self.Y.Min,
+        }
+    }
+
+    /// Corner with the largest coordinates.
+    #[inline]
+    pub fn max(&self) -> ImPlotPoint {
+        ImPlotPoint {
+            x: self.X.Max,
+            y: self.Y.Max,
+        }
+    }
+
+    /// Clamps `point` into the rectangle.
+    #[inline]
+    pub fn clamp(&self, point: ImPlotPoint) -> ImPlotPoint {
+        ImPlotPoint {
+            x: self.X.clamp(point.x),
+            y: self.Y.clamp(point.y),
+        }
+    }
+
+    /// Overlap of two rectangles, `None` if they don't overlap.
+    pub fn intersect(&self, other: &ImPlotRect) -> Option<ImPlotRect> {
+        Some(ImPlotRect {
+            X: self.X.intersect(&other.X)?,
+            Y: self.Y.intersect(&other.Y)?,
+        })
+    }
+
+    /// Smallest rectangle containing both rectangles.
+    pub fn union(&self, other: &ImPlotRect) -> ImPlotRect {
+        ImPlotRect {
+            X: self.X.union(&other.X),
+            Y: self.Y.union(&other.Y),
+        }
+    }
+
+    /// Grows both ranges by `fraction` of their size on each side, see
+    /// [`ImPlotRange::expand`].
+    pub fn expand(&self, fraction: f64) -> ImPlotRect {
+        ImPlotRect {
+            X: self.X.expand(fraction),
+            Y: self.Y.expand(fraction),
+        }
+    }
+}
+
+impl ImPlotTime {
+    /// Creates a time from seconds and microseconds since the Unix epoch, rolling excess
+    /// microseconds over into seconds like the C++ constructor does.
+    pub fn new(seconds: i64, microseconds: i32) -> Self {
+        let mut time = ImPlotTime {
+            S: seconds as time_t,
+            Us: microseconds,
+        };
+        time.roll_over();
+        time
+    }
+
+    /// Creates a time from (fractional) seconds since the Unix epoch, as used for values on
+    /// time axes.
+    pub fn from_double(t: f64) -> Self {
+        let seconds = t.floor();
+        ImPlotTime::new(seconds as i64, ((t - seconds) * 1_000_000.0).round() as i32)
+    }
+
+    /// Seconds since the Unix epoch, as used for values on time axes.
+    pub fn to_double(&self) -> f64 {
+        self.S as f64 + self.Us as f64 / 1_000_000.0
+    }
+
+    fn roll_over(&mut self) {
+        self.S += (self.Us / 1_000_000) as time_t;
+        self.Us %= 1_000_000;
+        if self.Us < 0 {
+            self.S -= 1;
+            self.Us += 1_000_000;
+        }
+    }
+}
+
+/// Called by `IM_ASSERT` in the compiled ImPlot sources instead of `assert()`, see
+/// include/implot_rs_assert.h. Panics with the failed expression, its location and the title of
+/// the plot being drawn, if any. This is mostly hit when the wrapper is misused, e.g. by drawing
+/// items outside of a plot.
+///
+/// The `ImPlot_*` functions are declared `extern "C-unwind"` (see implot-sys-bindgen), so the
+/// panic unwinds through ImPlot back to the Rust code that called into it and can be caught there.
+#[doc(hidden)]
+#[no_mangle]
+pub unsafe extern "C-unwind" fn implot_rs_assert_failed(
+    expr: *const c_char,
+    file: *const c_char,
+    line: c_int,
+) {
+    let to_string = |ptr: *const c_char| {
+        if ptr.is_null() {
+            "<unknown>".into()
+        } else {
+            CStr::from_ptr(ptr).to_string_lossy()
+        }
+    };
+    let message = format!(
+        "ImPlot assertion failed: {} at {}:{}",
+        to_string(expr),
+        to_string(file),
+        line
+    );
+
+    // Unwinding out of here while already unwinding would abort anyway, but without saying why
+    if std::thread::panicking() {
+        eprintln!("{} (while panicking)", message);
+        std::process::abort();
+    }
+
+    let plot = if ImPlot_GetCurrentContext().is_null() {
+        std::ptr::null_mut()
+    } else {
+        ImPlot_GetCurrentPlot()
+    };
+    if plot.is_null() {
+        panic!("{}", message);
+    } else {
+        panic!(
+            "{} (in plot \"{}\")",
+            message,
+            to_string(ImPlotPlot_GetTitle(plot))
+        );
+    }
+}
+
+/// Implements slice and iterator views over the generated `ImVector_*` types, so that code
+/// reading ImPlot's internal data (colormap tables, annotation collections, ...) does not need
+/// to do pointer arithmetic itself. Mirrors what imgui-sys does for its generic `ImVector<T>`.
+macro_rules! impl_imvector {
+    ($($vector:ident => $element:ty),* $(,)?) => {
+        $(
+            impl $vector {
+                /// Number of elements in the vector.
+                #[inline]
+                pub fn len(&self) -> usize {
+                    self.Size.max(0) as usize
+                }
+
+                /// Returns true if the vector contains no elements.
+                #[inline]
+                pub fn is_empty(&self) -> bool {
+                    self.len() == 0
+                }
+
+                /// View the contents of the vector as a slice.
+                #[inline]
+                pub fn as_slice(&self) -> &[$element] {
+                    if self.Data.is_null() || self.is_empty() {
+                        &[]
+                    } else {
+                        unsafe { slice::from_raw_parts(self.Data, self.len()) }
+                    }
+                }
+
+                /// View the contents of the vector as a mutable slice.
+                #[inline]
+                pub fn as_mut_slice(&mut self) -> &mut [$element] {
+                    if self.Data.is_null() || self.is_empty() {
+                        &mut []
+                    } else {
+                        unsafe { slice::from_raw_parts_mut(self.Data, self.len()) }
+                    }
+                }
+
+                /// Iterate over the elements of the vector.
+                #[inline]
+                pub fn iter(&self) -> slice::Iter<'_, $element> {
+                    self.as_slice().iter()
+                }
+            }
+
+            impl<'a> IntoIterator for &'a $vector {
+                type Item = &'a $element;
+                type IntoIter = slice::Iter<'a, $element>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.iter()
+                }
+            }
+        )*
+    };
+}
+
+impl_imvector!(
+    ImVector_bool => bool,
+    ImVector_char => ::std::os::raw::c_char,
+    ImVector_double => f64,
+    ImVector_float => f32,
+    ImVector_int => ::std::os::raw::c_int,
+    ImVector_ImS8 => ImS8,
+    ImVector_ImU8 => ImU8,
+    ImVector_ImS16 => ImS16,
+    ImVector_ImU16 => ImU16,
+    ImVector_ImS32 => ImS32,
+    ImVector_ImU32 => ImU32,
+    ImVector_ImS64 => ImS64,
+    ImVector_ImU64 => ImU64,
+    ImVector_ImVec2 => ImVec2,
+    ImVector_ImVec4 => ImVec4,
+    ImVector_ImPlotAlignmentData => ImPlotAlignmentData,
+    ImVector_ImPlotAnnotation => ImPlotAnnotation,
+    ImVector_ImPlotColormap => ImPlotColormap,
+    ImVector_ImPlotItem => ImPlotItem,
+    ImVector_ImPlotPlot => ImPlotPlot,
+    ImVector_ImPlotRange => ImPlotRange,
+    ImVector_ImPlotSubplot => ImPlotSubplot,
+    ImVector_ImPlotTag => ImPlotTag,
+    ImVector_ImPlotTick => ImPlotTick,
+);
+
+/// Implements conversions between the plain (non-flag) enums and the `int` typedefs the C API
+/// takes them as, so the safe layer doesn't need `as` casts. Converting from an integer fails
+/// with the integer itself if it is not one of the variants.
+macro_rules! impl_enum_conversions {
+    ($($enum:ident { $($variant:ident),* $(,)? })*) => {
+        $(
+            impl From<$enum> for c_int {
+                #[inline]
+                fn from(value: $enum) -> c_int {
+                    value as c_int
+                }
+            }
+
+            impl TryFrom<c_int> for $enum {
+                type Error = c_int;
+
+                fn try_from(raw: c_int) -> Result<Self, c_int> {
+                    $(
+                        if raw == $enum::$variant as c_int {
+                            return Ok($enum::$variant);
+                        }
+                    )*
+                    Err(raw)
+                }
+            }
+        )*
+    };
+}
+
+impl_enum_conversions! {
+    ImAxis_ { X1, X2, X3, Y1, Y2, Y3, COUNT }
+    ImPlotCol_ {
+        Line, Fill, MarkerOutline, MarkerFill, ErrorBar, FrameBg, PlotBg, PlotBorder, LegendBg,
+        LegendBorder, LegendText, TitleText, InlayText, AxisText, AxisGrid, AxisTick, AxisBg,
+        AxisBgHovered, AxisBgActive, Selection, Crosshairs, COUNT,
+    }
+    ImPlotCond_ { None, Always, Once }
+    ImPlotStyleVar_ {
+        LineWeight, Marker, MarkerSize, MarkerWeight, FillAlpha, ErrorBarSize, ErrorBarWeight,
+        DigitalBitHeight, DigitalBitGap, PlotBorderSize, MinorAlpha, MajorTickLen, MinorTickLen,
+        MajorTickSize, MinorTickSize, MajorGridSize, MinorGridSize, PlotPadding, LabelPadding,
+        LegendPadding, LegendInnerPadding, LegendSpacing, MousePosPadding, AnnotationPadding,
+        FitPadding, PlotDefaultSize, PlotMinSize, COUNT,
+    }
+    ImPlotScale_ { Linear, Time, Log10, SymLog }
+    ImPlotMarker_ {
+        None, Circle, Square, Diamond, Up, Down, Left, Right, Cross, Plus, Asterisk, COUNT,
+    }
+    ImPlotColormap_ {
+        Deep, Dark, Pastel, Paired, Viridis, Plasma, Hot, Cool, Pink, Jet, Twilight, RdBu, BrBG,
+        PiYG, Spectral, Greys,
+    }
+    ImPlotLocation_ {
+        Center, North, South, West, East, NorthWest, NorthEast, SouthWest, SouthEast,
+    }
+    ImPlotBin_ { Sqrt, Sturges, Rice, Scott }
+    ImPlotTimeUnit_ { Us, Ms, S, Min, Hr, Day, Mo, Yr, COUNT }
+    ImPlotDateFmt_ { None, DayMo, DayMoYr, MoYr, Mo, Yr }
+    ImPlotTimeFmt_ { None, Us, SUs, SMs, S, MinSMs, HrMinSMs, HrMinS, HrMin, Hr }
+}
+
+impl Default for ImPlotStyle {
+    /// Creates a style initialized by the C++ constructor, which is what ImPlot itself uses
+    /// for a fresh context. A zeroed `ImPlotStyle` is not a usable style.
+    fn default() -> Self {
+        unsafe {
+            let raw = ImPlotStyle_ImPlotStyle();
+            let style = *raw;
+            ImPlotStyle_destroy(raw);
+            style
+        }
+    }
+}
+
+impl Default for ImPlotInputMap {
+    /// Creates an input map initialized by the C++ constructor, i.e. with the default
+    /// mouse and modifier mapping.
+    fn default() -> Self {
+        unsafe {
+            let raw = ImPlotInputMap_ImPlotInputMap();
+            let input_map = *raw;
+            ImPlotInputMap_destroy(raw);
+            input_map
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,4 +507,83 @@ mod tests {
         assert_eq!(im_range.Min, imvec.x as f64);
         assert_eq!(im_range.Max, imvec.y as f64);
     }
+
+    #[test]
+    fn test_enum_conversions() {
+        assert_eq!(c_int::from(ImPlotMarker_::None), -1);
+        assert_eq!(ImPlotMarker_::try_from(7), Ok(ImPlotMarker_::Cross));
+        assert_eq!(ImAxis_::try_from(c_int::from(ImAxis_::Y2)), Ok(ImAxis_::Y2));
+        // Locations are bit combinations, not all of which are valid
+        assert_eq!(ImPlotLocation_::try_from(3), Err(3));
+    }
+
+    #[test]
+    fn test_range_and_rect_geometry() {
+        let range = ImPlotRange::new(0.0, 10.0);
+        assert!(range.contains(10.0));
+        assert!(!range.contains(-0.5));
+        assert_eq!(range.size(), 10.0);
+        assert_eq!(range.clamp(12.0), 10.0);
+        let overlap = range.intersect(&ImPlotRange::new(5.0, 20.0)).unwrap();
+        assert_eq!((overlap.Min, overlap.Max), (5.0, 10.0));
+        assert!(range.intersect(&ImPlotRange::new(11.0, 20.0)).is_none());
+        let expanded = range.expand(0.1);
+        assert_eq!((expanded.Min, expanded.Max), (-1.0, 11.0));
+
+        let rect = ImPlotRect::new([0.0, 4.0], [0.0, 2.0]);
+        assert!(rect.contains(ImPlotPoint { x: 1.0, y: 1.0 }));
+        assert!(!rect.contains(ImPlotPoint { x: 1.0, y: 3.0 }));
+        let size = rect.size();
+        assert_eq!((size.x, size.y), (4.0, 2.0));
+        let clamped = rect.clamp(ImPlotPoint { x: -1.0, y: 5.0 });
+        assert_eq!((clamped.x, clamped.y), (0.0, 2.0));
+        assert!(rect
+            .intersect(&ImPlotRect::new([5.0, 6.0], [0.0, 1.0]))
+            .is_none());
+    }
+
+    #[test]
+    fn test_plot_time_conversion() {
+        let time = ImPlotTime::from_double(1_600_000_000.25);
+        assert_eq!(time.S as i64, 1_600_000_000);
+        assert_eq!(time.Us, 250_000);
+        assert_eq!(time.to_double(), 1_600_000_000.25);
+
+        let rolled = ImPlotTime::new(10, 2_500_000);
+        assert_eq!((rolled.S as i64, rolled.Us), (12, 500_000));
+        let negative = ImPlotTime::new(10, -250_000);
+        assert_eq!((negative.S as i64, negative.Us), (9, 750_000));
+    }
+
+    #[test]
+    fn test_default_style_and_input_map() {
+        let style = ImPlotStyle::default();
+        assert_eq!(style.LineWeight, 1.0);
+        assert_eq!(style.Marker, ImPlotMarker_::None as i32);
+
+        let input_map = ImPlotInputMap::default();
+        assert!(input_map.ZoomRate > 0.0);
+    }
+
+    #[test]
+    fn test_imvector_slice_views() {
+        let mut data = vec![1.0, 2.0, 3.0];
+        let mut vector = ImVector_double {
+            Size: data.len() as i32,
+            Capacity: data.len() as i32,
+            Data: data.as_mut_ptr(),
+        };
+        assert_eq!(vector.len(), 3);
+        assert_eq!(vector.as_slice(), &[1.0, 2.0, 3.0]);
+        vector.as_mut_slice()[1] = 5.0;
+        assert_eq!(vector.iter().sum::<f64>(), 9.0);
+
+        let empty = ImVector_double {
+            Size: 0,
+            Capacity: 0,
+            Data: std::ptr::null_mut(),
+        };
+        assert!(empty.is_empty());
+        assert!(empty.as_slice().is_empty());
+    }
 }
@@ -0,0 +1,77 @@
+//! # ndarray module
+//!
+//! This module (enabled by the `ndarray` feature) allows plotting `ndarray` views directly.
+//! Arrays that are contiguous in memory are plotted without copying, which covers arrays in
+//! standard (C) order and, for heatmaps, Fortran order. Other layouts, e.g. views with a step
+//! or reversed axes, are copied into a contiguous buffer first:
+//! ```no_run
+//! # use implot::{Context, Plot, PlotHeatmap, PlotLine};
+//! # use ndarray::{Array1, Array2, ShapeBuilder};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! let x = Array1::linspace(0.0, 1.0, 100);
+//! let y = x.mapv(|x: f64| x.sin());
+//! let image = Array2::<f64>::zeros((64, 64).f());
+//! Plot::new("Arrays").build(&plot_ui, |_| {
+//!     PlotLine::new("sin").plot_array(x.view(), y.view());
+//!     PlotHeatmap::new("image").plot_array(image.view());
+//! });
+//! ```
+
+use crate::{PlotBars, PlotHeatmap, PlotLine, PlotScalar, PlotScatter};
+use ndarray::{ArrayView1, ArrayView2};
+
+impl PlotLine {
+    /// Plot a line from one-dimensional arrays, see [`PlotLine::plot`].
+    pub fn plot_array<T: PlotScalar>(&self, x: ArrayView1<T>, y: ArrayView1<T>) {
+        // Copies the arrays only if they are not contiguous already, and standard layout arrays
+        // always have a slice
+        let (x, y) = (x.as_standard_layout(), y.as_standard_layout());
+        self.plot(x.as_slice().unwrap(), y.as_slice().unwrap());
+    }
+}
+
+impl PlotScatter {
+    /// Plot points from one-dimensional arrays, see [`PlotScatter::plot`].
+    pub fn plot_array<T: PlotScalar>(&self, x: ArrayView1<T>, y: ArrayView1<T>) {
+        let (x, y) = (x.as_standard_layout(), y.as_standard_layout());
+        self.plot(x.as_slice().unwrap(), y.as_slice().unwrap());
+    }
+}
+
+impl PlotBars {
+    /// Plot bars from one-dimensional arrays, see [`PlotBars::plot`].
+    pub fn plot_array<T: PlotScalar>(
+        &self,
+        axis_positions: ArrayView1<T>,
+        bar_values: ArrayView1<T>,
+        horizontal: bool,
+    ) {
+        let axis_positions = axis_positions.as_standard_layout();
+        let bar_values = bar_values.as_standard_layout();
+        self.plot(
+            axis_positions.as_slice().unwrap(),
+            bar_values.as_slice().unwrap(),
+            horizontal,
+        );
+    }
+}
+
+impl PlotHeatmap {
+    /// Plot a heatmap from a two-dimensional array, with rows along the first axis. Arrays in
+    /// Fortran order are plotted as column-major data without copying them.
+    pub fn plot_array<T: PlotScalar>(&self, values: ArrayView2<T>) {
+        let (rows, cols) = values.dim();
+        if rows == 0 || cols == 0 {
+            return;
+        }
+        if let Some(row_major) = values.to_slice() {
+            self.plot(row_major, rows as u32, cols as u32, false);
+        } else if let Some(col_major) = values.t().to_slice() {
+            self.plot(col_major, rows as u32, cols as u32, true);
+        } else {
+            let values = values.as_standard_layout();
+            self.plot(values.as_slice().unwrap(), rows as u32, cols as u32, false);
+        }
+    }
+}
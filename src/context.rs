@@ -3,18 +3,51 @@
 //! also helps readability if one is already familiar with the imgui code.
 
 use parking_lot::ReentrantMutex;
+use std::cell::Cell;
 
-use crate::{sys, PlotUi};
+use crate::{stack_tracking, sys, PlotUi};
 
 /// An implot context.
 ///
 /// A context is required to do most of the things this library provides. While this was created
-/// implicitly in earlier versions of the library, it is now created explicitly. These contexts
-/// cannot currently be disabled through the high level API. This could be implemented though,
-/// if you need multiple contexts that you can switch around between, file an issue.
+/// implicitly in earlier versions of the library, it is now created explicitly. Only one context
+/// is active at a time. To use several, e.g. one per imgui context, the inactive ones are kept as
+/// [`SuspendedContext`]s:
+/// ```no_run
+/// # use implot::{Context, SuspendedContext};
+/// let main = Context::create();
+/// let mut tool = SuspendedContext::create();
+/// // Plot into the main context, then switch over
+/// let main = main.suspend();
+/// let tool_active = tool.activate().unwrap_or_else(|_| panic!("another context is active"));
+/// // Plot into the tool context
+/// tool = tool_active.suspend();
+/// let main = main.activate().unwrap_or_else(|_| panic!("another context is active"));
+/// # drop((main, tool));
+/// ```
 #[rustversion::attr(since(1.48), doc(alias = "ImPlotContext"))]
 pub struct Context {
     raw: *mut sys::ImPlotContext,
+    token_drop_policy: Cell<TokenDropPolicy>,
+}
+
+/// What to do when a [`PlotToken`](crate::PlotToken) is dropped without `end()` having been
+/// called on it, e.g. because of an early return.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenDropPolicy {
+    /// Panic, pointing out the plot that was not ended. This is the default, since it finds
+    /// such bugs quickly during development.
+    Panic,
+    /// Print an error to stderr and end the plot, so rendering can carry on.
+    LogError,
+    /// Silently end the plot.
+    AutoEnd,
+}
+
+impl Default for TokenDropPolicy {
+    fn default() -> Self {
+        TokenDropPolicy::Panic
+    }
 }
 
 // This mutex is used to guard any accesses to the context
@@ -28,7 +61,8 @@ pub fn no_current_context() -> bool {
 
 impl Context {
     /// Create a context. This will also activate the context in ImPlot, and hence creating
-    /// a second context when one already exists is an error and will panic.
+    /// a second context when one already exists is an error and will panic. To create more
+    /// contexts, use [`SuspendedContext::create`].
     pub fn create() -> Self {
         let _guard = CTX_MUTEX.lock();
         assert!(
@@ -40,15 +74,97 @@ impl Context {
         unsafe {
             sys::ImPlot_SetCurrentContext(ctx);
         }
-        Self { raw: ctx }
+        Self {
+            raw: ctx,
+            token_drop_policy: Cell::new(TokenDropPolicy::default()),
+        }
+    }
+
+    /// Deactivate the context, so that another one can be activated or created.
+    #[rustversion::attr(since(1.48), doc(alias = "SetCurrentContext"))]
+    pub fn suspend(self) -> SuspendedContext {
+        let _guard = CTX_MUTEX.lock();
+        if self.is_current_context() {
+            unsafe { sys::ImPlot_SetCurrentContext(std::ptr::null_mut()) };
+        }
+        SuspendedContext(self)
+    }
+
+    /// Make this context the current one in ImPlot again, in case the current context was
+    /// changed behind this library's back, e.g. through [`crate::sys`] or by C++ code sharing
+    /// the process.
+    #[rustversion::attr(since(1.48), doc(alias = "SetCurrentContext"))]
+    pub fn set_as_current(&self) {
+        let _guard = CTX_MUTEX.lock();
+        unsafe { sys::ImPlot_SetCurrentContext(self.raw) };
+    }
+
+    /// Returns true if this is the current context in ImPlot.
+    pub fn is_current_context(&self) -> bool {
+        unsafe { sys::ImPlot_GetCurrentContext() == self.raw }
+    }
+
+    /// Make ImPlot use the given imgui context. This is needed when ImPlot and imgui don't
+    /// share imgui's global context pointer, e.g. because they are linked into different
+    /// libraries. `imgui::Context` is only available while it is active, so its raw context
+    /// is the current one; otherwise, see [`Context::set_imgui_context_raw`].
+    #[rustversion::attr(since(1.48), doc(alias = "SetImGuiContext"))]
+    pub fn set_imgui_context(&self, _imgui: &imgui::Context) {
+        unsafe { self.set_imgui_context_raw(sys::igGetCurrentContext()) }
+    }
+
+    /// Make ImPlot use the given raw imgui context, e.g. one obtained from another library.
+    ///
+    /// # Safety
+    /// The pointer has to point to a valid imgui context that outlives its use by ImPlot.
+    #[rustversion::attr(since(1.48), doc(alias = "SetImGuiContext"))]
+    pub unsafe fn set_imgui_context_raw(&self, imgui: *mut sys::ImGuiContext) {
+        let _guard = CTX_MUTEX.lock();
+        sys::ImPlot_SetImGuiContext(imgui);
+    }
+
+    /// Set what happens when a plot token is dropped without being ended, see
+    /// [`TokenDropPolicy`]. Applications may want to choose robustness over strictness in
+    /// release builds, for example.
+    pub fn set_token_drop_policy(&self, policy: TokenDropPolicy) {
+        self.token_drop_policy.set(policy);
+    }
+
+    /// Get the current policy for plot tokens dropped without being ended.
+    pub fn token_drop_policy(&self) -> TokenDropPolicy {
+        self.token_drop_policy.get()
     }
 
     /// Get a "plot ui" struct, this will be used to build actual plots and is quite
     /// analogous to imgui-rs' "Ui" struct.
+    ///
+    /// In debug builds, this also reports colormap and style tokens that were leaked in earlier
+    /// frames, according to the [`TokenDropPolicy`].
     pub fn get_plot_ui(&self) -> PlotUi {
+        stack_tracking::check_leaks(self.token_drop_policy());
         PlotUi { context: self }
     }
 
+    /// Show time axes of this context's plots in local time instead of UTC. Same as setting
+    /// [`Style::use_local_time`](crate::Style::use_local_time). Like the other time settings,
+    /// this borrows the context mutably, so it can't be changed while a [`PlotUi`] is in use.
+    pub fn set_use_local_time(&mut self, use_local_time: bool) {
+        unsafe { (*self.raw).Style.UseLocalTime = use_local_time }
+    }
+
+    /// Use the 24 hour clock instead of AM/PM on time axes of this context's plots. Same as
+    /// setting [`Style::use_24_hour_clock`](crate::Style::use_24_hour_clock).
+    pub fn set_use_24_hour_clock(&mut self, use_24_hour_clock: bool) {
+        unsafe { (*self.raw).Style.Use24HourClock = use_24_hour_clock }
+    }
+
+    /// Show dates in ISO 8601 format (`2024-03-15`) instead of the US format (`3/15/24`) on
+    /// time axes of this context's plots. Same as setting
+    /// [`Style::use_iso8601`](crate::Style::use_iso8601).
+    pub fn set_use_iso8601(&mut self, use_iso8601: bool) {
+        unsafe { (*self.raw).Style.UseISO8601 = use_iso8601 }
+    }
+
     /// Use light colors for the implot style.
     ///
     /// This will eventually be exposed more thoroughly in the form of ImPlotStyle,
@@ -89,8 +205,44 @@ impl Context {
 impl Drop for Context {
     fn drop(&mut self) {
         let _guard = CTX_MUTEX.lock();
+        // This also clears the current context if it is this one
         unsafe {
             sys::ImPlot_DestroyContext(self.raw);
         }
     }
 }
+
+/// A context that is not active, see [`Context`] on using several contexts. Dropping it
+/// destroys the context.
+pub struct SuspendedContext(Context);
+
+impl SuspendedContext {
+    /// Create a new context without activating it, so it can be created while another context
+    /// is active.
+    pub fn create() -> Self {
+        let _guard = CTX_MUTEX.lock();
+        // ImPlot makes a new context current if there is none, which is undone here
+        let previous = unsafe { sys::ImPlot_GetCurrentContext() };
+        let ctx = unsafe { sys::ImPlot_CreateContext() };
+        unsafe {
+            sys::ImPlot_SetCurrentContext(previous);
+        }
+        SuspendedContext(Context {
+            raw: ctx,
+            token_drop_policy: Cell::new(TokenDropPolicy::default()),
+        })
+    }
+
+    /// Activate the context. This fails if another context is active, in which case the
+    /// suspended context is given back in the error.
+    #[rustversion::attr(since(1.48), doc(alias = "SetCurrentContext"))]
+    pub fn activate(self) -> Result<Context, SuspendedContext> {
+        let _guard = CTX_MUTEX.lock();
+        if no_current_context() {
+            unsafe { sys::ImPlot_SetCurrentContext(self.0.raw) };
+            Ok(self.0)
+        } else {
+            Err(self)
+        }
+    }
+}
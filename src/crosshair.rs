@@ -0,0 +1,246 @@
+//! # Crosshair module
+//!
+//! This module adds a crosshair overlay to plots, as found in most monitoring UIs: while the plot
+//! is hovered, lines through the mouse position are drawn and a tooltip lists the value of each
+//! series at the X value closest to the mouse:
+//! ```no_run
+//! # use implot::{Context, Plot, PlotLine};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! # let (x, cpu, memory) = (vec![0.0, 1.0], vec![0.2, 0.4], vec![0.5, 0.6]);
+//! Plot::new("Load").build(&plot_ui, |plot| {
+//!     PlotLine::new("cpu").plot(&x, &cpu);
+//!     PlotLine::new("memory").plot(&x, &memory);
+//!     plot.crosshair_tooltip([("cpu", &x[..], &cpu[..]), ("memory", &x[..], &memory[..])]);
+//! });
+//! ```
+//!
+//! It also finds the data point closest to the mouse in pixel space, for inspecting single
+//! points, see [`PlotToken::hovered_point`].
+
+use crate::{sys, AxisChoice, ImPlotPoint, ImVec2, ImVec4, PlotColorElement, PlotToken};
+use std::fmt::Write;
+use std::os::raw::c_char;
+
+/// Index of the value in the sorted `xs` closest to `x`, or `None` if `xs` is empty.
+fn nearest_index(xs: &[f64], x: f64) -> Option<usize> {
+    if xs.is_empty() {
+        return None;
+    }
+    let after = xs.partition_point(|&value| value < x);
+    if after == 0 {
+        Some(0)
+    } else if after == xs.len() || x - xs[after - 1] <= xs[after] - x {
+        Some(after - 1)
+    } else {
+        Some(after)
+    }
+}
+
+/// A data point close to the mouse, found by [`PlotToken::hovered_point`].
+#[derive(Clone, Copy, Debug)]
+pub struct HoveredPoint {
+    /// Index of the series the point belongs to, in the order the series were given.
+    pub series: usize,
+    /// Index of the point within its series.
+    pub index: usize,
+    /// Position of the point, in plot coordinates.
+    pub point: ImPlotPoint,
+}
+
+/// The candidate closest to `target` that is at most `max_distance` away from it, as the
+/// series and point indices it came with.
+fn closest(
+    candidates: impl Iterator<Item = (usize, usize, ImVec2)>,
+    target: ImVec2,
+    max_distance: f32,
+) -> Option<(usize, usize)> {
+    let mut best = None;
+    let mut best_distance = max_distance * max_distance;
+    for (series, index, position) in candidates {
+        let (dx, dy) = (position.x - target.x, position.y - target.y);
+        let distance = dx * dx + dy * dy;
+        if distance <= best_distance {
+            best = Some((series, index));
+            best_distance = distance;
+        }
+    }
+    best
+}
+
+/// The current color of a style element, with automatic colors resolved.
+fn style_color(element: PlotColorElement) -> ImVec4 {
+    let mut color = ImVec4 {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 0.0,
+    };
+    unsafe { sys::ImPlot_GetStyleColorVec4(&mut color, element.into()) };
+    color
+}
+
+impl PlotToken {
+    /// While the plot is hovered, draw crosshair lines through the mouse position and show a
+    /// tooltip with the value of each series at the X value closest to the mouse. The series
+    /// are given as `(label, xs, ys)`, with the X values sorted, and use the first X and Y axes.
+    /// Call this after plotting the items, so the crosshair is drawn on top of them. Returns
+    /// whether the plot was hovered.
+    pub fn crosshair_tooltip<'a, I>(&self, series: I) -> bool
+    where
+        I: IntoIterator<Item = (&'a str, &'a [f64], &'a [f64])>,
+    {
+        if !self.is_plot_hovered() {
+            return false;
+        }
+
+        let mouse = self.get_plot_mouse_position(Some(AxisChoice::X1), Some(AxisChoice::Y1));
+        let mouse_pixels =
+            self.plot_to_pixels_f32(mouse.x, mouse.y, AxisChoice::X1, AxisChoice::Y1);
+        let mut plot_position = ImVec2 { x: 0.0, y: 0.0 };
+        let mut plot_size = ImVec2 { x: 0.0, y: 0.0 };
+        let color = style_color(PlotColorElement::Crosshairs);
+        unsafe {
+            sys::ImPlot_GetPlotPos(&mut plot_position);
+            sys::ImPlot_GetPlotSize(&mut plot_size);
+        }
+
+        {
+            let _clip = self.push_plot_clip_rect(0.0);
+            let draw_list = self.get_plot_draw_list();
+            draw_list.add_line(
+                ImVec2 {
+                    x: mouse_pixels.x,
+                    y: plot_position.y,
+                },
+                ImVec2 {
+                    x: mouse_pixels.x,
+                    y: plot_position.y + plot_size.y,
+                },
+                color,
+                1.0,
+            );
+            draw_list.add_line(
+                ImVec2 {
+                    x: plot_position.x,
+                    y: mouse_pixels.y,
+                },
+                ImVec2 {
+                    x: plot_position.x + plot_size.x,
+                    y: mouse_pixels.y,
+                },
+                color,
+                1.0,
+            );
+        }
+
+        let mut text = format!("x: {:.4}", mouse.x);
+        for (label, xs, ys) in series {
+            let count = xs.len().min(ys.len());
+            if let Some(index) = nearest_index(&xs[..count], mouse.x) {
+                // Writing to a String does not fail
+                let _ = write!(text, "\n{}: {:.4}", label, ys[index]);
+            }
+        }
+        // The end pointer is passed, so the text does not need to be null-terminated
+        let range = text.as_bytes().as_ptr_range();
+        unsafe {
+            sys::igBeginTooltip();
+            sys::igTextUnformatted(range.start as *const c_char, range.end as *const c_char);
+            sys::igEndTooltip();
+        }
+        true
+    }
+
+    /// While the plot is hovered, find the data point closest to the mouse that is at most
+    /// `max_distance` pixels away from it, and highlight it with a circle. The series are
+    /// given as `(xs, ys)` and use the first X and Y axes. Every point is converted to pixels,
+    /// so for series with many points, consider plotting and searching a downsampled version
+    /// (see [`lttb`](crate::lttb)). Call this after plotting the items:
+    /// ```no_run
+    /// # use implot::{Context, Plot, PlotScatter};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// # let (x, y) = (vec![0.0, 1.0], vec![2.0, 3.0]);
+    /// Plot::new("Measurements").build(&plot_ui, |plot| {
+    ///     PlotScatter::new("samples").plot(&x, &y);
+    ///     if let Some(hovered) = plot.hovered_point([(&x[..], &y[..])], 10.0) {
+    ///         // Show details of sample `hovered.index`
+    ///     }
+    /// });
+    /// ```
+    pub fn hovered_point<'a, I>(&self, series: I, max_distance: f32) -> Option<HoveredPoint>
+    where
+        I: IntoIterator<Item = (&'a [f64], &'a [f64])>,
+    {
+        if !self.is_plot_hovered() {
+            return None;
+        }
+
+        let mouse = self.get_plot_mouse_position(Some(AxisChoice::X1), Some(AxisChoice::Y1));
+        let to_pixels =
+            |x: f64, y: f64| self.plot_to_pixels_f32(x, y, AxisChoice::X1, AxisChoice::Y1);
+        let series: Vec<_> = series.into_iter().collect();
+        let candidates = series
+            .iter()
+            .enumerate()
+            .flat_map(|(series_index, (xs, ys))| {
+                xs.iter()
+                    .zip(ys.iter())
+                    .enumerate()
+                    .map(move |(index, (&x, &y))| (series_index, index, to_pixels(x, y)))
+            });
+        let (series_index, index) = closest(candidates, to_pixels(mouse.x, mouse.y), max_distance)?;
+
+        let (xs, ys) = series[series_index];
+        let point = ImPlotPoint {
+            x: xs[index],
+            y: ys[index],
+        };
+        let _clip = self.push_plot_clip_rect(0.0);
+        self.get_plot_draw_list().add_circle(
+            to_pixels(point.x, point.y),
+            max_distance.min(6.0),
+            style_color(PlotColorElement::Crosshairs),
+            2.0,
+        );
+        Some(HoveredPoint {
+            series: series_index,
+            index,
+            point,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_index() {
+        let xs = [0.0, 1.0, 2.0, 4.0];
+        assert_eq!(nearest_index(&xs, -3.0), Some(0));
+        assert_eq!(nearest_index(&xs, 0.4), Some(0));
+        assert_eq!(nearest_index(&xs, 0.6), Some(1));
+        assert_eq!(nearest_index(&xs, 2.0), Some(2));
+        assert_eq!(nearest_index(&xs, 3.5), Some(3));
+        assert_eq!(nearest_index(&xs, 10.0), Some(3));
+        assert_eq!(nearest_index(&[], 1.0), None);
+    }
+
+    #[test]
+    fn test_closest_within_distance() {
+        let at = |x, y| ImVec2 { x, y };
+        let candidates = vec![
+            (0, 0, at(0.0, 0.0)),
+            (0, 1, at(10.0, 0.0)),
+            (1, 0, at(4.0, 3.0)),
+        ];
+        let target = at(5.0, 5.0);
+        assert_eq!(
+            closest(candidates.clone().into_iter(), target, 10.0),
+            Some((1, 0))
+        );
+        assert_eq!(closest(candidates.into_iter(), target, 1.0), None);
+    }
+}
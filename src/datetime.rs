@@ -0,0 +1,63 @@
+//! # chrono timestamps module
+//!
+//! This module (enabled by the `chrono` feature) allows plotting time series with `chrono`
+//! timestamps as X values, which are converted to the seconds since the Unix epoch used on time
+//! axes (see the `time` module):
+//! ```no_run
+//! # use implot::{AxisChoice, Context, Plot, PlotCond, PlotLine};
+//! # use chrono::{Duration, TimeZone, Utc};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! let end = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+//! let times: Vec<_> = (0..60).map(|minute| end - Duration::minutes(60 - minute)).collect();
+//! let load: Vec<f64> = (0..60).map(|minute| (minute % 7) as f64).collect();
+//! Plot::new("Load")
+//!     .datetime_axis(AxisChoice::X1, times[0], end, PlotCond::Once)
+//!     .build(&plot_ui, |_| {
+//!         PlotLine::new("load").plot_datetime(&times, &load);
+//!     });
+//! ```
+
+use crate::{AxisChoice, Plot, PlotCond, PlotLine, PlotScatter};
+use chrono::{DateTime, TimeZone};
+
+/// Convert a timestamp to the value used on time axes, keeping sub-second precision.
+pub fn datetime_value<Tz: TimeZone>(time: &DateTime<Tz>) -> f64 {
+    time.timestamp() as f64 + f64::from(time.timestamp_subsec_nanos()) * 1e-9
+}
+
+/// Convert timestamps to the values used on time axes, see [`datetime_value`].
+pub fn datetime_values<Tz: TimeZone>(times: &[DateTime<Tz>]) -> Vec<f64> {
+    times.iter().map(datetime_value).collect()
+}
+
+impl PlotLine {
+    /// Plot a line with timestamps as X values. Best used on an axis set up with
+    /// [`Plot::datetime_axis`] or [`Plot::with_time_axis`].
+    pub fn plot_datetime<Tz: TimeZone>(&self, x: &[DateTime<Tz>], y: &[f64]) {
+        self.plot(&datetime_values(x), y);
+    }
+}
+
+impl PlotScatter {
+    /// Plot points with timestamps as X values. Best used on an axis set up with
+    /// [`Plot::datetime_axis`] or [`Plot::with_time_axis`].
+    pub fn plot_datetime<Tz: TimeZone>(&self, x: &[DateTime<Tz>], y: &[f64]) {
+        self.plot(&datetime_values(x), y);
+    }
+}
+
+impl Plot {
+    /// Turn the given axis into a time axis showing the range from `start` to `end`.
+    pub fn datetime_axis<Tz: TimeZone>(
+        self,
+        axis_choice: AxisChoice,
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        condition: PlotCond,
+    ) -> Self {
+        let limits = [datetime_value(&start), datetime_value(&end)];
+        self.with_time_axis(axis_choice)
+            .axis_limits(limits, axis_choice, condition)
+    }
+}
@@ -0,0 +1,52 @@
+//! # Deque plotting module
+//!
+//! This module allows plotting sliding windows of data kept in `VecDeque`s, e.g. the last few
+//! thousand samples of a sensor, without making them contiguous first. The values are read
+//! through ImPlot's getter-based functions, so the wrap-around point of the deque does not show
+//! up as a gap in lines:
+//! ```no_run
+//! # use implot::PlotLine;
+//! # use std::collections::VecDeque;
+//! let mut times: VecDeque<f64> = VecDeque::with_capacity(1000);
+//! let mut values: VecDeque<f64> = VecDeque::with_capacity(1000);
+//! # let (time, value) = (0.0, 0.0);
+//! // For every new sample:
+//! if times.len() == 1000 {
+//!     times.pop_front();
+//!     values.pop_front();
+//! }
+//! times.push_back(time);
+//! values.push_back(value);
+//! // Every frame:
+//! PlotLine::new("sensor").plot_deque(&times, &values);
+//! ```
+
+use crate::{ImPlotPoint, PlotLine, PlotScalar, PlotScatter};
+use std::collections::VecDeque;
+
+/// Getter for the point with the given index of two deques.
+fn deque_point<'a, T: PlotScalar>(
+    x: &'a VecDeque<T>,
+    y: &'a VecDeque<T>,
+) -> impl FnMut(usize) -> ImPlotPoint + 'a {
+    move |index| ImPlotPoint {
+        x: x[index].to_f64(),
+        y: y[index].to_f64(),
+    }
+}
+
+impl PlotLine {
+    /// Plot a line through the values of two deques, without copying them. Like for
+    /// [`PlotLine::plot_with`], stride and offset are not applied.
+    pub fn plot_deque<T: PlotScalar>(&self, x: &VecDeque<T>, y: &VecDeque<T>) {
+        self.plot_with(x.len().min(y.len()), deque_point(x, y));
+    }
+}
+
+impl PlotScatter {
+    /// Plot points with the values of two deques, without copying them. Like for
+    /// [`PlotScatter::plot_with`], stride and offset are not applied.
+    pub fn plot_deque<T: PlotScalar>(&self, x: &VecDeque<T>, y: &VecDeque<T>) {
+        self.plot_with(x.len().min(y.len()), deque_point(x, y));
+    }
+}
@@ -0,0 +1,232 @@
+//! # Downsampling module
+//!
+//! This module reduces large series to a number of points that can be plotted at interactive
+//! rates, which is only a few times the plot width in pixels anyway. Largest-Triangle-Three-
+//! Buckets ([`lttb`]) keeps the points that shape the series visually, e.g. peaks, while plain
+//! decimation ([`decimate`]) is cheaper but can miss them. For waveforms with millions of
+//! points, [`decimate_minmax`] keeps the extremes of each pixel column, and
+//! [`PlotLine::plot_decimated`](crate::PlotLine::plot_decimated) applies it to the visible
+//! range every frame:
+//! ```no_run
+//! # use implot::{lttb, Context, Plot, PlotLine};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! let x: Vec<f64> = (0..1_000_000).map(|i| i as f64 * 0.001).collect();
+//! let y: Vec<f64> = x.iter().map(|x| (x * 3.0).sin() + (x * 50.0).sin() * 0.1).collect();
+//! // Once, or whenever the data changes:
+//! let (x_shown, y_shown) = lttb(&x, &y, 2000);
+//! Plot::new("Signal").build(&plot_ui, |_| {
+//!     PlotLine::new("signal").plot(&x_shown, &y_shown);
+//! });
+//! ```
+
+/// Downsample a series to at most `threshold` points with the Largest-Triangle-Three-Buckets
+/// algorithm. The first and last points are always kept, and from each bucket of points in
+/// between, the one forming the largest triangle with its neighbors is. The X values are
+/// expected to be sorted. The series is returned unchanged if it already has at most
+/// `threshold` points or `threshold` is below 3.
+pub fn lttb(x: &[f64], y: &[f64], threshold: usize) -> (Vec<f64>, Vec<f64>) {
+    let len = x.len().min(y.len());
+    if threshold < 3 || len <= threshold {
+        return (x[..len].to_vec(), y[..len].to_vec());
+    }
+
+    let mut x_out = Vec::with_capacity(threshold);
+    let mut y_out = Vec::with_capacity(threshold);
+    x_out.push(x[0]);
+    y_out.push(y[0]);
+
+    // The first and last points have their own buckets, the rest is split evenly
+    let bucket_size = (len - 2) as f64 / (threshold - 2) as f64;
+    let bucket_start = |bucket: usize| (bucket as f64 * bucket_size) as usize + 1;
+    let mut selected = 0;
+    for bucket in 0..threshold - 2 {
+        let start = bucket_start(bucket);
+        let end = if bucket == threshold - 3 {
+            len - 1
+        } else {
+            bucket_start(bucket + 1).min(len - 1)
+        };
+
+        // Average of the next bucket, which is the last point for the last bucket
+        let (next_start, next_end) = (end, bucket_start(bucket + 2).min(len - 1).max(end + 1));
+        let next_count = (next_end - next_start) as f64;
+        let next_x = x[next_start..next_end].iter().sum::<f64>() / next_count;
+        let next_y = y[next_start..next_end].iter().sum::<f64>() / next_count;
+
+        let (selected_x, selected_y) = (x[selected], y[selected]);
+        let mut max_area = -1.0;
+        for index in start..end {
+            // Twice the triangle area, which is fine for comparisons
+            let area = ((selected_x - next_x) * (y[index] - selected_y)
+                - (selected_x - x[index]) * (next_y - selected_y))
+                .abs();
+            if area > max_area {
+                max_area = area;
+                selected = index;
+            }
+        }
+        x_out.push(x[selected]);
+        y_out.push(y[selected]);
+    }
+
+    x_out.push(x[len - 1]);
+    y_out.push(y[len - 1]);
+    (x_out, y_out)
+}
+
+/// Downsample a series to at most `max_points` points by keeping every n-th point, starting
+/// with the first. The series is returned unchanged if it already has at most `max_points`
+/// points.
+pub fn decimate(x: &[f64], y: &[f64], max_points: usize) -> (Vec<f64>, Vec<f64>) {
+    let len = x.len().min(y.len());
+    if max_points == 0 {
+        return (Vec::new(), Vec::new());
+    }
+    // Rounded up, without `usize::div_ceil` since that needs a newer Rust than this crate does
+    let step = len / max_points + usize::from(len % max_points != 0);
+    if step <= 1 {
+        return (x[..len].to_vec(), y[..len].to_vec());
+    }
+    (
+        x[..len].iter().step_by(step).copied().collect(),
+        y[..len].iter().step_by(step).copied().collect(),
+    )
+}
+
+/// Downsample a series to at most two points per pixel column, the minimum and maximum Y value
+/// of the points falling into it, in their original order. The X range of the data is split
+/// into `pixel_width` columns of equal width, so the X values are expected to be sorted. Drawn
+/// with a line, the result looks the same as the full series at that width, including single
+/// spikes. The series is returned unchanged if it already has at most two points per column.
+pub fn decimate_minmax(x: &[f64], y: &[f64], pixel_width: usize) -> (Vec<f64>, Vec<f64>) {
+    let len = x.len().min(y.len());
+    let (mut x_out, mut y_out) = (Vec::new(), Vec::new());
+    decimate_minmax_into(
+        len,
+        |index| x[index],
+        |index| y[index],
+        pixel_width,
+        &mut x_out,
+        &mut y_out,
+    );
+    (x_out, y_out)
+}
+
+/// [`decimate_minmax`] for a series of `len` points that is read through `x_of` and `y_of`,
+/// appending the result to `x_out` and `y_out`, so series that aren't stored as slices, e.g.
+/// memory-mapped ones, don't have to be copied first.
+pub(crate) fn decimate_minmax_into<X: Fn(usize) -> f64, Y: Fn(usize) -> f64>(
+    len: usize,
+    x_of: X,
+    y_of: Y,
+    pixel_width: usize,
+    x_out: &mut Vec<f64>,
+    y_out: &mut Vec<f64>,
+) {
+    if len <= 2 * pixel_width {
+        for index in 0..len {
+            x_out.push(x_of(index));
+            y_out.push(y_of(index));
+        }
+        return;
+    }
+    if pixel_width == 0 {
+        return;
+    }
+
+    x_out.reserve(2 * pixel_width);
+    y_out.reserve(2 * pixel_width);
+    let (first, last) = (x_of(0), x_of(len - 1));
+    let columns_per_x = if last > first {
+        pixel_width as f64 / (last - first)
+    } else {
+        0.0
+    };
+    let column_of = |value: f64| (((value - first) * columns_per_x) as usize).min(pixel_width - 1);
+
+    let mut column = column_of(first);
+    let first_y = y_of(0);
+    let (mut min, mut max) = ((0, first_y), (0, first_y));
+    let mut flush = |min: (usize, f64), max: (usize, f64)| {
+        let (start, end) = if min.0 <= max.0 {
+            (min, max)
+        } else {
+            (max, min)
+        };
+        x_out.push(x_of(start.0));
+        y_out.push(start.1);
+        if end.0 != start.0 {
+            x_out.push(x_of(end.0));
+            y_out.push(end.1);
+        }
+    };
+    for index in 1..len {
+        let next_column = column_of(x_of(index));
+        let value = y_of(index);
+        if next_column != column {
+            flush(min, max);
+            column = next_column;
+            min = (index, value);
+            max = (index, value);
+        } else if value < min.1 {
+            min = (index, value);
+        } else if value > max.1 {
+            max = (index, value);
+        }
+    }
+    flush(min, max);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lttb_keeps_peaks_and_ends() {
+        let x: Vec<f64> = (0..100).map(|i| i as f64).collect();
+        let mut y = vec![0.0; 100];
+        y[37] = 10.0;
+        y[80] = -5.0;
+
+        let (x_out, y_out) = lttb(&x, &y, 10);
+        assert_eq!(x_out.len(), 10);
+        assert_eq!((x_out[0], x_out[9]), (0.0, 99.0));
+        assert!(x_out.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(y_out.contains(&10.0));
+        assert!(y_out.contains(&-5.0));
+
+        assert_eq!(lttb(&x[..5], &y[..5], 10).0.len(), 5);
+        assert_eq!(lttb(&x, &y, 2).0.len(), 100);
+    }
+
+    #[test]
+    fn test_decimate() {
+        let x: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        assert_eq!(decimate(&x, &x, 4).0, vec![0.0, 3.0, 6.0, 9.0]);
+        assert_eq!(decimate(&x, &x, 5).0, vec![0.0, 2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(decimate(&x, &x, 20).0.len(), 10);
+        assert!(decimate(&x, &x, 0).0.is_empty());
+    }
+
+    #[test]
+    fn test_decimate_minmax_keeps_spikes() {
+        let x: Vec<f64> = (0..1000).map(|i| i as f64).collect();
+        let mut y = vec![0.0; 1000];
+        y[123] = 10.0;
+        y[124] = -10.0;
+        y[700] = 5.0;
+
+        let (x_out, y_out) = decimate_minmax(&x, &y, 10);
+        assert!(x_out.len() <= 20);
+        assert!(x_out.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(y_out.contains(&10.0) && y_out.contains(&-10.0) && y_out.contains(&5.0));
+
+        assert_eq!(decimate_minmax(&x[..15], &y[..15], 10).0.len(), 15);
+        assert!(decimate_minmax(&x, &y, 0).0.is_empty());
+        assert_eq!(
+            decimate_minmax(&[1.0; 5], &[1.0, 3.0, 0.0, 2.0, 1.0], 1).1,
+            vec![3.0, 0.0]
+        );
+    }
+}
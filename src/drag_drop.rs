@@ -0,0 +1,210 @@
+//! # Drag and drop module
+//!
+//! This module wraps ImPlot's drag and drop helpers, which make plots, axes, legends and legend
+//! entries act as imgui drag and drop sources and targets. Payloads are plain imgui payloads, so
+//! they can be exchanged with imgui-rs' own `DragDropSource`/`DragDropTarget` through their
+//! `_unchecked` and empty payload functions, e.g. to drag a signal from a list onto an axis:
+//! ```no_run
+//! # use implot::{AxisChoice, Context, Plot};
+//! # use imgui::DragDropFlags;
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! Plot::new("Signals").build(&plot_ui, |plot| {
+//!     if let Some(target) = plot.drag_drop_target_axis(AxisChoice::Y2) {
+//!         let flags = DragDropFlags::empty();
+//!         if let Some(signal_index) = target.accept_payload::<usize>("SIGNAL", flags) {
+//!             // Move the signal to the second Y axis
+//!         }
+//!     }
+//! });
+//! ```
+
+use crate::{sys, AxisChoice, PlotToken};
+use bytemuck::Pod;
+use imgui::DragDropFlags;
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::os::raw::c_void;
+
+/// Converts a payload type name, which imgui limits to 32 bytes including the terminator.
+fn payload_type_name(name: &str) -> CString {
+    assert!(
+        name.len() < 32,
+        "Payload type names are limited to 31 bytes: {}",
+        name
+    );
+    CString::new(name).unwrap_or_else(|_| panic!("Payload type has internal null bytes: {}", name))
+}
+
+/// Tracks an active drag and drop source started on a plot, axis or legend entry. Ends the
+/// source when dropped.
+#[must_use]
+pub struct PlotDragDropSourceToken<'a>(PhantomData<&'a PlotToken>);
+
+impl PlotDragDropSourceToken<'_> {
+    /// Set the payload as raw bytes, which are copied by imgui. Returns true if the payload
+    /// has been accepted by a target.
+    ///
+    /// # Panics
+    /// Will panic if the payload type is 32 bytes or longer or contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "SetDragDropPayload"))]
+    pub fn set_payload_raw(&self, payload_type: &str, data: &[u8]) -> bool {
+        let payload_type = payload_type_name(payload_type);
+        unsafe {
+            sys::igSetDragDropPayload(
+                payload_type.as_ptr(),
+                data.as_ptr() as *const c_void,
+                data.len(),
+                0,
+            )
+        }
+    }
+
+    /// Set the payload to a copy of `value`. Returns true if the payload has been accepted by
+    /// a target. Read it with [`PlotDragDropTargetToken::accept_payload`] using the same type.
+    /// The type has to be [`Pod`], i.e. plain bytes without padding, so that any payload can
+    /// be read back as it safely.
+    pub fn set_payload<T: Pod>(&self, payload_type: &str, value: &T) -> bool {
+        self.set_payload_raw(payload_type, bytemuck::bytes_of(value))
+    }
+
+    /// End the drag and drop source, same as dropping the token.
+    #[inline]
+    pub fn end(self) {
+        // left empty for drop
+    }
+}
+
+impl Drop for PlotDragDropSourceToken<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::ImPlot_EndDragDropSource() }
+    }
+}
+
+/// Tracks an active drag and drop target on a plot, axis or legend. Ends the target when
+/// dropped.
+#[must_use]
+pub struct PlotDragDropTargetToken<'a>(PhantomData<&'a PlotToken>);
+
+impl<'a> PlotDragDropTargetToken<'a> {
+    /// Accept a payload of the given type, returning its raw bytes once it is dropped on the
+    /// target (or while hovering, with the `ACCEPT_BEFORE_DELIVERY` flag).
+    ///
+    /// # Panics
+    /// Will panic if the payload type is 32 bytes or longer or contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "AcceptDragDropPayload"))]
+    pub fn accept_payload_raw(&self, payload_type: &str, flags: DragDropFlags) -> Option<&'a [u8]> {
+        let payload_type = payload_type_name(payload_type);
+        unsafe {
+            let payload = sys::igAcceptDragDropPayload(
+                payload_type.as_ptr(),
+                flags.bits() as sys::ImGuiDragDropFlags,
+            );
+            if payload.is_null() {
+                return None;
+            }
+            let payload = &*payload;
+            if payload.Data.is_null() || payload.DataSize <= 0 {
+                return Some(&[]);
+            }
+            Some(std::slice::from_raw_parts(
+                payload.Data as *const u8,
+                payload.DataSize as usize,
+            ))
+        }
+    }
+
+    /// Accept a payload set with [`PlotDragDropSourceToken::set_payload`] (or any other
+    /// payload that is a plain copy of a `T`). Returns `None` if the payload has a different
+    /// size than `T`.
+    pub fn accept_payload<T: Pod>(&self, payload_type: &str, flags: DragDropFlags) -> Option<T> {
+        let data = self.accept_payload_raw(payload_type, flags)?;
+        // imgui copies payloads into a byte buffer, so alignment is not guaranteed
+        bytemuck::try_pod_read_unaligned(data).ok()
+    }
+
+    /// End the drag and drop target, same as dropping the token.
+    #[inline]
+    pub fn end(self) {
+        // left empty for drop
+    }
+}
+
+impl Drop for PlotDragDropTargetToken<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::ImPlot_EndDragDropTarget() }
+    }
+}
+
+impl PlotToken {
+    /// Turn the plot area into a drag and drop target. Returns `None` if nothing is being
+    /// dragged onto it.
+    #[rustversion::attr(since(1.48), doc(alias = "BeginDragDropTargetPlot"))]
+    pub fn drag_drop_target_plot(&self) -> Option<PlotDragDropTargetToken> {
+        unsafe { sys::ImPlot_BeginDragDropTargetPlot() }
+            .then(|| PlotDragDropTargetToken(PhantomData))
+    }
+
+    /// Turn an axis into a drag and drop target. Returns `None` if nothing is being dragged
+    /// onto it.
+    #[rustversion::attr(since(1.48), doc(alias = "BeginDragDropTargetAxis"))]
+    pub fn drag_drop_target_axis(&self, axis: AxisChoice) -> Option<PlotDragDropTargetToken> {
+        unsafe { sys::ImPlot_BeginDragDropTargetAxis(axis.into()) }
+            .then(|| PlotDragDropTargetToken(PhantomData))
+    }
+
+    /// Turn the legend into a drag and drop target. Returns `None` if nothing is being
+    /// dragged onto it.
+    #[rustversion::attr(since(1.48), doc(alias = "BeginDragDropTargetLegend"))]
+    pub fn drag_drop_target_legend(&self) -> Option<PlotDragDropTargetToken> {
+        unsafe { sys::ImPlot_BeginDragDropTargetLegend() }
+            .then(|| PlotDragDropTargetToken(PhantomData))
+    }
+
+    /// Make the plot area a drag and drop source, by default dragged with Ctrl held. Returns
+    /// `None` if it is not being dragged.
+    #[rustversion::attr(since(1.48), doc(alias = "BeginDragDropSourcePlot"))]
+    pub fn drag_drop_source_plot(&self, flags: DragDropFlags) -> Option<PlotDragDropSourceToken> {
+        unsafe { sys::ImPlot_BeginDragDropSourcePlot(flags.bits() as sys::ImGuiDragDropFlags) }
+            .then(|| PlotDragDropSourceToken(PhantomData))
+    }
+
+    /// Make an axis a drag and drop source, by default dragged with Ctrl held. Returns `None`
+    /// if it is not being dragged.
+    #[rustversion::attr(since(1.48), doc(alias = "BeginDragDropSourceAxis"))]
+    pub fn drag_drop_source_axis(
+        &self,
+        axis: AxisChoice,
+        flags: DragDropFlags,
+    ) -> Option<PlotDragDropSourceToken> {
+        unsafe {
+            sys::ImPlot_BeginDragDropSourceAxis(
+                axis.into(),
+                flags.bits() as sys::ImGuiDragDropFlags,
+            )
+        }
+        .then(|| PlotDragDropSourceToken(PhantomData))
+    }
+
+    /// Make the legend entry of an item a drag and drop source. Call this after the item
+    /// was plotted. Returns `None` if it is not being dragged.
+    ///
+    /// # Panics
+    /// Will panic if the legend entry string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "BeginDragDropSourceItem"))]
+    pub fn drag_drop_source_item(
+        &self,
+        legend_entry: &str,
+        flags: DragDropFlags,
+    ) -> Option<PlotDragDropSourceToken> {
+        let label = CString::new(legend_entry)
+            .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", legend_entry));
+        unsafe {
+            sys::ImPlot_BeginDragDropSourceItem(
+                label.as_ptr(),
+                flags.bits() as sys::ImGuiDragDropFlags,
+            )
+        }
+        .then(|| PlotDragDropSourceToken(PhantomData))
+    }
+}
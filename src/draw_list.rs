@@ -0,0 +1,155 @@
+//! # Draw list module
+//!
+//! This module gives access to the draw list of the current plot, for custom items that ImPlot
+//! has no element for (candlesticks, arrows, shapes, ...). Drawing happens in pixel coordinates,
+//! so plot coordinates are converted with
+//! [`PlotToken::plot_to_pixels_vec2`](crate::PlotToken::plot_to_pixels_vec2) first:
+//! ```no_run
+//! # use implot::{AxisChoice, Context, ImPlotPoint, ImVec4, Plot};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! Plot::new("Custom").build(&plot_ui, |plot| {
+//!     let to_pixels = |x, y| {
+//!         plot.plot_to_pixels_vec2(&ImPlotPoint { x, y }, AxisChoice::X1, AxisChoice::Y1)
+//!     };
+//!     let (start, end) = (to_pixels(0.0, 0.0), to_pixels(1.0, 1.0));
+//!     let red = ImVec4 { x: 1.0, y: 0.0, z: 0.0, w: 1.0 };
+//!     let _clip = plot.push_plot_clip_rect(0.0);
+//!     plot.get_plot_draw_list().add_line(start, end, red, 2.0);
+//! });
+//! ```
+
+use crate::{sys, ImVec2, ImVec4, PlotToken};
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+
+/// Converts a color to imgui's packed format.
+fn to_u32(color: ImVec4) -> u32 {
+    unsafe { sys::igColorConvertFloat4ToU32(color) }
+}
+
+/// The draw list of the current plot. Obtained from [`PlotToken::get_plot_draw_list`], and only
+/// valid while the plot is being drawn.
+pub struct PlotDrawList<'a> {
+    raw: *mut sys::ImDrawList,
+    _marker: PhantomData<&'a PlotToken>,
+}
+
+impl PlotDrawList<'_> {
+    /// The raw draw list, for drawing calls that are not wrapped here.
+    pub fn raw(&self) -> *mut sys::ImDrawList {
+        self.raw
+    }
+
+    /// Draw a line from `start` to `end`.
+    #[rustversion::attr(since(1.48), doc(alias = "AddLine"))]
+    pub fn add_line(&self, start: ImVec2, end: ImVec2, color: ImVec4, thickness: f32) {
+        unsafe { sys::ImDrawList_AddLine(self.raw, start, end, to_u32(color), thickness) }
+    }
+
+    /// Draw the outline of the rectangle between the corners `min` and `max`.
+    #[rustversion::attr(since(1.48), doc(alias = "AddRect"))]
+    pub fn add_rect(&self, min: ImVec2, max: ImVec2, color: ImVec4, thickness: f32) {
+        unsafe { sys::ImDrawList_AddRect(self.raw, min, max, to_u32(color), 0.0, 0, thickness) }
+    }
+
+    /// Draw a filled rectangle between the corners `min` and `max`.
+    #[rustversion::attr(since(1.48), doc(alias = "AddRectFilled"))]
+    pub fn add_rect_filled(&self, min: ImVec2, max: ImVec2, color: ImVec4) {
+        unsafe { sys::ImDrawList_AddRectFilled(self.raw, min, max, to_u32(color), 0.0, 0) }
+    }
+
+    /// Draw the outline of a circle. The number of segments is chosen automatically.
+    #[rustversion::attr(since(1.48), doc(alias = "AddCircle"))]
+    pub fn add_circle(&self, center: ImVec2, radius: f32, color: ImVec4, thickness: f32) {
+        unsafe { sys::ImDrawList_AddCircle(self.raw, center, radius, to_u32(color), 0, thickness) }
+    }
+
+    /// Draw a filled circle. The number of segments is chosen automatically.
+    #[rustversion::attr(since(1.48), doc(alias = "AddCircleFilled"))]
+    pub fn add_circle_filled(&self, center: ImVec2, radius: f32, color: ImVec4) {
+        unsafe { sys::ImDrawList_AddCircleFilled(self.raw, center, radius, to_u32(color), 0) }
+    }
+
+    /// Draw a filled triangle, e.g. for arrow heads.
+    #[rustversion::attr(since(1.48), doc(alias = "AddTriangleFilled"))]
+    pub fn add_triangle_filled(&self, p1: ImVec2, p2: ImVec2, p3: ImVec2, color: ImVec4) {
+        unsafe { sys::ImDrawList_AddTriangleFilled(self.raw, p1, p2, p3, to_u32(color)) }
+    }
+
+    /// Draw connected line segments through the given points, optionally closing the shape.
+    #[rustversion::attr(since(1.48), doc(alias = "AddPolyline"))]
+    pub fn add_polyline(&self, points: &[ImVec2], color: ImVec4, closed: bool, thickness: f32) {
+        if points.len() < 2 {
+            return;
+        }
+        // ImDrawFlags_Closed
+        let flags = if closed { 1 } else { 0 };
+        unsafe {
+            sys::ImDrawList_AddPolyline(
+                self.raw,
+                points.as_ptr(),
+                points.len() as i32,
+                to_u32(color),
+                flags,
+                thickness,
+            )
+        }
+    }
+
+    /// Draw text with its upper left corner at `position`.
+    #[rustversion::attr(since(1.48), doc(alias = "AddText"))]
+    pub fn add_text(&self, position: ImVec2, color: ImVec4, text: &str) {
+        // The end pointer is passed, so the text does not need to be null-terminated
+        let range = text.as_bytes().as_ptr_range();
+        unsafe {
+            sys::ImDrawList_AddText_Vec2(
+                self.raw,
+                position,
+                to_u32(color),
+                range.start as *const c_char,
+                range.end as *const c_char,
+            )
+        }
+    }
+}
+
+/// Tracks a clip rectangle pushed with [`PlotToken::push_plot_clip_rect`], which is popped
+/// again when the token is dropped or `end()` is called on it.
+#[must_use]
+pub struct PlotClipRectToken<'a>(PhantomData<&'a PlotToken>);
+
+impl PlotClipRectToken<'_> {
+    /// Pop the clip rectangle, same as dropping the token.
+    #[inline]
+    pub fn end(self) {
+        // left empty for drop
+    }
+}
+
+impl Drop for PlotClipRectToken<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::ImPlot_PopPlotClipRect() }
+    }
+}
+
+impl PlotToken {
+    /// Get the draw list of the current plot, for custom rendering in pixel coordinates. Use
+    /// [`PlotToken::push_plot_clip_rect`] to keep the drawing within the plot area.
+    #[rustversion::attr(since(1.48), doc(alias = "GetPlotDrawList"))]
+    pub fn get_plot_draw_list(&self) -> PlotDrawList {
+        PlotDrawList {
+            raw: unsafe { sys::ImPlot_GetPlotDrawList() },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Clip drawing to the plot area, expanded by `expand` pixels on each side, until the
+    /// returned token is dropped. Plot elements do this themselves, so this is only needed for
+    /// custom drawing through [`PlotToken::get_plot_draw_list`].
+    #[rustversion::attr(since(1.48), doc(alias = "PushPlotClipRect"))]
+    pub fn push_plot_clip_rect(&self, expand: f32) -> PlotClipRectToken {
+        unsafe { sys::ImPlot_PushPlotClipRect(expand) };
+        PlotClipRectToken(PhantomData)
+    }
+}
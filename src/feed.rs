@@ -0,0 +1,296 @@
+//! # Plot feed module
+//!
+//! This module provides a way to plot data that is produced on other threads. Background
+//! threads send typed messages through a [`FeedSender`], and the [`PlotFeed`] owned by the UI
+//! thread drains them into scrolling buffers once per frame and renders them. ImPlot itself is
+//! not thread-safe, so this keeps all calls into it on the UI thread while still allowing
+//! realtime data to come from anywhere:
+//! ```no_run
+//! # use implot::{Context, Plot, PlotFeed};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! let (sender, mut feed) = PlotFeed::new(1000);
+//! std::thread::spawn(move || {
+//!     for i in 0..100 {
+//!         let t = i as f64 * 0.1;
+//!         sender.append("sine", &[t], &[t.sin()]).ok();
+//!     }
+//! });
+//!
+//! // Every frame:
+//! feed.drain();
+//! feed.render(&plot_ui, Plot::new("Realtime"));
+//! ```
+
+use crate::{Plot, PlotLine, PlotUi, IMPLOT_AUTO_COL};
+use std::collections::{HashMap, VecDeque};
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::sync::mpsc::{self, Receiver, SendError, Sender};
+
+use crate::sys;
+
+/// A message sent from a producer thread to a [`PlotFeed`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeedMessage {
+    /// Append samples to the named series, creating it if it doesn't exist yet
+    Append {
+        series: String,
+        x: Vec<f64>,
+        y: Vec<f64>,
+    },
+    /// Replace all samples of the named series
+    Replace {
+        series: String,
+        x: Vec<f64>,
+        y: Vec<f64>,
+    },
+    /// Remove the named series
+    Remove { series: String },
+    /// Add a vertical event marker at the given X position, with a label
+    Event { x: f64, label: String },
+    /// Remove all series and event markers
+    Clear,
+}
+
+/// Sending half of a [`PlotFeed`]. Cheap to clone and can be moved to other threads.
+#[derive(Clone, Debug)]
+pub struct FeedSender {
+    sender: Sender<FeedMessage>,
+}
+
+impl FeedSender {
+    /// Send a raw message to the feed. Fails if the feed was dropped.
+    pub fn send(&self, message: FeedMessage) -> Result<(), SendError<FeedMessage>> {
+        self.sender.send(message)
+    }
+
+    /// Append samples to the named series.
+    pub fn append(&self, series: &str, x: &[f64], y: &[f64]) -> Result<(), SendError<FeedMessage>> {
+        self.send(FeedMessage::Append {
+            series: series.to_owned(),
+            x: x.to_vec(),
+            y: y.to_vec(),
+        })
+    }
+
+    /// Replace all samples of the named series.
+    pub fn replace(
+        &self,
+        series: &str,
+        x: Vec<f64>,
+        y: Vec<f64>,
+    ) -> Result<(), SendError<FeedMessage>> {
+        self.send(FeedMessage::Replace {
+            series: series.to_owned(),
+            x,
+            y,
+        })
+    }
+
+    /// Add a labelled event marker at the given X position.
+    pub fn event(&self, x: f64, label: &str) -> Result<(), SendError<FeedMessage>> {
+        self.send(FeedMessage::Event {
+            x,
+            label: label.to_owned(),
+        })
+    }
+}
+
+/// Samples of one series, limited to the feed's capacity.
+struct FeedSeries {
+    line: PlotLine,
+    x: VecDeque<f64>,
+    y: VecDeque<f64>,
+}
+
+impl FeedSeries {
+    fn push(&mut self, x: &[f64], y: &[f64], capacity: usize) {
+        self.x.extend(x.iter().take(y.len()));
+        self.y.extend(y.iter().take(x.len()));
+        while self.x.len() > capacity {
+            self.x.pop_front();
+            self.y.pop_front();
+        }
+    }
+}
+
+/// Receiving half of a plot feed, to be owned by the UI thread. Keeps the last `capacity`
+/// samples of each series, dropping older ones as new samples come in.
+pub struct PlotFeed {
+    receiver: Receiver<FeedMessage>,
+    capacity: usize,
+    /// Series in order of first appearance, so colors stay stable between frames
+    series: Vec<(String, FeedSeries)>,
+    series_index: HashMap<String, usize>,
+    event_positions: VecDeque<f64>,
+    event_labels: VecDeque<CString>,
+}
+
+impl PlotFeed {
+    /// Create a feed keeping up to `capacity` samples per series (and event markers), along
+    /// with the sender to give to producer threads.
+    pub fn new(capacity: usize) -> (FeedSender, Self) {
+        let (sender, receiver) = mpsc::channel();
+        (
+            FeedSender { sender },
+            Self {
+                receiver,
+                capacity,
+                series: Vec::new(),
+                series_index: HashMap::new(),
+                event_positions: VecDeque::new(),
+                event_labels: VecDeque::new(),
+            },
+        )
+    }
+
+    /// Process all messages that arrived since the last call, without blocking. Returns the
+    /// number of processed messages.
+    pub fn drain(&mut self) -> usize {
+        let mut count = 0;
+        while let Ok(message) = self.receiver.try_recv() {
+            self.apply(message);
+            count += 1;
+        }
+        count
+    }
+
+    /// Apply a single message directly, e.g. for data produced on the UI thread itself.
+    ///
+    /// # Panics
+    /// Will panic if a series name or event label contains internal null bytes.
+    pub fn apply(&mut self, message: FeedMessage) {
+        let capacity = self.capacity;
+        match message {
+            FeedMessage::Append { series, x, y } => {
+                self.series_mut(&series).push(&x, &y, capacity);
+            }
+            FeedMessage::Replace { series, x, y } => {
+                let series = self.series_mut(&series);
+                series.x.clear();
+                series.y.clear();
+                series.push(&x, &y, capacity);
+            }
+            FeedMessage::Remove { series } => {
+                if let Some(index) = self.series_index.remove(&series) {
+                    self.series.remove(index);
+                    self.series_index
+                        .values_mut()
+                        .filter(|other| **other > index)
+                        .for_each(|other| *other -= 1);
+                }
+            }
+            FeedMessage::Event { x, label } => {
+                self.event_positions.push_back(x);
+                self.event_labels.push_back(
+                    CString::new(label.as_str()).unwrap_or_else(|_| {
+                        panic!("Event label has internal null bytes: {}", label)
+                    }),
+                );
+                if self.event_positions.len() > capacity {
+                    self.event_positions.pop_front();
+                    self.event_labels.pop_front();
+                }
+            }
+            FeedMessage::Clear => {
+                self.series.clear();
+                self.series_index.clear();
+                self.event_positions.clear();
+                self.event_labels.clear();
+            }
+        }
+    }
+
+    fn series_mut(&mut self, name: &str) -> &mut FeedSeries {
+        let index = match self.series_index.get(name) {
+            Some(index) => *index,
+            None => {
+                self.series.push((
+                    name.to_owned(),
+                    FeedSeries {
+                        line: PlotLine::new(name),
+                        x: VecDeque::new(),
+                        y: VecDeque::new(),
+                    },
+                ));
+                self.series_index
+                    .insert(name.to_owned(), self.series.len() - 1);
+                self.series.len() - 1
+            }
+        };
+        &mut self.series[index].1
+    }
+
+    /// Names of the series currently in the feed, in order of first appearance.
+    pub fn series_names(&self) -> impl Iterator<Item = &str> {
+        self.series.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Draw all series as lines and all event markers as labelled vertical lines into the
+    /// given plot. Call this once per frame, within an imgui window.
+    pub fn render(&mut self, plot_ui: &PlotUi, plot: Plot) {
+        plot.build(plot_ui, |_| self.plot_contents());
+    }
+
+    /// Draw the series and event markers into the current plot. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build) to combine the feed with other elements.
+    pub fn plot_contents(&mut self) {
+        for (_, series) in self.series.iter_mut() {
+            let x = series.x.make_contiguous();
+            // The two buffers always have the same length, see FeedSeries::push
+            let y = series.y.make_contiguous();
+            series.line.plot(x, y);
+        }
+
+        if self.event_positions.is_empty() {
+            return;
+        }
+        let positions = self.event_positions.make_contiguous();
+        unsafe {
+            sys::ImPlot_PlotInfLines_doublePtr(
+                b"events\0".as_ptr() as *const c_char,
+                positions.as_ptr(),
+                positions.len() as i32,
+                sys::ImPlotInfLinesFlags_::NONE.0 as sys::ImPlotInfLinesFlags,
+                0,
+                std::mem::size_of::<f64>() as i32,
+            );
+            for (x, label) in positions.iter().zip(self.event_labels.iter()) {
+                sys::ImPlot_TagX_Str(
+                    *x,
+                    IMPLOT_AUTO_COL,
+                    b"%s\0".as_ptr() as *const c_char,
+                    label.as_ptr(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed_drain_and_capacity() {
+        let (sender, mut feed) = PlotFeed::new(3);
+        sender.append("a", &[0.0, 1.0], &[0.0, 1.0]).unwrap();
+        sender.append("b", &[0.0], &[5.0]).unwrap();
+        sender.append("a", &[2.0, 3.0], &[4.0, 9.0]).unwrap();
+        sender.event(1.5, "start").unwrap();
+        assert_eq!(feed.drain(), 4);
+
+        assert_eq!(feed.series_names().collect::<Vec<_>>(), vec!["a", "b"]);
+        let a = &feed.series[feed.series_index["a"]].1;
+        assert_eq!(a.x, [1.0, 2.0, 3.0]);
+        assert_eq!(a.y, [1.0, 4.0, 9.0]);
+        assert_eq!(feed.event_positions, [1.5]);
+
+        feed.apply(FeedMessage::Remove {
+            series: "a".to_owned(),
+        });
+        assert_eq!(feed.series_names().collect::<Vec<_>>(), vec!["b"]);
+        assert_eq!(feed.series_index["b"], 0);
+    }
+}
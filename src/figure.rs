@@ -0,0 +1,202 @@
+//! # Figure module
+//!
+//! This module provides `Figure`, an opt-in facade in the style of matplotlib for quickly
+//! putting a chart on screen without dealing with plot builders and tokens directly:
+//! ```no_run
+//! # use implot::{Context, Figure};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! let x = [0.0, 1.0, 2.0, 3.0];
+//! let y = [0.0, 1.0, 4.0, 9.0];
+//! Figure::new()
+//!     .title("Squares")
+//!     .line(&x, &y)
+//!     .label("x²")
+//!     .scatter(&x, &y)
+//!     .xlabel("x")
+//!     .ylabel("y")
+//!     .show(&plot_ui);
+//! ```
+//! Everything here is layered on top of [`Plot`] and the plot elements, so anything not covered
+//! by the facade can be done with those instead.
+
+use crate::{
+    Plot, PlotBars, PlotCond, PlotLine, PlotScatter, PlotStairs, PlotStems, PlotUi,
+    DEFAULT_PLOT_SIZE_X, DEFAULT_PLOT_SIZE_Y,
+};
+use implot_sys::ImPlotRange;
+
+/// Kind of an item added to a [`Figure`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FigureItemKind {
+    Line,
+    Scatter,
+    Stairs,
+    Stems,
+    Bars(f64),
+}
+
+struct FigureItem<'a> {
+    kind: FigureItemKind,
+    label: Option<String>,
+    x: &'a [f64],
+    y: &'a [f64],
+}
+
+/// A quick chart, built up with chained calls and drawn with [`Figure::show`]. The data is
+/// borrowed, not copied.
+pub struct Figure<'a> {
+    title: String,
+    size: [f32; 2],
+    x_label: Option<String>,
+    y_label: Option<String>,
+    x_limits: Option<ImPlotRange>,
+    y_limits: Option<ImPlotRange>,
+    items: Vec<FigureItem<'a>>,
+}
+
+impl<'a> Figure<'a> {
+    /// Create an empty figure. Figures without a title all share the same ImGui ID, so set
+    /// a [`Figure::title`] when showing more than one in the same window.
+    pub fn new() -> Self {
+        Self {
+            title: "##Figure".to_owned(),
+            size: [DEFAULT_PLOT_SIZE_X, DEFAULT_PLOT_SIZE_Y],
+            x_label: None,
+            y_label: None,
+            x_limits: None,
+            y_limits: None,
+            items: Vec::new(),
+        }
+    }
+
+    /// Set the title of the figure.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_owned();
+        self
+    }
+
+    /// Set the size of the figure, given as [size_x, size_y].
+    pub fn size(mut self, size: [f32; 2]) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the label of the X axis.
+    pub fn xlabel(mut self, label: &str) -> Self {
+        self.x_label = Some(label.to_owned());
+        self
+    }
+
+    /// Set the label of the Y axis.
+    pub fn ylabel(mut self, label: &str) -> Self {
+        self.y_label = Some(label.to_owned());
+        self
+    }
+
+    /// Set the initial limits of the X axis.
+    pub fn xlim<L: Into<ImPlotRange>>(mut self, limits: L) -> Self {
+        self.x_limits = Some(limits.into());
+        self
+    }
+
+    /// Set the initial limits of the Y axis.
+    pub fn ylim<L: Into<ImPlotRange>>(mut self, limits: L) -> Self {
+        self.y_limits = Some(limits.into());
+        self
+    }
+
+    fn item(mut self, kind: FigureItemKind, x: &'a [f64], y: &'a [f64]) -> Self {
+        self.items.push(FigureItem {
+            kind,
+            label: None,
+            x,
+            y,
+        });
+        self
+    }
+
+    /// Add a line.
+    pub fn line(self, x: &'a [f64], y: &'a [f64]) -> Self {
+        self.item(FigureItemKind::Line, x, y)
+    }
+
+    /// Add a scatter plot.
+    pub fn scatter(self, x: &'a [f64], y: &'a [f64]) -> Self {
+        self.item(FigureItemKind::Scatter, x, y)
+    }
+
+    /// Add a stairs style line.
+    pub fn stairs(self, x: &'a [f64], y: &'a [f64]) -> Self {
+        self.item(FigureItemKind::Stairs, x, y)
+    }
+
+    /// Add a stem plot.
+    pub fn stems(self, x: &'a [f64], y: &'a [f64]) -> Self {
+        self.item(FigureItemKind::Stems, x, y)
+    }
+
+    /// Add vertical bars of the given width.
+    pub fn bars(self, x: &'a [f64], y: &'a [f64], width: f64) -> Self {
+        self.item(FigureItemKind::Bars(width), x, y)
+    }
+
+    /// Set the legend label of the most recently added item. Items without a label are named
+    /// after their kind and position, e.g. "line 1".
+    pub fn label(mut self, label: &str) -> Self {
+        if let Some(item) = self.items.last_mut() {
+            item.label = Some(label.to_owned());
+        }
+        self
+    }
+
+    /// Draw the figure. Call this once per frame, within an imgui window.
+    ///
+    /// # Panics
+    /// Will panic if any of the strings given to the figure contain internal null bytes.
+    pub fn show(&self, plot_ui: &PlotUi) {
+        let mut plot = Plot::new(&self.title).size(self.size);
+        if let Some(label) = &self.x_label {
+            plot = plot.x_label(label);
+        }
+        if let Some(label) = &self.y_label {
+            plot = plot.y_label(label);
+        }
+        if let Some(limits) = self.x_limits {
+            plot = plot.x_limits(limits, PlotCond::Once);
+        }
+        if let Some(limits) = self.y_limits {
+            plot = plot.y_limits(limits, PlotCond::Once);
+        }
+
+        plot.build(plot_ui, |_| {
+            for (index, item) in self.items.iter().enumerate() {
+                let label = item.label.clone().unwrap_or_else(|| {
+                    let kind = match item.kind {
+                        FigureItemKind::Line => "line",
+                        FigureItemKind::Scatter => "scatter",
+                        FigureItemKind::Stairs => "stairs",
+                        FigureItemKind::Stems => "stems",
+                        FigureItemKind::Bars(_) => "bars",
+                    };
+                    format!("{} {}", kind, index + 1)
+                });
+                match item.kind {
+                    FigureItemKind::Line => PlotLine::new(&label).plot(item.x, item.y),
+                    FigureItemKind::Scatter => PlotScatter::new(&label).plot(item.x, item.y),
+                    FigureItemKind::Stairs => PlotStairs::new(&label).plot(item.x, item.y),
+                    FigureItemKind::Stems => PlotStems::new(&label).plot(item.x, item.y, false),
+                    FigureItemKind::Bars(width) => PlotBars::new(&label)
+                        .with_bar_width(width)
+                        .plot(item.x, item.y, false),
+                }
+            }
+        });
+    }
+}
+
+impl Default for Figure<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
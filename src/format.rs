@@ -0,0 +1,136 @@
+//! # Format module
+//!
+//! This module contains ready-made formatters for axis tick labels, for use with
+//! [`Plot::axis_format`](crate::Plot::axis_format):
+//! ```no_run
+//! # use implot::{AxisChoice, Plot, SiFormat};
+//! // Ticks like "500 mV", "1 V", "1.5 V"
+//! let plot = Plot::new("Voltage").axis_format(AxisChoice::Y1, SiFormat::new("V").formatter());
+//! ```
+
+/// SI prefixes from 10^-24 to 10^24, in steps of 10^3.
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+/// Formats values with SI prefixes (`1.2k`, `3.4M`, `5µ`), or in engineering notation (`1.2e3`),
+/// followed by an optional unit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SiFormat {
+    unit: String,
+    /// Maximum number of decimals, trailing zeros are removed
+    precision: usize,
+    engineering: bool,
+}
+
+impl SiFormat {
+    /// Create a formatter using SI prefixes and at most two decimals. The unit is appended
+    /// after a space, e.g. `1.2 kHz`; if it is empty, nothing is appended, e.g. `1.2k`.
+    pub fn new(unit: &str) -> Self {
+        Self {
+            unit: unit.to_owned(),
+            precision: 2,
+            engineering: false,
+        }
+    }
+
+    /// Set the maximum number of decimals. Trailing zeros are not shown.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Use engineering notation, i.e. exponents that are multiples of three (`1.2e3`), instead
+    /// of SI prefixes.
+    pub fn engineering(mut self) -> Self {
+        self.engineering = true;
+        self
+    }
+
+    /// Format a single value.
+    pub fn format(&self, value: f64) -> String {
+        let (mantissa, exponent) = self.split(value);
+        let mut text = format!("{:.*}", self.precision, mantissa);
+        if text.contains('.') {
+            let trimmed = text.trim_end_matches('0').trim_end_matches('.').len();
+            text.truncate(trimmed);
+        }
+        if text == "-0" {
+            text.remove(0);
+        }
+
+        if self.engineering {
+            if exponent != 0 {
+                text.push_str(&format!("e{}", exponent));
+            }
+            if !self.unit.is_empty() {
+                text.push(' ');
+                text.push_str(&self.unit);
+            }
+        } else {
+            if !self.unit.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(SI_PREFIXES[(exponent / 3 + 8) as usize]);
+            text.push_str(&self.unit);
+        }
+        text
+    }
+
+    /// The formatter as a closure, to be passed to [`Plot::axis_format`](crate::Plot::axis_format).
+    pub fn formatter(self) -> impl FnMut(f64) -> String + 'static {
+        move |value| self.format(value)
+    }
+
+    /// Split `value` into a mantissa and an exponent that is a multiple of three, so that the
+    /// mantissa rounded to the precision is at least 1 and below 1000 in magnitude.
+    fn split(&self, value: f64) -> (f64, i32) {
+        if value == 0.0 || !value.is_finite() {
+            return (value, 0);
+        }
+        let mut exponent = (value.abs().log10().floor() as i32).div_euclid(3) * 3;
+        exponent = exponent.clamp(-24, 24);
+        let mut mantissa = value / 10f64.powi(exponent);
+        // Rounding can carry over into the next power of 1000, e.g. 999.96 -> "1000"
+        let rounding = 10f64.powi(self.precision as i32);
+        if (mantissa.abs() * rounding).round() / rounding >= 1000.0 && exponent < 24 {
+            exponent += 3;
+            mantissa /= 1000.0;
+        }
+        (mantissa, exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_si_prefixes() {
+        let format = SiFormat::new("");
+        assert_eq!(format.format(0.0), "0");
+        assert_eq!(format.format(1234.0), "1.23k");
+        assert_eq!(format.format(3.4e6), "3.4M");
+        assert_eq!(format.format(5e-6), "5µ");
+        assert_eq!(format.format(-0.25), "-250m");
+        assert_eq!(format.format(999.999), "1k");
+        assert_eq!(format.format(12.0), "12");
+    }
+
+    #[test]
+    fn test_si_units() {
+        let format = SiFormat::new("Hz").with_precision(1);
+        assert_eq!(format.format(1240.0), "1.2 kHz");
+        assert_eq!(format.format(5.0), "5 Hz");
+        assert_eq!(format.format(1e30), "1000000 YHz");
+    }
+
+    #[test]
+    fn test_engineering_notation() {
+        let format = SiFormat::new("V").engineering();
+        assert_eq!(format.format(1200.0), "1.2e3 V");
+        assert_eq!(format.format(0.5), "500e-3 V");
+        assert_eq!(format.format(7.0), "7 V");
+        assert_eq!(SiFormat::new("").engineering().format(4.5e-9), "4.5e-9");
+    }
+}
@@ -0,0 +1,99 @@
+//! # Gaps module
+//!
+//! This module prepares discontinuous data, e.g. from sensors with dropouts, so that it is
+//! drawn with gaps instead of lines bridging the missing stretches. ImPlot leaves out the line
+//! segments next to NaN values, so [`insert_nan_gaps`] breaks a series wherever consecutive X
+//! values are too far apart, and [`split_at_gaps`] finds the contiguous segments instead, e.g.
+//! for plotting them as separate items:
+//! ```no_run
+//! # use implot::{insert_nan_gaps, Context, Plot, PlotLine};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! // Sampled every second, with the sensor offline between 3 and 10
+//! let time = [0.0, 1.0, 2.0, 3.0, 10.0, 11.0];
+//! let value = [1.0, 1.2, 1.1, 1.3, 0.9, 1.0];
+//! let (time, value) = insert_nan_gaps(&time, &value, 1.5);
+//! Plot::new("Sensor").build(&plot_ui, |_| {
+//!     PlotLine::new("value").plot(&time, &value);
+//! });
+//! ```
+
+use std::ops::Range;
+
+/// Whether the step from `previous` to `next` is a gap, i.e. larger than `max_step`.
+fn is_gap(previous: f64, next: f64, max_step: f64) -> bool {
+    next - previous > max_step
+}
+
+/// Copy a series, inserting a point with NaN X and Y values between consecutive points whose X
+/// values are more than `max_step` apart, so plotting it leaves a gap there. NaN values that
+/// are already in the data are kept, so they break the line as well.
+pub fn insert_nan_gaps(x: &[f64], y: &[f64], max_step: f64) -> (Vec<f64>, Vec<f64>) {
+    let len = x.len().min(y.len());
+    let mut x_out = Vec::with_capacity(len);
+    let mut y_out = Vec::with_capacity(len);
+    for index in 0..len {
+        if index > 0 && is_gap(x[index - 1], x[index], max_step) {
+            x_out.push(f64::NAN);
+            y_out.push(f64::NAN);
+        }
+        x_out.push(x[index]);
+        y_out.push(y[index]);
+    }
+    (x_out, y_out)
+}
+
+/// Split a series into the index ranges of its contiguous segments: a segment ends before a
+/// point with a non-finite X or Y value, which is not part of any segment, and between
+/// consecutive points whose X values are more than `max_step` apart. Pass `f64::INFINITY` as
+/// `max_step` to only split at non-finite values.
+pub fn split_at_gaps(x: &[f64], y: &[f64], max_step: f64) -> Vec<Range<usize>> {
+    let len = x.len().min(y.len());
+    let mut segments = Vec::new();
+    let mut start = None;
+    for index in 0..len {
+        if !x[index].is_finite() || !y[index].is_finite() {
+            if let Some(start) = start.take() {
+                segments.push(start..index);
+            }
+            continue;
+        }
+        match start {
+            Some(segment_start) if is_gap(x[index - 1], x[index], max_step) => {
+                segments.push(segment_start..index);
+                start = Some(index);
+            }
+            Some(_) => {}
+            None => start = Some(index),
+        }
+    }
+    if let Some(start) = start {
+        segments.push(start..len);
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_nan_gaps() {
+        let (x, y) = insert_nan_gaps(&[0.0, 1.0, 5.0, 6.0], &[1.0, 2.0, 3.0, 4.0], 1.5);
+        assert_eq!(x.len(), 5);
+        assert!(x[2].is_nan() && y[2].is_nan());
+        assert_eq!((x[3], y[3]), (5.0, 3.0));
+
+        let (x, _) = insert_nan_gaps(&[0.0, 1.0, 2.0], &[1.0, 2.0], 1.5);
+        assert_eq!(x, vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_split_at_gaps() {
+        let x = [0.0, 1.0, 2.0, 3.0, 10.0, 11.0, 12.0];
+        let y = [1.0, 1.0, f64::NAN, 1.0, 1.0, 1.0, 1.0];
+        assert_eq!(split_at_gaps(&x, &y, 1.5), vec![0..2, 3..4, 4..7]);
+        assert_eq!(split_at_gaps(&x, &y, f64::INFINITY), vec![0..2, 3..7]);
+        assert!(split_at_gaps(&[f64::NAN], &[1.0], 1.0).is_empty());
+    }
+}
@@ -0,0 +1,205 @@
+//! # Input map module
+//!
+//! This module allows changing the mouse buttons and modifier keys used to interact with plots.
+//! The input map is shared by all plots, e.g. for box selection with the left and panning with
+//! the middle mouse button:
+//! ```no_run
+//! # use implot::{Context, KeyModifiers};
+//! # use imgui::MouseButton;
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! let mut input_map = plot_ui.input_map();
+//! input_map.select = MouseButton::Left;
+//! input_map.select_cancel = MouseButton::Right;
+//! input_map.pan = MouseButton::Middle;
+//! input_map.pan_mod = KeyModifiers::NONE;
+//! plot_ui.set_input_map(&input_map);
+//! ```
+
+use crate::{sys, PlotUi};
+use imgui::MouseButton;
+use std::ops::{BitOr, BitOrAssign};
+
+/// Combination of modifier keys that have to be held for an input to apply.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyModifiers(pub i32);
+
+impl KeyModifiers {
+    pub const NONE: Self = Self(sys::ImGuiMod_None as i32);
+    pub const CTRL: Self = Self(sys::ImGuiMod_Ctrl as i32);
+    pub const SHIFT: Self = Self(sys::ImGuiMod_Shift as i32);
+    pub const ALT: Self = Self(sys::ImGuiMod_Alt as i32);
+    pub const SUPER: Self = Self(sys::ImGuiMod_Super as i32);
+
+    /// Whether all of the modifiers in `other` are part of this combination.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for KeyModifiers {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for KeyModifiers {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+/// Mouse buttons, modifier keys and zoom rate used to interact with plots, as in
+/// `ImPlotInputMap`. Get the current one with [`PlotUi::input_map`] and apply changes with
+/// [`PlotUi::set_input_map`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputMap {
+    /// Button for panning, when held and dragged
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::mouse_button"))]
+    pub pan: MouseButton,
+    /// Modifiers that have to be held for panning
+    pub pan_mod: KeyModifiers,
+    /// Button for fitting the data, when double clicked
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::mouse_button"))]
+    pub fit: MouseButton,
+    /// Button for box selection, when held and dragged
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::mouse_button"))]
+    pub select: MouseButton,
+    /// Button for cancelling an ongoing box selection
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::mouse_button"))]
+    pub select_cancel: MouseButton,
+    /// Modifiers that have to be held for box selection
+    pub select_mod: KeyModifiers,
+    /// Modifiers that expand a box selection to the full width of the plot
+    pub select_horz_mod: KeyModifiers,
+    /// Modifiers that expand a box selection to the full height of the plot
+    pub select_vert_mod: KeyModifiers,
+    /// Button for opening the context menu, when clicked
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::mouse_button"))]
+    pub menu: MouseButton,
+    /// Modifiers that switch the locking and interaction behavior while held
+    pub override_mod: KeyModifiers,
+    /// Modifiers that have to be held for zooming with the mouse wheel
+    pub zoom_mod: KeyModifiers,
+    /// Zoom rate for each scroll tick, e.g. 0.1 zooms by 10% per tick
+    pub zoom_rate: f32,
+}
+
+/// Converts a mouse button from ImPlot, falling back to the left button for invalid ones.
+fn mouse_button(raw: sys::ImGuiMouseButton) -> MouseButton {
+    MouseButton::VARIANTS
+        .iter()
+        .copied()
+        .find(|button| *button as sys::ImGuiMouseButton == raw)
+        .unwrap_or(MouseButton::Left)
+}
+
+impl InputMap {
+    fn from_raw(raw: &sys::ImPlotInputMap) -> Self {
+        Self {
+            pan: mouse_button(raw.Pan),
+            pan_mod: KeyModifiers(raw.PanMod),
+            fit: mouse_button(raw.Fit),
+            select: mouse_button(raw.Select),
+            select_cancel: mouse_button(raw.SelectCancel),
+            select_mod: KeyModifiers(raw.SelectMod),
+            select_horz_mod: KeyModifiers(raw.SelectHorzMod),
+            select_vert_mod: KeyModifiers(raw.SelectVertMod),
+            menu: mouse_button(raw.Menu),
+            override_mod: KeyModifiers(raw.OverrideMod),
+            zoom_mod: KeyModifiers(raw.ZoomMod),
+            zoom_rate: raw.ZoomRate,
+        }
+    }
+
+    fn to_raw(self) -> sys::ImPlotInputMap {
+        sys::ImPlotInputMap {
+            Pan: self.pan as sys::ImGuiMouseButton,
+            PanMod: self.pan_mod.0,
+            Fit: self.fit as sys::ImGuiMouseButton,
+            Select: self.select as sys::ImGuiMouseButton,
+            SelectCancel: self.select_cancel as sys::ImGuiMouseButton,
+            SelectMod: self.select_mod.0,
+            SelectHorzMod: self.select_horz_mod.0,
+            SelectVertMod: self.select_vert_mod.0,
+            Menu: self.menu as sys::ImGuiMouseButton,
+            OverrideMod: self.override_mod.0,
+            ZoomMod: self.zoom_mod.0,
+            ZoomRate: self.zoom_rate,
+        }
+    }
+}
+
+impl PlotUi<'_> {
+    /// Get a copy of the current input map.
+    #[rustversion::attr(since(1.48), doc(alias = "GetInputMap"))]
+    pub fn input_map(&self) -> InputMap {
+        unsafe {
+            let input_map = sys::ImPlot_GetInputMap();
+            assert_ne!(input_map, std::ptr::null_mut());
+            InputMap::from_raw(&*input_map)
+        }
+    }
+
+    /// Replace the current input map, changing how all plots are interacted with.
+    #[rustversion::attr(since(1.48), doc(alias = "GetInputMap"))]
+    pub fn set_input_map(&self, input_map: &InputMap) {
+        unsafe {
+            let current = sys::ImPlot_GetInputMap();
+            assert_ne!(current, std::ptr::null_mut());
+            *current = input_map.to_raw();
+        }
+    }
+
+    /// Reset the input map to ImPlot's default: panning with the left and box selection with
+    /// the right mouse button.
+    #[rustversion::attr(since(1.48), doc(alias = "MapInputDefault"))]
+    pub fn map_input_default(&self) {
+        unsafe {
+            sys::ImPlot_MapInputDefault(std::ptr::null_mut());
+        }
+    }
+
+    /// Set the input map to the reverse of ImPlot's default: box selection with the left and
+    /// panning with the right mouse button.
+    #[rustversion::attr(since(1.48), doc(alias = "MapInputReverse"))]
+    pub fn map_input_reverse(&self) {
+        unsafe {
+            sys::ImPlot_MapInputReverse(std::ptr::null_mut());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_map_raw_round_trip() {
+        let input_map = InputMap {
+            pan: MouseButton::Middle,
+            pan_mod: KeyModifiers::NONE,
+            fit: MouseButton::Left,
+            select: MouseButton::Left,
+            select_cancel: MouseButton::Right,
+            select_mod: KeyModifiers::NONE,
+            select_horz_mod: KeyModifiers::ALT,
+            select_vert_mod: KeyModifiers::SHIFT,
+            menu: MouseButton::Right,
+            override_mod: KeyModifiers::CTRL | KeyModifiers::SHIFT,
+            zoom_mod: KeyModifiers::NONE,
+            zoom_rate: 0.1,
+        };
+        assert_eq!(InputMap::from_raw(&input_map.to_raw()), input_map);
+        assert!(input_map.override_mod.contains(KeyModifiers::CTRL));
+        assert!(!input_map.override_mod.contains(KeyModifiers::ALT));
+
+        let mut raw = input_map.to_raw();
+        raw.Pan = -1;
+        assert_eq!(InputMap::from_raw(&raw).pan, MouseButton::Left);
+    }
+}
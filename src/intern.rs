@@ -0,0 +1,103 @@
+//! Interning of the strings handed to ImPlot.
+//!
+//! Plot titles, item labels and tick labels have to be passed to ImPlot as null-terminated
+//! strings. Since plots and their elements are usually rebuilt every frame with the same labels,
+//! converting them every time would allocate and free a `CString` per label and frame. Instead,
+//! they are looked up here and converted only once. Interned strings are shared with the
+//! elements holding them, and the ones no element holds anymore are evicted when the interner
+//! has grown to twice its size after the last eviction, so labels that keep changing (e.g. with
+//! a value in them) don't accumulate, and eviction takes amortized constant time per label.
+//!
+//! The interner is per thread and shared by all contexts on it. The strings don't refer to
+//! any ImPlot state, so they stay valid across contexts, and dropping a
+//! [`Context`](crate::Context) leaves them alone.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::sync::Arc;
+
+/// Minimum number of interned strings above which unused ones are evicted.
+const CAPACITY: usize = 4096;
+
+struct Interner {
+    strings: HashMap<String, Arc<CStr>>,
+    /// Number of strings at which the next eviction happens
+    evict_at: usize,
+}
+
+thread_local! {
+    static INTERNED: RefCell<Interner> = RefCell::new(Interner {
+        strings: HashMap::new(),
+        evict_at: CAPACITY,
+    });
+}
+
+/// Get the null-terminated version of a label, converting it only if it is not interned yet.
+///
+/// # Panics
+/// Will panic if the label contains internal null bytes.
+pub(crate) fn intern(label: &str) -> Arc<CStr> {
+    INTERNED.with(|interned| {
+        let mut interned = interned.borrow_mut();
+        if let Some(existing) = interned.strings.get(label) {
+            return Arc::clone(existing);
+        }
+        if interned.strings.len() >= interned.evict_at {
+            interned
+                .strings
+                .retain(|_, string| Arc::strong_count(string) > 1);
+            interned.evict_at = CAPACITY.max(2 * interned.strings.len());
+        }
+        let string: Arc<CStr> = CString::new(label)
+            .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label))
+            .into();
+        interned
+            .strings
+            .insert(label.to_owned(), Arc::clone(&string));
+        string
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interned_count() -> usize {
+        INTERNED.with(|interned| interned.borrow().strings.len())
+    }
+
+    #[test]
+    fn test_intern_reuses_and_evicts() {
+        let first = intern("price");
+        let second = intern("price");
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.to_str().unwrap(), "price");
+
+        for index in 0..CAPACITY {
+            intern(&format!("value {}", index));
+        }
+        // Filling up the interner evicted the unused labels, but kept the held one
+        assert!(interned_count() < CAPACITY);
+        assert!(Arc::ptr_eq(&intern("price"), &first));
+    }
+
+    #[test]
+    fn test_intern_evicts_after_doubling() {
+        // All of these are held, so the eviction when reaching the capacity keeps them
+        let held: Vec<_> = (0..CAPACITY)
+            .map(|index| intern(&format!("held {}", index)))
+            .collect();
+        intern("unused");
+        let evict_at = INTERNED.with(|interned| interned.borrow().evict_at);
+        assert_eq!(evict_at, 2 * CAPACITY);
+
+        // No further eviction until the interner has doubled
+        for index in 0..CAPACITY - 1 {
+            intern(&format!("more {}", index));
+        }
+        assert_eq!(interned_count(), 2 * CAPACITY);
+        intern("trigger");
+        assert_eq!(interned_count(), held.len() + 1);
+    }
+}
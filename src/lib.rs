@@ -1,13 +1,72 @@
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::marker::PhantomData;
+use std::os::raw::c_char;
 
-pub use self::{context::*, plot::*, plot_elements::*};
+use stack_tracking::StackKind;
+
+#[cfg(feature = "ndarray")]
+pub use self::array::*;
+#[cfg(feature = "chrono")]
+pub use self::datetime::*;
+#[cfg(feature = "mmap")]
+pub use self::mapped::*;
+#[cfg(feature = "spec")]
+pub use self::spec::*;
+#[cfg(feature = "style-watcher")]
+pub use self::style_watcher::*;
+pub use self::{
+    context::*, crosshair::*, downsample::*, drag_drop::*, draw_list::*, feed::*, figure::*,
+    format::*, gaps::*, input_map::*, memory::*, plot::*, plot_elements::*, plot_helpers::*,
+    retained::*, scalar::*, strided::*, style::*, summary::*, time::*, trendline::*,
+};
+#[cfg(feature = "derive")]
+pub use implot_derive::Plottable;
 pub use implot_sys as sys;
-pub use sys::{ImPlotColormap, ImPlotPoint, ImPlotRange, ImPlotRect, ImVec2, ImVec4};
+pub use sys::{
+    ImPlotColormap, ImPlotInputMap, ImPlotPoint, ImPlotRange, ImPlotRect, ImPlotStyle, ImPlotTime,
+    ImVec2, ImVec4,
+};
 
+#[cfg(feature = "ndarray")]
+mod array;
 mod context;
+mod crosshair;
+#[cfg(feature = "chrono")]
+mod datetime;
+mod deque;
+mod downsample;
+mod drag_drop;
+mod draw_list;
+mod feed;
+mod figure;
+mod format;
+mod gaps;
+mod input_map;
+mod intern;
+#[cfg(feature = "mmap")]
+mod mapped;
+mod memory;
 mod plot;
 mod plot_elements;
+mod plot_helpers;
+mod retained;
+mod scalar;
+#[cfg(feature = "serde")]
+mod serde_helpers;
+#[cfg(feature = "spec")]
+mod spec;
+mod stack_tracking;
+mod strided;
+mod style;
+#[cfg(feature = "style-watcher")]
+mod style_watcher;
+mod summary;
+#[cfg(feature = "test-support")]
+pub mod testing;
+pub mod themes;
+mod time;
 mod tokens;
+mod trendline;
 
 const NUMBER_OF_AXES: usize = sys::ImAxis_::COUNT as usize;
 
@@ -18,32 +77,56 @@ pub struct PlotUi<'ui> {
 impl<'ui> PlotUi<'ui> {
     /// Switch to a colormap preset.
     #[rustversion::attr(since(1.48), doc(alias = "PushColormap"))]
+    #[track_caller]
     pub fn push_colormap_from_preset(&self, colormap: ColormapPreset) -> ColormapToken {
         unsafe {
-            sys::ImPlot_PushColormap_PlotColormap(colormap as sys::ImPlotColormap);
+            sys::ImPlot_PushColormap_PlotColormap(colormap.into());
         }
+        self.record_push(StackKind::Colormap, 1);
         ColormapToken::new(self)
     }
 
     /// Switch to a different colormap.
     #[rustversion::attr(since(1.48), doc(alias = "PushColormap"))]
+    #[track_caller]
     pub fn push_colormap(&self, colormap: Colormap) -> ColormapToken {
         unsafe {
             sys::ImPlot_PushColormap_PlotColormap(colormap.to_index());
         }
+        self.record_push(StackKind::Colormap, 1);
         ColormapToken::new(self)
     }
 
     /// Switch to a colormap by name.
     #[rustversion::attr(since(1.48), doc(alias = "PushColormap"))]
+    #[track_caller]
     pub fn push_colormap_from_name(&self, name: &str) -> ColormapToken {
         let name = CString::new(name).unwrap();
         unsafe {
             sys::ImPlot_PushColormap_Str(name.as_ptr());
         }
+        self.record_push(StackKind::Colormap, 1);
         ColormapToken::new(self)
     }
 
+    /// Switch to a different colormap for the duration of the given closure. The colormap is
+    /// popped again once the closure returns, so this is a structured alternative to keeping a
+    /// [`ColormapToken`] alive across the right part of the frame:
+    /// ```no_run
+    /// # use implot::{Colormap, ColormapPreset, Context};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// plot_ui.with_colormap(Colormap::Preset(ColormapPreset::Viridis), || {
+    ///     // Plot things using the viridis colormap
+    /// });
+    /// ```
+    #[rustversion::attr(since(1.48), doc(alias = "PushColormap"))]
+    #[track_caller]
+    pub fn with_colormap<R, F: FnOnce() -> R>(&self, colormap: Colormap, f: F) -> R {
+        let _token = self.push_colormap(colormap);
+        f()
+    }
+
     /// Push a f32 style variable to the stack. The returned token is used for removing
     /// the variable from the stack again:
     /// ```no_run
@@ -53,10 +136,12 @@ impl<'ui> PlotUi<'ui> {
     /// pushed_var.pop();
     /// ```
     #[rustversion::attr(since(1.48), doc(alias = "PushStyleVar"))]
+    #[track_caller]
     pub fn push_style_var_f32(&self, element: &StyleVar, value: f32) -> StyleVarToken {
         unsafe {
-            sys::ImPlot_PushStyleVar_Float(*element as sys::ImPlotStyleVar, value);
+            sys::ImPlot_PushStyleVar_Float((*element).into(), value);
         }
+        self.record_push(StackKind::StyleVar, 1);
         StyleVarToken::new(self)
     }
 
@@ -69,25 +154,28 @@ impl<'ui> PlotUi<'ui> {
     /// markerchoice.pop()
     /// ```
     #[rustversion::attr(since(1.48), doc(alias = "PushStyleVar"))]
+    #[track_caller]
     pub fn push_style_var_i32(&self, element: &StyleVar, value: i32) -> StyleVarToken {
         unsafe {
-            sys::ImPlot_PushStyleVar_Int(*element as sys::ImPlotStyleVar, value);
+            sys::ImPlot_PushStyleVar_Int((*element).into(), value);
         }
+        self.record_push(StackKind::StyleVar, 1);
         StyleVarToken::new(self)
     }
 
     /// Push an ImVec2 style variable to the stack. The returned token is used for removing
     /// the variable from the stack again.
+    #[track_caller]
     pub fn push_style_var_imvec2(&self, element: &StyleVar, value: ImVec2) -> StyleVarToken {
         unsafe {
-            sys::ImPlot_PushStyleVar_Vec2(*element as sys::ImPlotStyleVar, value);
+            sys::ImPlot_PushStyleVar_Vec2((*element).into(), value);
         }
+        self.record_push(StackKind::StyleVar, 1);
         StyleVarToken::new(self)
     }
 
     // --- Push/pop utils -------------------------------------------------------------------------
-    // Currently not in a struct yet. imgui-rs has some smarts about dealing with stacks, in particular
-    // leak detection, which I'd like to replicate here at some point.
+    // Pushes are tracked in debug builds to detect leaked tokens, see the stack_tracking module.
     /// Push a style color to the stack, giving an element and the four components of the color.
     /// The components should be between 0.0 (no intensity) and 1.0 (full intensity).
     /// The return value is a token that gets used for removing the style color from the stack again:
@@ -98,6 +186,7 @@ impl<'ui> PlotUi<'ui> {
     /// pushed_var.pop();
     /// ```
     #[rustversion::attr(since(1.48), doc(alias = "PushStyleColor"))]
+    #[track_caller]
     pub fn push_style_color(
         &self,
         element: &PlotColorElement,
@@ -108,7 +197,7 @@ impl<'ui> PlotUi<'ui> {
     ) -> StyleColorToken {
         unsafe {
             sys::ImPlot_PushStyleColor_Vec4(
-                *element as sys::ImPlotCol,
+                (*element).into(),
                 sys::ImVec4 {
                     x: red,
                     y: green,
@@ -117,9 +206,81 @@ impl<'ui> PlotUi<'ui> {
                 },
             );
         }
+        self.record_push(StackKind::StyleColor, 1);
         StyleColorToken::new(self)
     }
 
+    /// Push several style colors at once, as `(element, [red, green, blue, alpha])`. The
+    /// returned token pops all of them again:
+    /// ```no_run
+    /// # use implot::{Context, PlotColorElement};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// let _colors = plot_ui.push_style_colors(&[
+    ///     (PlotColorElement::PlotBg, [0.1, 0.1, 0.1, 1.0]),
+    ///     (PlotColorElement::AxisGrid, [1.0, 1.0, 1.0, 0.2]),
+    /// ]);
+    /// // Plot some things
+    /// ```
+    #[rustversion::attr(since(1.48), doc(alias = "PushStyleColor"))]
+    #[track_caller]
+    pub fn push_style_colors(&self, colors: &[(PlotColorElement, [f32; 4])]) -> StyleColorsToken {
+        for (element, [red, green, blue, alpha]) in colors {
+            unsafe {
+                sys::ImPlot_PushStyleColor_Vec4(
+                    (*element).into(),
+                    sys::ImVec4 {
+                        x: *red,
+                        y: *green,
+                        z: *blue,
+                        w: *alpha,
+                    },
+                );
+            }
+        }
+        self.record_push(StackKind::StyleColor, colors.len() as i32);
+        StyleColorsToken {
+            count: colors.len() as i32,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Push several style variables at once. The returned token pops all of them again:
+    /// ```no_run
+    /// # use implot::{Context, Marker, StyleVar, StyleVarValue};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// let _vars = plot_ui.push_style_vars(&[
+    ///     (StyleVar::LineWeight, StyleVarValue::F32(2.0)),
+    ///     (StyleVar::Marker, StyleVarValue::I32(Marker::Circle as i32)),
+    /// ]);
+    /// // Plot some things
+    /// ```
+    #[rustversion::attr(since(1.48), doc(alias = "PushStyleVar"))]
+    #[track_caller]
+    pub fn push_style_vars(&self, vars: &[(StyleVar, StyleVarValue)]) -> StyleVarsToken {
+        for (element, value) in vars {
+            let element = (*element).into();
+            unsafe {
+                match *value {
+                    StyleVarValue::F32(value) => sys::ImPlot_PushStyleVar_Float(element, value),
+                    StyleVarValue::I32(value) => sys::ImPlot_PushStyleVar_Int(element, value),
+                    StyleVarValue::Vec2(value) => sys::ImPlot_PushStyleVar_Vec2(element, value),
+                }
+            }
+        }
+        self.record_push(StackKind::StyleVar, vars.len() as i32);
+        StyleVarsToken {
+            count: vars.len() as i32,
+            _marker: PhantomData,
+        }
+    }
+
+    #[track_caller]
+    fn record_push(&self, kind: StackKind, count: i32) {
+        stack_tracking::record_push(kind, count, self.context.token_drop_policy());
+    }
+
     /// Get index of the given colormap
     pub fn get_colormap_index(&self, name: &str) -> Option<Colormap> {
         let name = CString::new(name).unwrap();
@@ -151,7 +312,261 @@ impl<'ui> PlotUi<'ui> {
         Colormap::Custom(index)
     }
 
-    // --- Demo window -------------------------------------------------------------------------------
+    /// Add a custom colormap from its definition, e.g. one restored from saved settings. Same
+    /// as [`PlotUi::add_colormap_from_vec`].
+    #[rustversion::attr(since(1.48), doc(alias = "AddColormap"))]
+    pub fn add_colormap(&self, definition: &ColormapDefinition) -> Colormap {
+        self.add_colormap_from_vec(
+            &definition.name,
+            definition.colors.clone(),
+            definition.discrete,
+        )
+    }
+
+    /// Get the color at the given index of a colormap, or of the current colormap if `None` is
+    /// given. Indices wrap around at the size of the colormap.
+    #[rustversion::attr(since(1.48), doc(alias = "GetColormapColor"))]
+    pub fn get_colormap_color(&self, index: i32, colormap: Option<Colormap>) -> ImVec4 {
+        let cmap = colormap.map_or(IMPLOT_AUTO as sys::ImPlotColormap, |cm| cm.to_index());
+        let mut color = IMPLOT_AUTO_COL;
+        unsafe {
+            sys::ImPlot_GetColormapColor(&mut color, index, cmap);
+        }
+        color
+    }
+
+    /// Sample a colormap at `t` between 0 and 1, interpolating between its colors unless it is
+    /// a discrete colormap. Uses the current colormap if `None` is given.
+    #[rustversion::attr(since(1.48), doc(alias = "SampleColormap"))]
+    pub fn sample_colormap(&self, t: f32, colormap: Option<Colormap>) -> ImVec4 {
+        let cmap = colormap.map_or(IMPLOT_AUTO as sys::ImPlotColormap, |cm| cm.to_index());
+        let mut color = IMPLOT_AUTO_COL;
+        unsafe {
+            sys::ImPlot_SampleColormap(&mut color, t, cmap);
+        }
+        color
+    }
+
+    /// Get the number of colormaps, built-in and custom ones. Valid colormap indices are
+    /// `0..count`, so all colormaps can be listed with `Colormap::Custom`.
+    #[rustversion::attr(since(1.48), doc(alias = "GetColormapCount"))]
+    pub fn get_colormap_count(&self) -> i32 {
+        unsafe { sys::ImPlot_GetColormapCount() }
+    }
+
+    /// Get the name of a colormap.
+    #[rustversion::attr(since(1.48), doc(alias = "GetColormapName"))]
+    pub fn get_colormap_name(&self, colormap: Colormap) -> String {
+        unsafe { CStr::from_ptr(sys::ImPlot_GetColormapName(colormap.to_index())) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Get the number of colors in a colormap.
+    #[rustversion::attr(since(1.48), doc(alias = "GetColormapSize"))]
+    pub fn get_colormap_size(&self, colormap: Colormap) -> i32 {
+        unsafe { sys::ImPlot_GetColormapSize(colormap.to_index()) }
+    }
+
+    /// Draw a slider over a colormap, with `t` between 0 and 1. Returns true if `t` was
+    /// changed. The color at `t` can be retrieved with [`PlotUi::sample_colormap`]. Uses the
+    /// current colormap if `None` is given.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "ColormapSlider"))]
+    pub fn colormap_slider(&self, label: &str, t: &mut f32, colormap: Option<Colormap>) -> bool {
+        let label = CString::new(label)
+            .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label));
+        let cmap = colormap.map_or(IMPLOT_AUTO as sys::ImPlotColormap, |cm| cm.to_index());
+        unsafe {
+            sys::ImPlot_ColormapSlider(
+                label.as_ptr(),
+                t,
+                std::ptr::null_mut(),
+                b"\0".as_ptr() as *const c_char,
+                cmap,
+            )
+        }
+    }
+
+    /// Draw a button filled with the colors of a colormap. A zero size picks a default size.
+    /// Returns true if the button was clicked. Uses the current colormap if `None` is given.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "ColormapButton"))]
+    pub fn colormap_button(&self, label: &str, size: ImVec2, colormap: Option<Colormap>) -> bool {
+        let label = CString::new(label)
+            .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label));
+        let cmap = colormap.map_or(IMPLOT_AUTO as sys::ImPlotColormap, |cm| cm.to_index());
+        unsafe { sys::ImPlot_ColormapButton(label.as_ptr(), size, cmap) }
+    }
+
+    /// Draw a small square icon showing the colors of a colormap, e.g. next to its name in a
+    /// selection list.
+    #[rustversion::attr(since(1.48), doc(alias = "ColormapIcon"))]
+    pub fn colormap_icon(&self, colormap: Colormap) {
+        unsafe { sys::ImPlot_ColormapIcon(colormap.to_index()) }
+    }
+
+    /// Clear the cached item colors of the plot with the given title, or of all plots if `None`
+    /// is given, so that items pick up their colors again from the current colormap and style.
+    ///
+    /// # Panics
+    /// Will panic if the plot title string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "BustColorCache"))]
+    pub fn bust_color_cache(&self, plot_title: Option<&str>) {
+        let plot_title = plot_title.map(|title| {
+            CString::new(title)
+                .unwrap_or_else(|_| panic!("Title string has internal null bytes: {}", title))
+        });
+        unsafe {
+            sys::ImPlot_BustColorCache(
+                plot_title
+                    .as_ref()
+                    .map_or(std::ptr::null(), |title| title.as_ptr()),
+            );
+        }
+    }
+
+    // --- Aligned plots -------------------------------------------------------------------------
+    /// Start a group of plots whose axis padding is aligned, so that the plot areas of e.g.
+    /// vertically stacked plots line up even if their tick labels have different widths. All
+    /// plots drawn until the returned token is dropped (or `end()` is called on it) are part
+    /// of the group. Returns `None` if the group is not rendered, e.g. because the window is
+    /// collapsed.
+    ///
+    /// # Panics
+    /// Will panic if the group ID string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "BeginAlignedPlots"))]
+    pub fn begin_aligned_plots(&self, group_id: &str, vertical: bool) -> Option<AlignedPlotsToken> {
+        let group_id = CString::new(group_id)
+            .unwrap_or_else(|_| panic!("String contains internal null bytes: {}", group_id));
+        if unsafe { sys::ImPlot_BeginAlignedPlots(group_id.as_ptr(), vertical) } {
+            Some(AlignedPlotsToken::new(self))
+        } else {
+            None
+        }
+    }
+
+    /// Draw the plots in the given closure as an aligned group, see
+    /// [`PlotUi::begin_aligned_plots`]:
+    /// ```no_run
+    /// # use implot::{Context, Plot};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// plot_ui.aligned_plots("stacked", true, || {
+    ///     Plot::new("Prices").build(&plot_ui, |_| {});
+    ///     Plot::new("Volumes").build(&plot_ui, |_| {});
+    /// });
+    /// ```
+    #[rustversion::attr(since(1.48), doc(alias = "BeginAlignedPlots"))]
+    pub fn aligned_plots<F: FnOnce()>(&self, group_id: &str, vertical: bool, f: F) {
+        if let Some(_token) = self.begin_aligned_plots(group_id, vertical) {
+            f();
+        }
+    }
+
+    // --- Next plot axes -----------------------------------------------------------------------
+    /// Set the limits of an axis of the next plot, e.g. when they are only known right before
+    /// calling [`Plot::begin`]. Limits set on the [`Plot`] builder itself take precedence.
+    #[rustversion::attr(since(1.48), doc(alias = "SetNextAxisLimits"))]
+    pub fn set_next_axis_limits(
+        &self,
+        axis_choice: AxisChoice,
+        min: f64,
+        max: f64,
+        condition: PlotCond,
+    ) {
+        unsafe {
+            sys::ImPlot_SetNextAxisLimits(axis_choice.into(), min, max, condition.into());
+        }
+    }
+
+    /// Set the limits of the first X and Y axes of the next plot, see
+    /// [`PlotUi::set_next_axis_limits`].
+    #[rustversion::attr(since(1.48), doc(alias = "SetNextAxesLimits"))]
+    pub fn set_next_axes_limits(
+        &self,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+        condition: PlotCond,
+    ) {
+        unsafe {
+            sys::ImPlot_SetNextAxesLimits(x_min, x_max, y_min, y_max, condition.into());
+        }
+    }
+
+    /// Fit an axis of the next plot to its data on the next frame. See also
+    /// [`Plot::fit_next_frame`].
+    #[rustversion::attr(since(1.48), doc(alias = "SetNextAxisToFit"))]
+    pub fn set_next_axis_to_fit(&self, axis_choice: AxisChoice) {
+        unsafe {
+            sys::ImPlot_SetNextAxisToFit(axis_choice.into());
+        }
+    }
+
+    /// Fit all axes of the next plot to their data on the next frame, e.g. when a "Reset view"
+    /// button was clicked:
+    /// ```no_run
+    /// # use implot::{Context, Plot};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// # let reset_clicked = true;
+    /// # let plot = Plot::new("Signal");
+    /// if reset_clicked {
+    ///     plot_ui.set_next_axes_to_fit();
+    /// }
+    /// plot.build(&plot_ui, |_| {
+    ///     // Plot the data
+    /// });
+    /// ```
+    #[rustversion::attr(since(1.48), doc(alias = "SetNextAxesToFit"))]
+    pub fn set_next_axes_to_fit(&self) {
+        unsafe {
+            sys::ImPlot_SetNextAxesToFit();
+        }
+    }
+
+    // --- Legend ------------------------------------------------------------------------------------
+    /// Show the legend of a plot in the current imgui region instead of within the plot, e.g.
+    /// in a side panel next to it. Clicking entries toggles the items like in the regular legend
+    /// if `interactable` is true. A `size` of `[0.0, 0.0]` fits the legend to its entries.
+    ///
+    /// The plot is looked up by its title in the current ID scope, so this has to be called
+    /// within the same imgui window (and ID stack) the plot was drawn in, after the plot. To
+    /// avoid showing the legend twice, create the plot with the `NO_LEGEND` flag. Does nothing
+    /// if there is no such plot.
+    ///
+    /// # Panics
+    /// Will panic if the title string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "ShowAltLegend"))]
+    pub fn show_alt_legend(
+        &self,
+        plot_title: &str,
+        vertical: bool,
+        size: [f32; 2],
+        interactable: bool,
+    ) {
+        let plot_title = CString::new(plot_title)
+            .unwrap_or_else(|_| panic!("String contains internal null bytes: {}", plot_title));
+        unsafe {
+            sys::ImPlot_ShowAltLegend(
+                plot_title.as_ptr(),
+                vertical,
+                ImVec2 {
+                    x: size[0],
+                    y: size[1],
+                },
+                interactable,
+            );
+        }
+    }
+
+    // --- Tool windows ------------------------------------------------------------------------------
     /// Show the demo window for poking around what functionality implot has to
     /// offer. Note that not all of this is necessarily implemented in implot-rs
     /// already - if you find something missing you'd really like, raise an issue.
@@ -162,6 +577,59 @@ impl<'ui> PlotUi<'ui> {
             implot_sys::ImPlot_ShowDemoWindow(show);
         }
     }
+
+    /// Show the style editor, as a block of widgets inside the current imgui window. Changes
+    /// can be reverted to `reference`, or to the style at the time of the first call if `None`
+    /// is given.
+    #[rustversion::attr(since(1.48), doc(alias = "ShowStyleEditor"))]
+    pub fn show_style_editor(&self, reference: Option<&mut Style>) {
+        let reference = reference.map_or(std::ptr::null_mut(), |style| style.raw_mut());
+        unsafe {
+            sys::ImPlot_ShowStyleEditor(reference);
+        }
+    }
+
+    /// Show the metrics window, with information about the plots, items and axes ImPlot
+    /// currently keeps track of.
+    #[rustversion::attr(since(1.48), doc(alias = "ShowMetricsWindow"))]
+    pub fn show_metrics_window(&self, show: &mut bool) {
+        unsafe {
+            sys::ImPlot_ShowMetricsWindow(show);
+        }
+    }
+
+    /// Show the user guide on how to interact with plots, as text inside the current imgui
+    /// window.
+    #[rustversion::attr(since(1.48), doc(alias = "ShowUserGuide"))]
+    pub fn show_user_guide(&self) {
+        unsafe {
+            sys::ImPlot_ShowUserGuide();
+        }
+    }
+
+    /// Show a combo box for selecting the current colormap. Returns true if the selection
+    /// changed.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "ShowColormapSelector"))]
+    pub fn show_colormap_selector(&self, label: &str) -> bool {
+        let label = CString::new(label)
+            .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label));
+        unsafe { sys::ImPlot_ShowColormapSelector(label.as_ptr()) }
+    }
+
+    /// Show a combo box for selecting the input map, i.e. the mouse buttons and modifiers
+    /// used to interact with plots. Returns true if the selection changed.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "ShowInputMapSelector"))]
+    pub fn show_input_map_selector(&self, label: &str) -> bool {
+        let label = CString::new(label)
+            .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label));
+        unsafe { sys::ImPlot_ShowInputMapSelector(label.as_ptr()) }
+    }
 }
 
 /// Choice of axis.
@@ -195,19 +663,34 @@ pub enum Colormap {
 impl Colormap {
     fn to_index(&self) -> sys::ImPlotColormap {
         match self {
-            Colormap::Preset(preset) => *preset as sys::ImPlotColormap,
+            Colormap::Preset(preset) => (*preset).into(),
             Colormap::Custom(custom) => *custom as sys::ImPlotColormap,
         }
     }
 }
 
+/// Definition of a custom colormap, to be added with [`PlotUi::add_colormap`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColormapDefinition {
+    /// Unique name of the colormap
+    pub name: String,
+    #[cfg_attr(feature = "serde", serde(with = "serde_helpers::colors"))]
+    pub colors: Vec<ImVec4>,
+    /// Whether the colors are used as they are, or interpolated between when sampling
+    pub discrete: bool,
+}
+
 create_token!(
     /// Tracks a colormap token that can be ended by calling `.end()`
     /// or by dropping
     pub struct ColormapToken<'ui>;
 
     /// Ends a main menu bar
-    drop { sys::ImPlot_PopColormap(1) }
+    drop {
+        sys::ImPlot_PopColormap(1);
+        stack_tracking::record_pop(StackKind::Colormap);
+    }
 );
 
 create_token!(
@@ -216,7 +699,10 @@ create_token!(
     pub struct StyleColorToken<'ui>;
 
     /// Ends a main menu bar
-    drop { sys::ImPlot_PopStyleColor(1) }
+    drop {
+        sys::ImPlot_PopStyleColor(1);
+        stack_tracking::record_pop(StackKind::StyleColor);
+    }
 );
 
 create_token!(
@@ -225,5 +711,72 @@ create_token!(
     pub struct StyleVarToken<'ui>;
 
     /// Ends a main menu bar
-    drop { sys::ImPlot_PopStyleVar(1) }
+    drop {
+        sys::ImPlot_PopStyleVar(1);
+        stack_tracking::record_pop(StackKind::StyleVar);
+    }
+);
+
+/// Value of a style variable, for pushing several at once with [`PlotUi::push_style_vars`].
+/// The variant has to match the type of the variable.
+#[derive(Clone, Copy, Debug)]
+pub enum StyleVarValue {
+    F32(f32),
+    I32(i32),
+    Vec2(ImVec2),
+}
+
+/// Tracks style colors pushed with [`PlotUi::push_style_colors`], which are all popped again
+/// when the token is dropped or `end()` is called on it.
+#[must_use]
+pub struct StyleColorsToken<'a> {
+    count: i32,
+    _marker: PhantomData<&'a PlotUi<'a>>,
+}
+
+impl StyleColorsToken<'_> {
+    /// Pops the style colors, same as dropping the token.
+    #[inline]
+    pub fn end(self) {
+        // left empty for drop
+    }
+}
+
+impl Drop for StyleColorsToken<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::ImPlot_PopStyleColor(self.count) }
+        stack_tracking::record_pop(StackKind::StyleColor);
+    }
+}
+
+/// Tracks style variables pushed with [`PlotUi::push_style_vars`], which are all popped again
+/// when the token is dropped or `end()` is called on it.
+#[must_use]
+pub struct StyleVarsToken<'a> {
+    count: i32,
+    _marker: PhantomData<&'a PlotUi<'a>>,
+}
+
+impl StyleVarsToken<'_> {
+    /// Pops the style variables, same as dropping the token.
+    #[inline]
+    pub fn end(self) {
+        // left empty for drop
+    }
+}
+
+impl Drop for StyleVarsToken<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::ImPlot_PopStyleVar(self.count) }
+        stack_tracking::record_pop(StackKind::StyleVar);
+    }
+}
+
+create_token!(
+    /// Tracks a group of aligned plots that can be ended by calling `.end()`
+    /// or by dropping
+    pub struct AlignedPlotsToken<'ui>;
+
+    /// Ends the group of aligned plots
+    drop { sys::ImPlot_EndAlignedPlots() }
 );
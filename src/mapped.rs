@@ -0,0 +1,233 @@
+//! # Memory-mapped data module
+//!
+//! This module (enabled by the `mmap` feature) plots recordings straight from memory-mapped
+//! files of raw `f64` or `f32` samples, so multi-gigabyte datasets can be browsed interactively
+//! without loading them into memory. Each frame, only the part of the file that is visible in
+//! the current plot limits is read, and it is reduced to a bounded number of points with
+//! [`decimate_minmax`](crate::decimate_minmax). That way the operating system only pages in
+//! what is looked at, and zoomed-out views still show all peaks:
+//! ```no_run
+//! # use implot::{Context, MappedDataset, MappedPlotter, MappedScalar, Plot};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! // Two interleaved f32 channels, sampled at 1 kHz
+//! // Safety: nothing else modifies or truncates the recording while it is plotted
+//! let dataset = unsafe { MappedDataset::open("recording.bin", MappedScalar::F32, 2) }.unwrap();
+//! let mut plotter = MappedPlotter::new(dataset, 0.0, 0.001);
+//!
+//! // Every frame:
+//! Plot::new("Recording").build(&plot_ui, |_| {
+//!     plotter.plot_line("channel 0", 0);
+//!     plotter.plot_line("channel 1", 1);
+//! });
+//! ```
+
+use crate::downsample::decimate_minmax_into;
+use crate::{sys, ImPlotRange, ImPlotRect, PlotLine, IMPLOT_AUTO};
+use memmap2::Mmap;
+use std::convert::TryInto;
+use std::fs::File;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+
+/// Type of the raw samples in a mapped file. Samples are read in native byte order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MappedScalar {
+    F32,
+    F64,
+}
+
+impl MappedScalar {
+    fn size(self) -> usize {
+        match self {
+            MappedScalar::F32 => std::mem::size_of::<f32>(),
+            MappedScalar::F64 => std::mem::size_of::<f64>(),
+        }
+    }
+}
+
+/// A memory-mapped file of raw samples, made up of `channels` interleaved channels. A file
+/// with one channel is just a flat array of samples.
+pub struct MappedDataset {
+    mmap: Mmap,
+    scalar: MappedScalar,
+    channels: usize,
+}
+
+impl MappedDataset {
+    /// Map the file at `path`. Trailing bytes that don't make up a full frame (one sample of
+    /// every channel) are ignored.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated, by this or any other process, for as long as
+    /// the dataset exists. The samples are read straight from the mapping, so a concurrent
+    /// modification is a data race, and accessing pages that were truncated away raises
+    /// `SIGBUS` on Unix. See [`memmap2::Mmap::map`].
+    ///
+    /// # Panics
+    /// Will panic if `channels` is zero.
+    pub unsafe fn open<P: AsRef<Path>>(
+        path: P,
+        scalar: MappedScalar,
+        channels: usize,
+    ) -> io::Result<Self> {
+        assert!(channels > 0, "A mapped dataset needs at least one channel");
+        let file = File::open(path)?;
+        let mmap = Mmap::map(&file)?;
+        Ok(Self {
+            mmap,
+            scalar,
+            channels,
+        })
+    }
+
+    /// Number of channels in the file.
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Number of samples per channel.
+    pub fn len(&self) -> usize {
+        self.mmap.len() / (self.scalar.size() * self.channels)
+    }
+
+    /// Returns true if the file contains no complete frame.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get sample `index` of the given channel, if it is in range.
+    pub fn get(&self, channel: usize, index: usize) -> Option<f64> {
+        if channel >= self.channels || index >= self.len() {
+            return None;
+        }
+        let size = self.scalar.size();
+        let start = (index * self.channels + channel) * size;
+        let bytes = &self.mmap[start..start + size];
+        // The slice has exactly the right length, so the conversions can't fail
+        Some(match self.scalar {
+            MappedScalar::F32 => f32::from_ne_bytes(bytes.try_into().unwrap()) as f64,
+            MappedScalar::F64 => f64::from_ne_bytes(bytes.try_into().unwrap()),
+        })
+    }
+}
+
+/// Plots channels of a [`MappedDataset`], with sample `i` at X position
+/// `x_start + i * x_step`. Holds the scratch buffers the visible part is decimated into, so
+/// no allocations happen from frame to frame once they have grown.
+pub struct MappedPlotter {
+    dataset: MappedDataset,
+    x_start: f64,
+    x_step: f64,
+    max_points: usize,
+    x: Vec<f64>,
+    y: Vec<f64>,
+}
+
+impl MappedPlotter {
+    /// Create a plotter for the dataset. `x_step` is the distance between samples along the X
+    /// axis, e.g. the sampling period, and has to be positive.
+    ///
+    /// # Panics
+    /// Will panic if `x_step` is not positive.
+    pub fn new(dataset: MappedDataset, x_start: f64, x_step: f64) -> Self {
+        assert!(
+            x_step > 0.0,
+            "The X step of a mapped dataset must be positive"
+        );
+        Self {
+            dataset,
+            x_start,
+            x_step,
+            max_points: 4096,
+            x: Vec::new(),
+            y: Vec::new(),
+        }
+    }
+
+    /// Set the maximum number of points plotted per channel, 4096 by default. Roughly twice the
+    /// plot width in pixels is enough to not lose any visible detail.
+    pub fn with_max_points(mut self, max_points: usize) -> Self {
+        self.max_points = max_points;
+        self
+    }
+
+    /// The plotted dataset.
+    pub fn dataset(&self) -> &MappedDataset {
+        &self.dataset
+    }
+
+    /// Indices of the samples that fall within the given X range, plus one sample on each side
+    /// so lines continue to the plot edges.
+    pub fn visible_indices(&self, x_range: ImPlotRange) -> Range<usize> {
+        let len = self.dataset.len();
+        let to_index = |x: f64| ((x - self.x_start) / self.x_step).max(0.0);
+        let start = (to_index(x_range.Min).floor() as usize).saturating_sub(1);
+        let end = (to_index(x_range.Max).ceil() as usize).saturating_add(2);
+        start.min(len)..end.min(len)
+    }
+
+    /// Plot a channel as a line into the current plot, reading only the visible samples. Call
+    /// this within a plot.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    pub fn plot_line(&mut self, label: &str, channel: usize) {
+        if channel >= self.dataset.channels() {
+            return;
+        }
+        let mut limits = ImPlotRect::new([0.0, 0.0], [0.0, 0.0]);
+        unsafe {
+            sys::ImPlot_GetPlotLimits(&mut limits, IMPLOT_AUTO, IMPLOT_AUTO);
+        }
+        let indices = self.visible_indices(limits.X);
+
+        self.x.clear();
+        self.y.clear();
+        let (dataset, x_start, x_step) = (&self.dataset, self.x_start, self.x_step);
+        // Two points per column
+        decimate_minmax_into(
+            indices.len(),
+            |offset| x_start + (indices.start + offset) as f64 * x_step,
+            |offset| dataset.get(channel, indices.start + offset).unwrap(),
+            (self.max_points / 2).max(1),
+            &mut self.x,
+            &mut self.y,
+        );
+        PlotLine::new(label).plot(&self.x, &self.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_mapped_dataset_channels() {
+        let path = std::env::temp_dir().join(format!(
+            "implot_test_mapped_dataset_{}_{:?}.bin",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        let mut file = File::create(&path).unwrap();
+        for value in [1.0f32, 10.0, 2.0, 20.0, 3.0, 30.0].iter() {
+            file.write_all(&value.to_ne_bytes()).unwrap();
+        }
+        // Incomplete trailing frame
+        file.write_all(&4.0f32.to_ne_bytes()).unwrap();
+        drop(file);
+
+        let dataset = unsafe { MappedDataset::open(&path, MappedScalar::F32, 2) }.unwrap();
+        assert_eq!(dataset.len(), 3);
+        assert_eq!(dataset.get(0, 2), Some(3.0));
+        assert_eq!(dataset.get(1, 1), Some(20.0));
+        assert_eq!(dataset.get(1, 3), None);
+
+        let plotter = MappedPlotter::new(dataset, 0.0, 0.5);
+        assert_eq!(plotter.visible_indices(ImPlotRange::new(0.6, 0.9)), 0..3);
+        assert_eq!(plotter.visible_indices(ImPlotRange::new(-5.0, -1.0)), 0..2);
+        std::fs::remove_file(&path).ok();
+    }
+}
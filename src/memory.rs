@@ -0,0 +1,115 @@
+//! # Memory module
+//!
+//! ImPlot allocates through imgui's allocator functions, which default to the C `malloc` and
+//! `free`. This module allows routing those allocations (imgui's own included, since they share
+//! the allocator) through Rust's global allocator instead, so that a tracking or arena
+//! `#[global_allocator]` sees them, and keeps a count of the bytes currently allocated:
+//! ```no_run
+//! // Before creating the imgui and implot contexts:
+//! unsafe { implot::use_rust_allocator() };
+//!
+//! // Later, e.g. in a debug overlay:
+//! println!("plot memory: {} bytes", implot::allocated_bytes());
+//! ```
+
+use crate::sys;
+use std::alloc::{self, Layout};
+use std::os::raw::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocation function signature used by imgui.
+pub type AllocFunction = unsafe extern "C" fn(size: usize, user_data: *mut c_void) -> *mut c_void;
+
+/// Deallocation function signature used by imgui. May be called with a null pointer.
+pub type FreeFunction = unsafe extern "C" fn(ptr: *mut c_void, user_data: *mut c_void);
+
+/// Alignment of allocations made by [`use_rust_allocator`], matching what `malloc` guarantees
+/// on common 64 bit platforms.
+const ALIGNMENT: usize = 16;
+
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Install custom allocation functions for imgui and ImPlot, mirroring imgui's
+/// `SetAllocatorFunctions`.
+///
+/// # Safety
+/// This has to be called before any imgui or implot context is created, or after all of them
+/// were destroyed, since memory allocated by one set of functions must not be freed by another.
+/// The functions have to be thread-safe if contexts are used on more than one thread, and
+/// `user_data` has to stay valid for as long as they are installed.
+#[rustversion::attr(since(1.48), doc(alias = "SetAllocatorFunctions"))]
+pub unsafe fn set_allocator_functions(
+    alloc_function: AllocFunction,
+    free_function: FreeFunction,
+    user_data: *mut c_void,
+) {
+    sys::igSetAllocatorFunctions(Some(alloc_function), Some(free_function), user_data);
+}
+
+/// Route all imgui and ImPlot allocations through Rust's global allocator, counting the
+/// allocated bytes (see [`allocated_bytes`]).
+///
+/// # Safety
+/// This has to be called before any imgui or implot context is created, or after all of them
+/// were destroyed, see [`set_allocator_functions`]. Memory allocated by `malloc` before would
+/// otherwise be handed to Rust's allocator when it is freed.
+pub unsafe fn use_rust_allocator() {
+    set_allocator_functions(rust_alloc, rust_free, std::ptr::null_mut());
+}
+
+/// Number of bytes currently allocated through the functions installed by
+/// [`use_rust_allocator`]. Always zero if they are not installed.
+pub fn allocated_bytes() -> usize {
+    ALLOCATED_BYTES.load(Ordering::Relaxed)
+}
+
+// The size of each allocation is stored in a header in front of it, since imgui does not pass
+// the size when freeing but Rust's allocator needs it.
+fn layout_for(size: usize) -> Option<Layout> {
+    Layout::from_size_align(size.checked_add(ALIGNMENT)?, ALIGNMENT).ok()
+}
+
+unsafe extern "C" fn rust_alloc(size: usize, _user_data: *mut c_void) -> *mut c_void {
+    let layout = match layout_for(size) {
+        Some(layout) => layout,
+        None => return std::ptr::null_mut(),
+    };
+    let base = alloc::alloc(layout);
+    if base.is_null() {
+        return std::ptr::null_mut();
+    }
+    (base as *mut usize).write(size);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    base.add(ALIGNMENT) as *mut c_void
+}
+
+unsafe extern "C" fn rust_free(ptr: *mut c_void, _user_data: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    let base = (ptr as *mut u8).sub(ALIGNMENT);
+    let size = (base as *const usize).read();
+    ALLOCATED_BYTES.fetch_sub(size, Ordering::Relaxed);
+    // The layout was valid when allocating, so it still is
+    alloc::dealloc(base, layout_for(size).unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rust_allocator_counts_bytes() {
+        unsafe {
+            let before = allocated_bytes();
+            let ptr = rust_alloc(100, std::ptr::null_mut());
+            assert!(!ptr.is_null());
+            assert_eq!(ptr as usize % ALIGNMENT, 0);
+            assert_eq!(allocated_bytes(), before + 100);
+            std::ptr::write_bytes(ptr as *mut u8, 0xAB, 100);
+            rust_free(ptr, std::ptr::null_mut());
+            rust_free(std::ptr::null_mut(), std::ptr::null_mut());
+            assert_eq!(allocated_bytes(), before);
+        }
+    }
+}
@@ -5,17 +5,24 @@
 
 #![allow(clippy::bad_bit_mask)]
 
-use crate::{AxisChoice, Context, PlotLegendFlags, PlotLocation, PlotUi, NUMBER_OF_AXES};
+use crate::intern::intern;
+use crate::plot_elements::{call_formatter, resume_callback_panic};
+use crate::{
+    AxisChoice, Marker, PlotLegendFlags, PlotLocation, PlotUi, TokenDropPolicy, NUMBER_OF_AXES,
+};
 pub use imgui::Condition;
-use implot_sys::{self as sys, ImAxis, ImPlotFlags, ImPlotLocation, ImPlotPoint, ImVec4};
-use std::ffi::CString;
-use std::os::raw::c_char;
+use implot_sys::{self as sys, ImAxis, ImPlotPoint, ImPlotRect, ImVec4};
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::sync::Arc;
 use std::{cell::RefCell, rc::Rc};
 pub use sys::{ImPlotRange, ImVec2};
 
-const DEFAULT_PLOT_SIZE_X: f32 = 400.0;
-const DEFAULT_PLOT_SIZE_Y: f32 = 400.0;
+pub(crate) const DEFAULT_PLOT_SIZE_X: f32 = 400.0;
+pub(crate) const DEFAULT_PLOT_SIZE_Y: f32 = 400.0;
 pub(crate) const IMPLOT_AUTO: i32 = -1;
+/// Item size that fills the available space exactly; -1 would leave one pixel free.
+const FILL_AVAILABLE: f32 = -f32::MIN_POSITIVE;
 pub(crate) const IMVEC2_ZERO: ImVec2 = ImVec2 { x: 0.0, y: 0.0 };
 pub(crate) const IMPLOT_AUTO_COL: ImVec4 = ImVec4 {
     x: 0.0,
@@ -36,6 +43,115 @@ enum AxisLimitSpecification {
     Single(ImPlotRange, PlotCond),
     /// Limits that are linked to limits of other plots (via clones of the same Rc)
     Linked(Rc<RefCell<ImPlotRange>>),
+    /// Primary axis of a pair of mirrored axes
+    MirroredPrimary(Rc<RefCell<MirroredAxisLimits>>),
+    /// Secondary axis of a pair of mirrored axes, showing the primary one in other units
+    MirroredSecondary(Rc<RefCell<MirroredAxisLimits>>),
+}
+
+/// A conversion between the units of two mirrored axes, see [`Plot::mirrored_axes`].
+/// Conversions are linear, i.e. `secondary = primary * scale + offset`, which covers things
+/// like °C to °F or Hz to rad/s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AxisConversion {
+    scale: f64,
+    offset: f64,
+}
+
+impl AxisConversion {
+    /// Create a conversion computing `primary * scale + offset`.
+    ///
+    /// # Panics
+    /// Will panic if `scale` is zero, because the conversion has to be invertible.
+    pub fn linear(scale: f64, offset: f64) -> Self {
+        assert!(scale != 0.0, "Axis conversion scale must not be zero");
+        Self { scale, offset }
+    }
+
+    /// Convert a value in primary axis units to secondary axis units.
+    pub fn forward(&self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+
+    /// Convert a value in secondary axis units to primary axis units.
+    pub fn inverse(&self, value: f64) -> f64 {
+        (value - self.offset) / self.scale
+    }
+
+    fn forward_range(&self, range: &ImPlotRange) -> ImPlotRange {
+        let (a, b) = (self.forward(range.Min), self.forward(range.Max));
+        ImPlotRange {
+            Min: a.min(b),
+            Max: a.max(b),
+        }
+    }
+
+    fn inverse_range(&self, range: &ImPlotRange) -> ImPlotRange {
+        let (a, b) = (self.inverse(range.Min), self.inverse(range.Max));
+        ImPlotRange {
+            Min: a.min(b),
+            Max: a.max(b),
+        }
+    }
+}
+
+/// Shared limits for a pair of mirrored axes, see [`Plot::mirrored_axes`]. Keep this around
+/// across frames (like the `Rc` passed to [`Plot::linked_axis_limits`]) so user interaction with
+/// either axis carries over to the next frame.
+#[derive(Clone, Debug)]
+pub struct MirroredAxisLimits {
+    primary: ImPlotRange,
+    secondary: ImPlotRange,
+    /// Primary limits as of the last synchronization, used to tell which axis the user changed
+    synced_primary: ImPlotRange,
+    conversion: AxisConversion,
+}
+
+impl MirroredAxisLimits {
+    /// Create mirrored limits starting out with the given primary axis limits.
+    pub fn new<L: Into<ImPlotRange>>(primary_limits: L, conversion: AxisConversion) -> Self {
+        let primary = primary_limits.into();
+        Self {
+            primary,
+            secondary: conversion.forward_range(&primary),
+            synced_primary: primary,
+            conversion,
+        }
+    }
+
+    /// Current limits of the primary axis.
+    pub fn primary(&self) -> ImPlotRange {
+        self.primary
+    }
+
+    /// Current limits of the secondary axis.
+    pub fn secondary(&self) -> ImPlotRange {
+        self.secondary
+    }
+
+    /// The conversion between the two axes.
+    pub fn conversion(&self) -> AxisConversion {
+        self.conversion
+    }
+
+    /// Bring the two ranges back in sync after ImPlot wrote to them. If only the secondary axis
+    /// was changed (e.g. the user zoomed on it), the primary follows it, otherwise the secondary
+    /// follows the primary.
+    fn sync(&mut self) {
+        let primary_changed = !ranges_equal(&self.primary, &self.synced_primary);
+        let expected_secondary = self.conversion.forward_range(&self.synced_primary);
+        let secondary_changed = !ranges_equal(&self.secondary, &expected_secondary);
+        if secondary_changed && !primary_changed {
+            self.primary = self.conversion.inverse_range(&self.secondary);
+        } else {
+            self.secondary = self.conversion.forward_range(&self.primary);
+        }
+        self.synced_primary = self.primary;
+    }
+}
+
+fn ranges_equal(a: &ImPlotRange, b: &ImPlotRange) -> bool {
+    a.Min == b.Min && a.Max == b.Max
 }
 
 /// Struct to represent an ImPlot. This is the main construct used to contain all kinds of plots in ImPlot.
@@ -55,14 +171,13 @@ enum AxisLimitSpecification {
 /// (If you are coming from the C++ implementation or the C bindings: build() calls both
 /// begin() and end() internally)
 pub struct Plot {
-    /// Title of the plot, shown on top. Stored as CString because that's what we'll use
-    /// afterwards, and this ensures the CString itself will stay alive long enough for the plot.
-    title: CString,
+    /// Title of the plot, shown on top. Interned, since plots are usually rebuilt every frame
+    /// with the same title.
+    title: Arc<CStr>,
     /// Size of the plot in [x, y] direction, in the same units imgui uses.
     size: [f32; 2],
-    /// Label of an axis. Stored as CString because that's what we'll use
-    /// afterwards, and this ensures the CString itself will stay alive long enough for the plot.
-    labels: [Option<CString>; NUMBER_OF_AXES],
+    /// Label of an axis, interned like the title.
+    labels: [Option<Arc<CStr>>; NUMBER_OF_AXES],
     /// Enable the axis
     axis_enabled: [bool; NUMBER_OF_AXES],
     /// Axis limits, if present
@@ -71,6 +186,8 @@ pub struct Plot {
     axis_limits_constraints: [Option<(f64, f64)>; NUMBER_OF_AXES],
     /// Axis zoom constraints, if present
     axis_zoom_constraints: [Option<(f64, f64)>; NUMBER_OF_AXES],
+    /// Axes to fit to the data when the plot is begun
+    axes_to_fit: [bool; NUMBER_OF_AXES],
     /// Positions for custom axis ticks, if any
     axis_tick_positions: [Option<Vec<f64>>; NUMBER_OF_AXES],
     /// Labels for custom axis ticks, if any. I'd prefer to store these together
@@ -79,11 +196,14 @@ pub struct Plot {
     /// The data is stored as CStrings because those are null-terminated, and since we have to
     /// convert to null-terminated data anyway, we may as well do that directly instead of cloning
     /// Strings and converting them afterwards.
-    axis_tick_labels: [Option<Vec<CString>>; NUMBER_OF_AXES],
+    axis_tick_labels: [Option<Vec<Arc<CStr>>>; NUMBER_OF_AXES],
     /// Axis scale (e.g.: linear, log10, ...)
-    axis_scales: [sys::ImPlotScale; NUMBER_OF_AXES],
+    axis_scales: [AxisScale; NUMBER_OF_AXES],
     /// Whether to also show the default ticks when showing custom ticks or not
     show_axis_default_ticks: [bool; NUMBER_OF_AXES],
+    /// Formatters for the tick labels, if any. They are shared with the plot token, since
+    /// ImPlot calls them until the plot is ended.
+    axis_formatters: [Option<AxisFormatter>; NUMBER_OF_AXES],
     /// Configuration for the legend, if specified. The tuple contains location, orientation
     /// and a boolean (true means legend is outside of plot, false means within). If nothing
     /// is set, implot's defaults are used. Note also  that if these are set, then implot's
@@ -105,12 +225,13 @@ impl Plot {
     /// Will panic if the title string contains internal null bytes.
     pub fn new(title: &str) -> Self {
         // Needed for initialization, see https://github.com/rust-lang/rust/issues/49147
-        const LABELS_NONE: Option<CString> = None;
+        const LABELS_NONE: Option<Arc<CStr>> = None;
         const LIMITS_NONE: Option<AxisLimitSpecification> = None;
         const LIMITS_CONSTRAINTS_NONE: Option<(f64, f64)> = None;
         const LIMITS_ZOOM_NONE: Option<(f64, f64)> = None;
         const POS_NONE: Option<Vec<f64>> = None;
-        const TICK_NONE: Option<Vec<CString>> = None;
+        const TICK_NONE: Option<Vec<Arc<CStr>>> = None;
+        const FORMATTER_NONE: Option<AxisFormatter> = None;
 
         let mut axis_enabled = [false; NUMBER_OF_AXES];
         axis_enabled[AxisChoice::X1 as usize] = true;
@@ -118,24 +239,49 @@ impl Plot {
 
         // TODO(4bb4) question these defaults, maybe remove some of them
         Self {
-            title: CString::new(title)
-                .unwrap_or_else(|_| panic!("String contains internal null bytes: {}", title)),
+            title: intern(title),
             size: [DEFAULT_PLOT_SIZE_X, DEFAULT_PLOT_SIZE_Y],
             labels: [LABELS_NONE; NUMBER_OF_AXES],
             axis_enabled,
             axis_limits: [LIMITS_NONE; NUMBER_OF_AXES],
             axis_limits_constraints: [LIMITS_CONSTRAINTS_NONE; NUMBER_OF_AXES],
             axis_zoom_constraints: [LIMITS_ZOOM_NONE; NUMBER_OF_AXES],
+            axes_to_fit: [false; NUMBER_OF_AXES],
             axis_tick_positions: [POS_NONE; NUMBER_OF_AXES],
             axis_tick_labels: [TICK_NONE; NUMBER_OF_AXES],
-            axis_scales: [AxisScale::Linear as sys::ImPlotScale; NUMBER_OF_AXES],
+            axis_scales: [AxisScale::Linear; NUMBER_OF_AXES],
             show_axis_default_ticks: [false; NUMBER_OF_AXES],
+            axis_formatters: [FORMATTER_NONE; NUMBER_OF_AXES],
             legend_configuration: None,
             plot_flags: PlotFlags::NONE.0 as sys::ImPlotFlags,
             axis_flags: [AxisFlags::NONE.0 as sys::ImPlotAxisFlags; NUMBER_OF_AXES],
         }
     }
 
+    /// Set the ID of the plot, which ImPlot uses to keep its state (e.g. the axis limits the
+    /// user zoomed to) across frames. It defaults to the title, so plots that share a title,
+    /// e.g. ones created in a loop, need distinct IDs. Internally, this appends `##id` to the
+    /// title, replacing any `##` suffix given with it:
+    /// ```no_run
+    /// # use implot::{Context, Plot};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// for sensor in ["north", "south"] {
+    ///     Plot::new("Temperature").id(sensor).build(&plot_ui, |_| {
+    ///         // Plot the sensor's data
+    ///     });
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Will panic if the ID contains internal null bytes.
+    pub fn id(mut self, id: &str) -> Self {
+        let title = self.title.to_string_lossy();
+        let visible = title.find("##").map_or(&*title, |end| &title[..end]);
+        self.title = intern(&format!("{}##{}", visible, id));
+        self
+    }
+
     #[inline]
     pub fn with_axis(mut self, choice: AxisChoice) -> Self {
         self.axis_enabled[choice as usize] = true;
@@ -144,12 +290,31 @@ impl Plot {
 
     /// Sets the plot size, given as [size_x, size_y]. Units are the same as
     /// what imgui uses. TODO(4bb4) ... which is? I'm not sure it's pixels
+    ///
+    /// Like for other imgui items, a size of zero uses the default size (`plot_default_size` in
+    /// the [`Style`](crate::Style)), and a negative size fills the available space minus its
+    /// magnitude, see [`Plot::size_fill`].
     #[inline]
     pub fn size(mut self, size: [f32; 2]) -> Self {
         self.size = size;
         self
     }
 
+    /// Make the plot fill the remaining space of the window (or child window, table cell, ...)
+    /// in both directions. This uses imgui's convention of negative sizes, so items after the
+    /// plot end up outside of the visible area; give the plot a fixed height with
+    /// [`Plot::size_fill_width`] to put something below it.
+    #[inline]
+    pub fn size_fill(self) -> Self {
+        self.size([FILL_AVAILABLE, FILL_AVAILABLE])
+    }
+
+    /// Make the plot fill the remaining width of the window, with the given height.
+    #[inline]
+    pub fn size_fill_width(self, height: f32) -> Self {
+        self.size([FILL_AVAILABLE, height])
+    }
+
     /// Set the x label of the plot
     ///
     /// # Panics
@@ -171,10 +336,7 @@ impl Plot {
     pub fn axis_label(mut self, label: &str, axis_choice: AxisChoice) -> Self {
         self.axis_enabled[axis_choice as usize] = true;
         self.labels[axis_choice as usize] = if !label.is_empty() {
-            Some(
-                CString::new(label)
-                    .unwrap_or_else(|_| panic!("String contains internal null bytes: {}", label)),
-            )
+            Some(intern(label))
         } else {
             None
         };
@@ -243,6 +405,39 @@ impl Plot {
         self.axis_limits(limits, AxisChoice::Y3, condition)
     }
 
+    /// Convenience function to set up the first X and Y axes in one go, with labels and flags.
+    /// An empty label means no label.
+    ///
+    /// # Panics
+    /// Will panic if the label strings contain internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "SetupAxes"))]
+    pub fn axes(
+        self,
+        x_label: &str,
+        y_label: &str,
+        x_flags: &AxisFlags,
+        y_flags: &AxisFlags,
+    ) -> Self {
+        self.x_label(x_label)
+            .y_label(y_label)
+            .with_x1_flags(x_flags)
+            .with_y1_flags(y_flags)
+    }
+
+    /// Convenience function to set the limits of the first X and Y axes in one go.
+    #[rustversion::attr(since(1.48), doc(alias = "SetupAxesLimits"))]
+    pub fn axes_limits(
+        self,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+        condition: PlotCond,
+    ) -> Self {
+        self.x1_limits([x_min, x_max], condition)
+            .y1_limits([y_min, y_max], condition)
+    }
+
     #[inline]
     pub fn axis_limits_constraints(mut self, axis: AxisChoice, v_min: f64, v_max: f64) -> Self {
         let axis_index = axis as usize;
@@ -257,6 +452,35 @@ impl Plot {
         self
     }
 
+    /// Fit the given axis, or all axes if `None`, to the data when the plot is begun, like
+    /// double-clicking the plot does. This is meant for plots that are created when the fit is
+    /// wanted, e.g. after a "Reset view" button was clicked:
+    /// ```no_run
+    /// # use implot::{Context, Plot};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// # let reset_clicked = true;
+    /// let mut plot = Plot::new("Signal");
+    /// if reset_clicked {
+    ///     plot = plot.fit_next_frame(None);
+    /// }
+    /// plot.build(&plot_ui, |_| {
+    ///     // Plot the data
+    /// });
+    /// ```
+    /// A plot configured once and built every frame would be fitted every frame; use
+    /// [`PlotUi::set_next_axes_to_fit`] or [`PlotUi::set_next_axis_to_fit`] for those instead.
+    #[rustversion::attr(since(1.48), doc(alias = "SetNextAxisToFit"))]
+    #[rustversion::attr(since(1.48), doc(alias = "SetNextAxesToFit"))]
+    #[inline]
+    pub fn fit_next_frame(mut self, axis_choice: Option<AxisChoice>) -> Self {
+        match axis_choice {
+            Some(axis_choice) => self.axes_to_fit[axis_choice as usize] = true,
+            None => self.axes_to_fit = [true; NUMBER_OF_AXES],
+        }
+        self
+    }
+
     /// Set linked Y limits of the plot for the given Y axis. Pass clones of the same `Rc` into
     /// other plots to link their limits with the same values. Call multiple times with different
     /// `y_axis_choice` values to set for multiple axes, or use the convenience methods such as
@@ -308,6 +532,40 @@ impl Plot {
         self.linked_axis_limits(limits, AxisChoice::Y3)
     }
 
+    /// Configure `secondary` as a mirror of `primary` in different units, e.g. °C on Y1 and °F on
+    /// Y3. Both axes are linked to the given limits, and zooming or panning either of them moves
+    /// the other one along according to the limits' [`AxisConversion`]:
+    /// ```no_run
+    /// # use implot::{AxisChoice, AxisConversion, MirroredAxisLimits, Plot};
+    /// # use std::{cell::RefCell, rc::Rc};
+    /// let limits = Rc::new(RefCell::new(MirroredAxisLimits::new(
+    ///     0.0..100.0,
+    ///     AxisConversion::linear(1.8, 32.0),
+    /// )));
+    /// let plot = Plot::new("Temperature")
+    ///     .axis_label("°C", AxisChoice::Y1)
+    ///     .axis_label("°F", AxisChoice::Y3)
+    ///     .mirrored_axes(AxisChoice::Y1, AxisChoice::Y3, limits.clone());
+    /// ```
+    ///
+    /// Note: This conflicts with `axis_limits` and `linked_axis_limits` for both axes, whichever
+    /// is called last on plot construction takes effect for a given axis.
+    #[inline]
+    pub fn mirrored_axes(
+        mut self,
+        primary: AxisChoice,
+        secondary: AxisChoice,
+        limits: Rc<RefCell<MirroredAxisLimits>>,
+    ) -> Self {
+        self.axis_enabled[primary as usize] = true;
+        self.axis_enabled[secondary as usize] = true;
+        self.axis_limits[primary as usize] =
+            Some(AxisLimitSpecification::MirroredPrimary(limits.clone()));
+        self.axis_limits[secondary as usize] =
+            Some(AxisLimitSpecification::MirroredSecondary(limits));
+        self
+    }
+
     /// Set X ticks without labels for the plot. The vector contains one label each in
     /// the form of a tuple `(label_position, label_string)`. The `show_default` setting
     /// determines whether the default ticks are also shown.
@@ -329,6 +587,7 @@ impl Plot {
         let axis_index = axis_choice as usize;
         self.axis_enabled[axis_index] = true;
         self.axis_tick_positions[axis_index] = Some(ticks.into());
+        self.axis_tick_labels[axis_index] = None;
         self.show_axis_default_ticks[axis_index] = show_default;
         self
     }
@@ -371,26 +630,131 @@ impl Plot {
         let axis_index = axis_choice as usize;
         self.axis_enabled[axis_index] = true;
         self.axis_tick_positions[axis_index] = Some(tick_labels.iter().map(|x| x.0).collect());
-        self.axis_tick_labels[axis_index] = Some(
-            tick_labels
-                .iter()
-                .map(|x| {
-                    CString::new(x.1.as_str())
-                        .unwrap_or_else(|_| panic!("String contains internal null bytes: {}", x.1))
-                })
-                .collect(),
-        );
+        self.axis_tick_labels[axis_index] =
+            Some(tick_labels.iter().map(|x| intern(&x.1)).collect());
         self.show_axis_default_ticks[axis_index] = show_default;
         self
     }
 
-    /// Set the plot flags, see the help for `PlotFlags` for what the available flags are
+    /// Set ticks at the given positions, with labels computed from the positions by
+    /// `formatter`, e.g. to map category indices to their names. The labels are formatted when
+    /// the plot is drawn, like with [`Plot::axis_format`], whose formatter this replaces, so
+    /// `formatter` is also used for the mouse position text and any default ticks. The
+    /// `show_default` setting determines whether the default ticks are also shown:
+    /// ```no_run
+    /// # use implot::{AxisChoice, Plot};
+    /// let names = ["apples", "pears", "plums"];
+    /// let plot = Plot::new("Fruit").axis_ticks_with_formatter(
+    ///     AxisChoice::X1,
+    ///     &[0.0, 1.0, 2.0],
+    ///     move |position| names[position as usize].to_string(),
+    ///     false,
+    /// );
+    /// ```
+    /// Labels are cut off at internal null bytes, and truncated if they don't fit into ImPlot's
+    /// label buffer.
+    pub fn axis_ticks_with_formatter<F: FnMut(f64) -> String + 'static>(
+        self,
+        axis_choice: AxisChoice,
+        ticks: &[f64],
+        formatter: F,
+        show_default: bool,
+    ) -> Self {
+        self.axis_ticks(axis_choice, ticks, show_default)
+            .axis_format(axis_choice, formatter)
+    }
+
+    /// Format the tick labels of an axis with `formatter`, which gets the value of a tick and
+    /// returns its label. It is also used for the mouse position text. If `formatter` panics,
+    /// the panic continues once ImPlot returns, at the latest when the plot is ended. For values
+    /// with SI prefixes or in engineering notation, see [`SiFormat`](crate::SiFormat):
+    /// ```no_run
+    /// # use implot::{AxisChoice, Plot};
+    /// let plot = Plot::new("Prices")
+    ///     .axis_format(AxisChoice::Y1, |value| format!("${:.2}", value));
+    /// ```
+    #[rustversion::attr(since(1.48), doc(alias = "SetupAxisFormat"))]
+    pub fn axis_format<F: FnMut(f64) -> String + 'static>(
+        mut self,
+        axis_choice: AxisChoice,
+        formatter: F,
+    ) -> Self {
+        let axis_index = axis_choice as usize;
+        self.axis_enabled[axis_index] = true;
+        self.axis_formatters[axis_index] = Some(Rc::new(RefCell::new(Box::new(formatter))));
+        self
+    }
+
+    /// Set the plot flags, see the help for `PlotFlags` for what the available flags are. This
+    /// replaces all flags set before, use [`Plot::add_flags`] or the methods for single flags
+    /// like [`Plot::no_legend`] to keep them.
     #[inline]
     pub fn with_flags(mut self, flags: &PlotFlags) -> Self {
         self.plot_flags = flags.0 as sys::ImPlotFlags;
         self
     }
 
+    /// Set the given plot flags in addition to the ones set before.
+    #[inline]
+    pub fn add_flags(mut self, flags: &PlotFlags) -> Self {
+        self.plot_flags |= flags.0 as sys::ImPlotFlags;
+        self
+    }
+
+    /// Hide the title of the plot.
+    #[inline]
+    pub fn no_title(self) -> Self {
+        self.add_flags(&PlotFlags::NO_TITLE)
+    }
+
+    /// Hide the legend of the plot.
+    #[inline]
+    pub fn no_legend(self) -> Self {
+        self.add_flags(&PlotFlags::NO_LEGEND)
+    }
+
+    /// Don't show the mouse position in plot coordinates while the plot is hovered.
+    #[inline]
+    pub fn no_mouse_text(self) -> Self {
+        self.add_flags(&PlotFlags::NO_MOUSE_TEXT)
+    }
+
+    /// Make the plot ignore user input, e.g. for plots that are only displayed.
+    #[inline]
+    pub fn no_inputs(self) -> Self {
+        self.add_flags(&PlotFlags::NO_INPUTS)
+    }
+
+    /// Disable the context menus of the plot.
+    #[inline]
+    pub fn no_menus(self) -> Self {
+        self.add_flags(&PlotFlags::NO_MENUS)
+    }
+
+    /// Disable box selection for zooming.
+    #[inline]
+    pub fn no_box_select(self) -> Self {
+        self.add_flags(&PlotFlags::NO_BOX_SELECT)
+    }
+
+    /// Don't draw the frame around the plot.
+    #[inline]
+    pub fn no_frame(self) -> Self {
+        self.add_flags(&PlotFlags::NO_FRAME)
+    }
+
+    /// Keep the X and Y axes at the same units per pixel, e.g. for maps or geometry.
+    #[inline]
+    pub fn equal_aspect(self) -> Self {
+        self.add_flags(&PlotFlags::EQUAL)
+    }
+
+    /// Replace the mouse cursor with crosshairs while the plot is hovered.
+    #[inline]
+    pub fn crosshairs(self) -> Self {
+        self.add_flags(&PlotFlags::CROSSHAIRS)
+    }
+
     /// Set the axis flags for the X axis in this plot
     #[inline]
     pub fn with_x1_flags(self, flags: &AxisFlags) -> Self {
@@ -428,7 +792,7 @@ impl Plot {
     #[inline]
     pub fn with_x1_scale(mut self, scale: &AxisScale) -> Self {
         let axis_index = AxisChoice::X1 as usize;
-        self.axis_scales[axis_index] = *scale as sys::ImPlotScale;
+        self.axis_scales[axis_index] = *scale;
         self
     }
 
@@ -436,7 +800,7 @@ impl Plot {
     #[inline]
     pub fn with_y1_scale(mut self, scale: &AxisScale) -> Self {
         let axis_index = AxisChoice::Y1 as usize;
-        self.axis_scales[axis_index] = *scale as sys::ImPlotScale;
+        self.axis_scales[axis_index] = *scale;
         self
     }
 
@@ -445,7 +809,7 @@ impl Plot {
     pub fn with_axis_scale(mut self, axis_choice: AxisChoice, scale: &AxisScale) -> Self {
         let axis_index = axis_choice as usize;
         self.axis_enabled[axis_index] = true;
-        self.axis_scales[axis_index] = *scale as sys::ImPlotScale;
+        self.axis_scales[axis_index] = *scale;
         self
     }
 
@@ -467,7 +831,7 @@ impl Plot {
                         axis_index as ImAxis,
                         limits.Min,
                         limits.Max,
-                        *condition as sys::ImPlotCond,
+                        (*condition).into(),
                     );
                 },
                 AxisLimitSpecification::Linked(range) => {
@@ -481,6 +845,33 @@ impl Plot {
                         );
                     }
                 }
+                AxisLimitSpecification::MirroredPrimary(mirrored)
+                | AxisLimitSpecification::MirroredSecondary(mirrored) => {
+                    // --- Mirrored limit-setting ---
+                    // Syncing is idempotent, so it does not matter which of the two axes of the
+                    // pair gets here first.
+                    let mut borrowed = mirrored.borrow_mut();
+                    borrowed.sync();
+                    let range = match limits {
+                        AxisLimitSpecification::MirroredPrimary(_) => &mut borrowed.primary,
+                        _ => &mut borrowed.secondary,
+                    };
+                    unsafe {
+                        sys::ImPlot_SetNextAxisLinks(
+                            axis_index as ImAxis,
+                            &mut range.Min,
+                            &mut range.Max,
+                        );
+                    }
+                }
+            }
+        }
+
+        for (axis_index, fit) in self.axes_to_fit.iter().enumerate() {
+            if *fit {
+                unsafe {
+                    sys::ImPlot_SetNextAxisToFit(axis_index as ImAxis);
+                }
             }
         }
     }
@@ -496,17 +887,14 @@ impl Plot {
             .enumerate()
             .for_each(|(k, ((positions, labels), keep_default))| {
                 if positions.is_some() && !positions.as_ref().unwrap().is_empty() {
-                    // The vector of pointers we create has to have a longer lifetime
-                    let mut pointer_vec;
-                    let labels_pointer = if let Some(labels_value) = &labels {
-                        pointer_vec = labels_value
-                            .iter()
-                            .map(|x| x.as_ptr() as *const c_char)
-                            .collect::<Vec<*const c_char>>();
-                        pointer_vec.as_mut_ptr()
-                    } else {
-                        std::ptr::null_mut()
-                    };
+                    // The pointers are only needed during the call, so they aren't stored in
+                    // the plot, which keeps it free of raw pointers
+                    let label_pointers: Option<Vec<*const c_char>> = labels
+                        .as_ref()
+                        .map(|labels| labels.iter().map(|label| label.as_ptr()).collect());
+                    let labels_pointer = label_pointers
+                        .as_ref()
+                        .map_or(std::ptr::null(), |pointers| pointers.as_ptr());
 
                     unsafe {
                         sys::ImPlot_SetupAxisTicks_doublePtr(
@@ -541,8 +929,6 @@ impl Plot {
         };
 
         if should_render {
-            self.maybe_set_tick_labels();
-
             for (axis, enabled) in self.axis_enabled.iter().enumerate() {
                 if !enabled {
                     continue;
@@ -552,7 +938,20 @@ impl Plot {
                     .map_or_else(std::ptr::null, |s| s.as_ptr());
                 unsafe {
                     sys::ImPlot_SetupAxis(axis as ImAxis, ptr, self.axis_flags[axis]);
-                    sys::ImPlot_SetupAxisScale_PlotScale(axis as ImAxis, self.axis_scales[axis]);
+                    sys::ImPlot_SetupAxisScale_PlotScale(
+                        axis as ImAxis,
+                        self.axis_scales[axis].into(),
+                    );
+                }
+
+                if let Some(formatter) = &self.axis_formatters[axis] {
+                    unsafe {
+                        sys::ImPlot_SetupAxisFormat_PlotFormatter(
+                            axis as ImAxis,
+                            Some(call_formatter::<FormatterFn>),
+                            formatter.as_ptr() as *mut c_void,
+                        );
+                    }
                 }
 
                 if let Some(minmax) = self.axis_limits_constraints[axis] {
@@ -568,6 +967,10 @@ impl Plot {
                 }
             }
 
+            // Ticks without labels are labeled when they are set up, so this has to come after
+            // the scales and formatters of the axes are set up
+            self.maybe_set_tick_labels();
+
             // Configure legend location, if one was set. This has to be called between begin() and
             // end(), but since only the last call to it actually affects the outcome, I'm adding
             // it here instead of as a freestanding function. If this is too restrictive (for
@@ -579,13 +982,14 @@ impl Plot {
                 let location: PlotLocation = legend_config.0;
                 let flags: PlotLegendFlags = legend_config.1;
                 unsafe {
-                    sys::ImPlot_SetupLegend(location as ImPlotLocation, flags.0 as ImPlotFlags);
+                    sys::ImPlot_SetupLegend(location.into(), flags.0 as sys::ImPlotLegendFlags);
                 }
             }
 
             Some(PlotToken {
-                context: plot_ui.context,
-                plot_title: self.title.clone(),
+                drop_policy: Some(plot_ui.context.token_drop_policy()),
+                plot_title: Arc::clone(&self.title),
+                _formatters: self.axis_formatters.iter().flatten().cloned().collect(),
             })
         } else {
             // In contrast with imgui windows, end() does not have to be
@@ -597,33 +1001,111 @@ impl Plot {
     /// Creates a window and runs a closure to construct the contents. This internally
     /// calls `begin` and `end`.
     ///
+    /// The plot is not consumed, so a plot that does not change can be configured once and
+    /// built every frame, without converting its title, labels and ticks again.
+    ///
     /// Note: the closure is not called if ImPlot::BeginPlot() returned
     /// false - TODO(4bb4) figure out if this is if things are not rendered
     #[rustversion::attr(since(1.48), doc(alias = "BeginPlot"))]
     #[rustversion::attr(since(1.48), doc(alias = "EndPlot"))]
-    pub fn build<F: FnOnce(&PlotToken)>(self, plot_ui: &PlotUi, f: F) {
+    pub fn build<F: FnOnce(&PlotToken)>(&self, plot_ui: &PlotUi, f: F) {
         if let Some(token) = self.begin(plot_ui) {
             f(&token);
             token.end()
         }
     }
+
+    /// Like [`Plot::build`], with additional closures for drawing below and above the plot
+    /// items through [`PlotToken::get_plot_draw_list`], e.g. shading for night hours in the
+    /// background, or threshold bands as an overlay. `background` is called once the plot is
+    /// set up, so after the grid is drawn, and `overlay` after the contents from `f`. Both are
+    /// clipped to the plot area. The legend and mouse position text are still drawn on top:
+    /// ```no_run
+    /// # use implot::{AxisChoice, Context, ImVec4, Plot};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// Plot::new("Temperature").build_with_hooks(
+    ///     &plot_ui,
+    ///     |plot| {
+    ///         let night = ImVec4 { x: 0.0, y: 0.0, z: 0.3, w: 0.3 };
+    ///         let min = plot.plot_to_pixels_f32(0.0, 40.0, AxisChoice::X1, AxisChoice::Y1);
+    ///         let max = plot.plot_to_pixels_f32(6.0, -10.0, AxisChoice::X1, AxisChoice::Y1);
+    ///         plot.get_plot_draw_list().add_rect_filled(min, max, night);
+    ///     },
+    ///     |_| {
+    ///         // Plot the items
+    ///     },
+    ///     |_| {},
+    /// );
+    /// ```
+    pub fn build_with_hooks<B, F, O>(&self, plot_ui: &PlotUi, background: B, f: F, overlay: O)
+    where
+        B: FnOnce(&PlotToken),
+        F: FnOnce(&PlotToken),
+        O: FnOnce(&PlotToken),
+    {
+        if let Some(token) = self.begin(plot_ui) {
+            token.setup_finish();
+            {
+                let _clip = token.push_plot_clip_rect(0.0);
+                background(&token);
+            }
+            f(&token);
+            {
+                let _clip = token.push_plot_clip_rect(0.0);
+                overlay(&token);
+            }
+            token.end()
+        }
+    }
 }
 
 /// Tracks a plot that must be ended by calling `.end()`
 pub struct PlotToken {
-    context: *const Context,
-    /// For better error messages
-    plot_title: CString,
+    /// Policy of the context when the plot was begun, `None` once the plot has been ended
+    drop_policy: Option<TokenDropPolicy>,
+    /// For better error messages, shared with the plot so beginning it does not allocate
+    plot_title: Arc<CStr>,
+    /// Axis formatters of the plot, kept alive until the plot is ended
+    _formatters: Vec<AxisFormatter>,
 }
 
 pub type PlotDragToolFlags = sys::ImPlotDragToolFlags_;
 
+type FormatterFn = Box<dyn FnMut(f64) -> String>;
+
+/// A tick label formatter set with [`Plot::axis_format`]. The `RefCell` gives the pointer to
+/// the boxed closure that is handed to ImPlot.
+type AxisFormatter = Rc<RefCell<FormatterFn>>;
+
+/// What happened to a drag tool this frame, see [`PlotToken::drag_rect_response`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DragToolResponse {
+    /// The user changed the coordinates of the tool.
+    pub changed: bool,
+    pub clicked: bool,
+    pub hovered: bool,
+    pub held: bool,
+}
+
 impl PlotToken {
     /// End a previously begin()'ed plot.
     #[rustversion::attr(since(1.48), doc(alias = "EndPlot"))]
     pub fn end(mut self) {
-        self.context = std::ptr::null();
+        self.drop_policy = None;
         unsafe { sys::ImPlot_EndPlot() };
+        // Tick labels may be formatted by axis formatters as late as this
+        resume_callback_panic();
+    }
+
+    /// Finish setting up the plot. ImPlot does this implicitly on the first call that needs the
+    /// plot layout, such as plotting an item or converting coordinates, so this is only needed
+    /// after custom `Setup*` calls (e.g. through [`crate::sys`]) to lock them in before querying the
+    /// plot geometry. No `Setup*` functions may be called afterwards.
+    #[rustversion::attr(since(1.48), doc(alias = "SetupFinish"))]
+    pub fn setup_finish(&self) {
+        unsafe { sys::ImPlot_SetupFinish() };
+        resume_callback_panic();
     }
 
     // --- Miscellaneous -----------------------------------------------------------------------------
@@ -633,7 +1115,8 @@ impl PlotToken {
         unsafe { sys::ImPlot_IsPlotHovered() }
     }
 
-    /// Returns true if the user changed the coordinates.
+    /// Returns true if the user changed the coordinates. [`PlotToken::drag_rect_response`] does
+    /// the same with a rectangle and a response struct instead of separate out parameters.
     #[rustversion::attr(since(1.48), doc(alias = "DragRect"))]
     #[allow(clippy::too_many_arguments)]
     pub fn drag_rect(
@@ -665,11 +1148,119 @@ impl PlotToken {
         }
     }
 
+    /// Draw a rectangle that can be moved and resized by dragging it, e.g. for selecting a
+    /// region of interest. `rect` is read and updated in place, and the returned response tells
+    /// whether it was changed, clicked, hovered or held this frame:
+    /// ```no_run
+    /// # use implot::{Context, ImPlotRect, ImVec4, Plot, PlotDragToolFlags};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// let mut selection = ImPlotRect::new([0.2, 0.8], [0.2, 0.8]);
+    /// let color = ImVec4 { x: 1.0, y: 0.5, z: 0.0, w: 1.0 };
+    ///
+    /// // Every frame:
+    /// Plot::new("Selection").build(&plot_ui, |plot| {
+    ///     let flags = PlotDragToolFlags::NONE;
+    ///     if plot.drag_rect_response(0, &mut selection, color, flags).changed {
+    ///         // Update whatever depends on the selection
+    ///     }
+    /// });
+    /// ```
+    #[rustversion::attr(since(1.48), doc(alias = "DragRect"))]
+    pub fn drag_rect_response(
+        &self,
+        id: i32,
+        rect: &mut ImPlotRect,
+        color: ImVec4,
+        flags: PlotDragToolFlags,
+    ) -> DragToolResponse {
+        let (mut clicked, mut hovered, mut held) = (false, false, false);
+        let changed = unsafe {
+            sys::ImPlot_DragRect(
+                id,
+                &mut rect.X.Min,
+                &mut rect.Y.Min,
+                &mut rect.X.Max,
+                &mut rect.Y.Max,
+                color,
+                flags.0 as sys::ImPlotDragToolFlags,
+                &mut clicked,
+                &mut hovered,
+                &mut held,
+            )
+        };
+        DragToolResponse {
+            changed,
+            clicked,
+            hovered,
+            held,
+        }
+    }
+
+    /// Draw a vertical line at `x` that can be dragged along the X axis, e.g. as a cursor
+    /// for selecting a time range. Returns true if the user changed the position. `clicked`,
+    /// `hovered` and `held` are set to whether the line was clicked, hovered or held this frame.
+    #[rustversion::attr(since(1.48), doc(alias = "DragLineX"))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn drag_line_x(
+        &self,
+        id: i32,
+        x: &mut f64,
+        color: ImVec4,
+        thickness: f32,
+        flags: PlotDragToolFlags,
+        clicked: &mut bool,
+        hovered: &mut bool,
+        held: &mut bool,
+    ) -> bool {
+        unsafe {
+            sys::ImPlot_DragLineX(
+                id,
+                x,
+                color,
+                thickness,
+                flags.0 as sys::ImPlotDragToolFlags,
+                clicked,
+                hovered,
+                held,
+            )
+        }
+    }
+
+    /// Draw a horizontal line at `y` that can be dragged along the Y axis. Returns true if the
+    /// user changed the position, see [`PlotToken::drag_line_x`] for the other arguments.
+    #[rustversion::attr(since(1.48), doc(alias = "DragLineY"))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn drag_line_y(
+        &self,
+        id: i32,
+        y: &mut f64,
+        color: ImVec4,
+        thickness: f32,
+        flags: PlotDragToolFlags,
+        clicked: &mut bool,
+        hovered: &mut bool,
+        held: &mut bool,
+    ) -> bool {
+        unsafe {
+            sys::ImPlot_DragLineY(
+                id,
+                y,
+                color,
+                thickness,
+                flags.0 as sys::ImPlotDragToolFlags,
+                clicked,
+                hovered,
+                held,
+            )
+        }
+    }
+
     /// Set the axis to be used for any upcoming plot elements
     #[rustversion::attr(since(1.48), doc(alias = "SetAxis"))]
     pub fn set_axis(&self, axis_choice: AxisChoice) {
         unsafe {
-            sys::ImPlot_SetAxis(axis_choice as sys::ImAxis);
+            sys::ImPlot_SetAxis(axis_choice.into());
         }
     }
 
@@ -686,8 +1277,8 @@ impl PlotToken {
             sys::ImPlot_PixelsToPlot_Vec2(
                 &mut point as *mut ImPlotPoint,
                 *pixel_position,
-                x_axis as sys::ImAxis,
-                y_axis as sys::ImAxis,
+                x_axis.into(),
+                y_axis.into(),
             );
         }
         point
@@ -709,8 +1300,8 @@ impl PlotToken {
                 &mut point as *mut ImPlotPoint,
                 pixel_position_x,
                 pixel_position_y,
-                x_axis as sys::ImAxis,
-                y_axis as sys::ImAxis,
+                x_axis.into(),
+                y_axis.into(),
             );
         }
         point
@@ -729,8 +1320,8 @@ impl PlotToken {
             sys::ImPlot_PlotToPixels_PlotPoInt(
                 &mut pixel_position as *mut ImVec2,
                 *plot_position,
-                x_axis as sys::ImAxis,
-                y_axis as sys::ImAxis,
+                x_axis.into(),
+                y_axis.into(),
             );
         }
         pixel_position
@@ -751,8 +1342,8 @@ impl PlotToken {
                 &mut pixel_position as *mut ImVec2,
                 plot_position_x,
                 plot_position_y,
-                x_axis as sys::ImAxis,
-                y_axis as sys::ImAxis,
+                x_axis.into(),
+                y_axis.into(),
             );
         }
         pixel_position
@@ -765,8 +1356,8 @@ impl PlotToken {
         x_axis: Option<AxisChoice>,
         y_axis: Option<AxisChoice>,
     ) -> sys::ImPlotRect {
-        let x_axis = x_axis.map_or_else(|| IMPLOT_AUTO as sys::ImAxis, |x| x as sys::ImAxis);
-        let y_axis = y_axis.map_or_else(|| IMPLOT_AUTO as sys::ImAxis, |y| y as sys::ImAxis);
+        let x_axis = x_axis.map_or_else(|| IMPLOT_AUTO as sys::ImAxis, |x| x.into());
+        let y_axis = y_axis.map_or_else(|| IMPLOT_AUTO as sys::ImAxis, |y| y.into());
 
         // ImPlotLimits doesn't seem to have default()
         let mut limits = sys::ImPlotRect {
@@ -774,11 +1365,7 @@ impl PlotToken {
             Y: ImPlotRange { Min: 0.0, Max: 0.0 },
         };
         unsafe {
-            sys::ImPlot_GetPlotLimits(
-                &mut limits as *mut sys::ImPlotRect,
-                x_axis as sys::ImAxis,
-                y_axis as sys::ImAxis,
-            );
+            sys::ImPlot_GetPlotLimits(&mut limits as *mut sys::ImPlotRect, x_axis, y_axis);
         }
         limits
     }
@@ -786,12 +1373,94 @@ impl PlotToken {
     /// Returns true if the axis plot area in the current plot is hovered.
     #[rustversion::attr(since(1.48), doc(alias = "IsAxisHovered"))]
     pub fn is_axis_hovered(&self, axis: AxisChoice) -> bool {
-        unsafe { sys::ImPlot_IsAxisHovered(axis as sys::ImAxis) }
+        unsafe { sys::ImPlot_IsAxisHovered(axis.into()) }
     }
 
-    /// Returns true if the given item in the legend of the current plot is hovered.
+    /// Returns true if the given item in the legend of the current plot is hovered. The entry
+    /// is given by the label of the item, including any `##` suffix. Call this after the item
+    /// was plotted.
+    ///
+    /// # Panics
+    /// Will panic if the legend entry string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "IsLegendEntryHovered"))]
     pub fn is_legend_entry_hovered(&self, legend_entry: &str) -> bool {
-        unsafe { sys::ImPlot_IsLegendEntryHovered(legend_entry.as_ptr() as *const c_char) }
+        unsafe { sys::ImPlot_IsLegendEntryHovered(intern(legend_entry).as_ptr()) }
+    }
+
+    /// Returns the label of the legend entry of the current plot that is hovered, if any, as
+    /// shown in the legend (without a `##` suffix). Call this after plotting the items, e.g. to
+    /// highlight the hovered series elsewhere in the UI.
+    pub fn hovered_legend_entry(&self) -> Option<String> {
+        unsafe {
+            let plot = sys::ImPlot_GetCurrentPlot();
+            if plot.is_null() {
+                return None;
+            }
+            let items = &mut (*plot).Items as *mut sys::ImPlotItemGroup;
+            (0..sys::ImPlotItemGroup_GetLegendCount(items))
+                .find(|&index| (*sys::ImPlotItemGroup_GetLegendItem(items, index)).LegendHovered)
+                .map(|index| {
+                    CStr::from_ptr(sys::ImPlotItemGroup_GetLegendLabel(items, index))
+                        .to_string_lossy()
+                        .into_owned()
+                })
+        }
+    }
+
+    /// Show a tooltip with custom imgui content while the given entry in the legend of the
+    /// current plot is hovered, e.g. statistics or metadata of the series. Call this after the
+    /// item with that label was plotted. The closure is only called while the tooltip is shown,
+    /// and the return value tells whether that was the case:
+    /// ```no_run
+    /// # use implot::{Plot, PlotLine, Context};
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// # let (x, y) = (vec![0.0, 1.0], vec![2.0, 3.0]);
+    /// Plot::new("Prices").build(&plot_ui, |plot| {
+    ///     PlotLine::new("price").plot(&x, &y);
+    ///     plot.legend_entry_tooltip("price", || {
+    ///         // Draw imgui widgets, e.g. ui.text("last: 3.0")
+    ///     });
+    /// });
+    /// ```
+    ///
+    /// # Panics
+    /// Will panic if the legend entry string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "IsLegendEntryHovered"))]
+    pub fn legend_entry_tooltip<F: FnOnce()>(&self, legend_entry: &str, f: F) -> bool {
+        let label = intern(legend_entry);
+        if !unsafe { sys::ImPlot_IsLegendEntryHovered(label.as_ptr()) } {
+            return false;
+        }
+        unsafe {
+            sys::igBeginTooltip();
+        }
+        f();
+        unsafe {
+            sys::igEndTooltip();
+        }
+        true
+    }
+
+    /// Open a popup with custom imgui content when the given legend entry is right-clicked,
+    /// e.g. a settings menu for the series with color pickers and toggles. Call this after the
+    /// item with that label was plotted. The closure is only called while the popup is open,
+    /// and the return value tells whether that was the case.
+    ///
+    /// # Panics
+    /// Will panic if the legend entry string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "BeginLegendPopup"))]
+    pub fn legend_popup<F: FnOnce()>(&self, legend_entry: &str, f: F) -> bool {
+        let label = intern(legend_entry);
+        // Mouse button 1 is the right mouse button, as in ImPlot's default argument
+        if !unsafe { sys::ImPlot_BeginLegendPopup(label.as_ptr(), 1) } {
+            return false;
+        }
+        f();
+        unsafe {
+            sys::ImPlot_EndLegendPopup();
+        }
+        true
     }
 
     /// Returns the mouse position in x,y coordinates of the current or most recent plot,
@@ -803,17 +1472,82 @@ impl PlotToken {
         y_axis: Option<AxisChoice>,
     ) -> ImPlotPoint {
         let mut point = ImPlotPoint { x: 0.0, y: 0.0 }; // doesn't seem to have default()
-        let x_axis = x_axis.map_or_else(|| IMPLOT_AUTO as sys::ImAxis, |x| x as sys::ImAxis);
-        let y_axis = y_axis.map_or_else(|| IMPLOT_AUTO as sys::ImAxis, |y| y as sys::ImAxis);
+        let x_axis = x_axis.map_or_else(|| IMPLOT_AUTO as sys::ImAxis, |x| x.into());
+        let y_axis = y_axis.map_or_else(|| IMPLOT_AUTO as sys::ImAxis, |y| y.into());
         unsafe {
             sys::ImPlot_GetPlotMousePos(&mut point as *mut ImPlotPoint, x_axis, y_axis);
         }
         point
     }
 
+    // --- Next item styling ----------------------------------------------------------------------
+    // These apply to the next plotted item only, and are reset afterwards. `None` means using
+    // the value from the current style (or the automatic color, for colors).
+
+    /// Set the line color and weight of the next item.
+    #[rustversion::attr(since(1.48), doc(alias = "SetNextLineStyle"))]
+    pub fn set_next_line_style(&self, color: Option<ImVec4>, weight: Option<f32>) {
+        unsafe {
+            sys::ImPlot_SetNextLineStyle(
+                color.unwrap_or(IMPLOT_AUTO_COL),
+                weight.unwrap_or(IMPLOT_AUTO as f32),
+            );
+        }
+    }
+
+    /// Set the fill color of the next item, and the factor its alpha is multiplied with.
+    #[rustversion::attr(since(1.48), doc(alias = "SetNextFillStyle"))]
+    pub fn set_next_fill_style(&self, color: Option<ImVec4>, alpha_modifier: Option<f32>) {
+        unsafe {
+            sys::ImPlot_SetNextFillStyle(
+                color.unwrap_or(IMPLOT_AUTO_COL),
+                alpha_modifier.unwrap_or(IMPLOT_AUTO as f32),
+            );
+        }
+    }
+
+    /// Set the marker style of the next item: marker shape, size, fill color, outline weight
+    /// and outline color.
+    #[rustversion::attr(since(1.48), doc(alias = "SetNextMarkerStyle"))]
+    pub fn set_next_marker_style(
+        &self,
+        marker: Option<Marker>,
+        size: Option<f32>,
+        fill: Option<ImVec4>,
+        weight: Option<f32>,
+        outline: Option<ImVec4>,
+    ) {
+        unsafe {
+            sys::ImPlot_SetNextMarkerStyle(
+                marker.map_or(IMPLOT_AUTO, |marker| marker.into()),
+                size.unwrap_or(IMPLOT_AUTO as f32),
+                fill.unwrap_or(IMPLOT_AUTO_COL),
+                weight.unwrap_or(IMPLOT_AUTO as f32),
+                outline.unwrap_or(IMPLOT_AUTO_COL),
+            );
+        }
+    }
+
+    /// Set the color, whisker size and line weight of the next error bars.
+    #[rustversion::attr(since(1.48), doc(alias = "SetNextErrorBarStyle"))]
+    pub fn set_next_error_bar_style(
+        &self,
+        color: Option<ImVec4>,
+        size: Option<f32>,
+        weight: Option<f32>,
+    ) {
+        unsafe {
+            sys::ImPlot_SetNextErrorBarStyle(
+                color.unwrap_or(IMPLOT_AUTO_COL),
+                size.unwrap_or(IMPLOT_AUTO as f32),
+                weight.unwrap_or(IMPLOT_AUTO as f32),
+            );
+        }
+    }
+
     pub fn hide_next_item(&self, hidden: bool, when: PlotCond) {
         unsafe {
-            sys::ImPlot_HideNextItem(hidden, when as sys::ImPlotCond);
+            sys::ImPlot_HideNextItem(hidden, when.into());
         }
     }
 
@@ -834,11 +1568,30 @@ impl PlotToken {
 
 impl Drop for PlotToken {
     fn drop(&mut self) {
-        if !self.context.is_null() && !std::thread::panicking() {
-            panic!(
-                "Warning: A PlotToken for plot \"{:?}\" was not called end() on",
-                self.plot_title
-            );
+        let Some(policy) = self.drop_policy else {
+            return;
+        };
+        match policy {
+            TokenDropPolicy::Panic => {
+                if !std::thread::panicking() {
+                    panic!(
+                        "Warning: A PlotToken for plot \"{:?}\" was not called end() on",
+                        self.plot_title
+                    );
+                }
+            }
+            TokenDropPolicy::LogError => {
+                eprintln!(
+                    "Error: A PlotToken for plot \"{:?}\" was not called end() on, ending it now",
+                    self.plot_title
+                );
+                unsafe { sys::ImPlot_EndPlot() };
+            }
+            TokenDropPolicy::AutoEnd => unsafe { sys::ImPlot_EndPlot() },
+        }
+        // Unwinding again from a panic of an axis formatter would abort if already unwinding
+        if !std::thread::panicking() {
+            resume_callback_panic();
         }
     }
 }
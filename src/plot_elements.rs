@@ -6,21 +6,210 @@
 
 #![allow(clippy::bad_bit_mask)]
 
-use implot_sys::{ImPlotRange, ImVec2};
-
-use crate::{sys, Colormap, IMPLOT_AUTO, IMVEC2_ZERO};
+use imgui::TextureId;
+use implot_sys::{ImPlotRange, ImVec2, ImVec4};
+
+use crate::intern::intern;
+use crate::{
+    decimate_minmax, insert_nan_gaps, sys, Colormap, IntoPlotData, Marker, PlotScalar, StridedData,
+    IMPLOT_AUTO, IMPLOT_AUTO_COL, IMVEC2_ZERO,
+};
+use std::any::Any;
 use std::borrow::Cow;
-use std::ffi::CString;
-use std::os::raw::c_char;
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
 
 pub use crate::sys::ImPlotPoint;
 
+/// Number of values plotted out of `len` values when only every `stride`-th one is taken.
+fn strided_count(len: usize, stride: usize) -> usize {
+    if len == 0 {
+        0
+    } else {
+        (len - 1) / stride + 1
+    }
+}
+
+thread_local! {
+    /// Payload of a panic in a closure called by ImPlot, until it is resumed after the ImPlot
+    /// call returns.
+    static CALLBACK_PANIC: RefCell<Option<Box<dyn Any + Send>>> = RefCell::new(None);
+}
+
+/// Run a user closure from a callback called by ImPlot. Unwinding out of an `extern "C"`
+/// function aborts, so a panic is caught and stashed instead, and `fallback` is returned. Once a
+/// closure panicked, the following callbacks of the same ImPlot call return `fallback` as well.
+fn catch_callback_panic<R>(fallback: R, f: impl FnOnce() -> R) -> R {
+    if CALLBACK_PANIC.with(|panic| panic.borrow().is_some()) {
+        return fallback;
+    }
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => {
+            CALLBACK_PANIC.with(|panic| *panic.borrow_mut() = Some(payload));
+            fallback
+        }
+    }
+}
+
+/// Continue unwinding from a panic that a closure called by ImPlot stashed, if there was one.
+/// Call this once the ImPlot call that took the closure has returned.
+pub(crate) fn resume_callback_panic() {
+    if let Some(payload) = CALLBACK_PANIC.with(|panic| panic.borrow_mut().take()) {
+        panic::resume_unwind(payload);
+    }
+}
+
+/// Getter handed to ImPlot's getter-based plotting functions together with a pointer to the
+/// closure `F`, see e.g. [`PlotLine::plot_with`]. A panic in the closure is resumed by the
+/// caller with [`resume_callback_panic`].
+unsafe extern "C" fn call_getter<F: FnMut(usize) -> ImPlotPoint>(
+    data: *mut c_void,
+    index: c_int,
+    point: *mut ImPlotPoint,
+) -> *mut c_void {
+    let getter = &mut *(data as *mut F);
+    *point = catch_callback_panic(ImPlotPoint { x: 0.0, y: 0.0 }, || getter(index as usize));
+    data
+}
+
+/// Formatter handed to ImPlot together with a pointer to the closure `F`, see e.g.
+/// [`PlotPieChart::plot_with_formatter`]. Writes the string returned by the closure into
+/// ImPlot's buffer, truncated to fit if needed. A panic in the closure leaves the label empty
+/// and is resumed by the caller with [`resume_callback_panic`].
+pub(crate) unsafe extern "C" fn call_formatter<F: FnMut(f64) -> String>(
+    value: f64,
+    buffer: *mut c_char,
+    size: c_int,
+    data: *mut c_void,
+) -> c_int {
+    if size <= 0 {
+        return 0;
+    }
+    let formatter = &mut *(data as *mut F);
+    let text = catch_callback_panic(String::new(), || formatter(value));
+    // Leave room for the terminator, stop at internal null bytes and don't split characters
+    let mut length = text.find('\0').unwrap_or(text.len()).min(size as usize - 1);
+    while !text.is_char_boundary(length) {
+        length -= 1;
+    }
+    std::ptr::copy_nonoverlapping(text.as_ptr(), buffer as *mut u8, length);
+    *buffer.add(length) = 0;
+    length as c_int
+}
+
+/// Style of a single item, set with the `with_color` etc. methods of the elements. It is applied
+/// with ImPlot's `SetNext*Style` functions right before the item is plotted, so unlike pushing
+/// style colors and variables, it does not affect any other items.
+#[derive(Clone, Copy, Debug, Default)]
+struct ItemStyle {
+    color: Option<ImVec4>,
+    line_weight: Option<f32>,
+    fill_alpha: Option<f32>,
+    marker: Option<Marker>,
+    marker_size: Option<f32>,
+}
+
+impl ItemStyle {
+    fn apply(&self) {
+        unsafe {
+            if self.color.is_some() || self.line_weight.is_some() {
+                sys::ImPlot_SetNextLineStyle(
+                    self.color.unwrap_or(IMPLOT_AUTO_COL),
+                    self.line_weight.unwrap_or(IMPLOT_AUTO as f32),
+                );
+            }
+            // The fill takes the same color, like it does with automatic colors
+            if self.color.is_some() || self.fill_alpha.is_some() {
+                sys::ImPlot_SetNextFillStyle(
+                    self.color.unwrap_or(IMPLOT_AUTO_COL),
+                    self.fill_alpha.unwrap_or(IMPLOT_AUTO as f32),
+                );
+            }
+            if self.marker.is_some() || self.marker_size.is_some() {
+                sys::ImPlot_SetNextMarkerStyle(
+                    self.marker.map_or(IMPLOT_AUTO, |marker| marker.into()),
+                    self.marker_size.unwrap_or(IMPLOT_AUTO as f32),
+                    IMPLOT_AUTO_COL,
+                    IMPLOT_AUTO as f32,
+                    IMPLOT_AUTO_COL,
+                );
+            }
+        }
+    }
+}
+
+/// Implements the builder methods for the [`ItemStyle`] of the given elements.
+macro_rules! impl_item_style {
+    ($($element:ident),*) => {$(
+        impl $element {
+            /// Set the color of this item, instead of taking the next one from the colormap.
+            pub fn with_color(mut self, color: [f32; 4]) -> Self {
+                let [x, y, z, w] = color;
+                self.style.color = Some(ImVec4 { x, y, z, w });
+                self
+            }
+
+            /// Set the line weight of this item, in pixels.
+            pub fn with_line_weight(mut self, weight: f32) -> Self {
+                self.style.line_weight = Some(weight);
+                self
+            }
+
+            /// Set the factor the alpha of this item's fill color is multiplied with.
+            pub fn with_fill_alpha(mut self, alpha: f32) -> Self {
+                self.style.fill_alpha = Some(alpha);
+                self
+            }
+        }
+    )*};
+}
+
+/// Implements the builder methods for the markers of the given elements.
+macro_rules! impl_marker_style {
+    ($($element:ident),*) => {$(
+        impl $element {
+            /// Set the marker shown at the points of this item.
+            pub fn with_marker(mut self, marker: Marker) -> Self {
+                self.style.marker = Some(marker);
+                self
+            }
+
+            /// Set the size of this item's markers, in pixels.
+            pub fn with_marker_size(mut self, size: f32) -> Self {
+                self.style.marker_size = Some(size);
+                self
+            }
+        }
+    )*};
+}
+
+impl_item_style!(
+    PlotLine,
+    PlotStairs,
+    PlotScatter,
+    PlotDigital,
+    PlotBars,
+    PlotStems,
+    PlotShaded
+);
+impl_marker_style!(PlotLine, PlotScatter, PlotStems);
+
 // --- Actual plotting functionality -------------------------------------------------------------
 /// Struct to provide functionality for plotting a line in a plot.
 pub struct PlotLine {
     /// Label to show in the legend for this line
-    label: CString,
+    label: Arc<CStr>,
     flags: PlotLineFlags,
+    /// Only every `stride`-th value is plotted, see `with_stride`
+    stride: usize,
+    /// Index of the value to start plotting at, see `with_offset`
+    offset: usize,
+    /// Per-item style overrides, see `with_color` etc.
+    style: ItemStyle,
 }
 
 pub type PlotLineFlags = sys::ImPlotLineFlags_;
@@ -32,9 +221,11 @@ impl PlotLine {
     /// Will panic if the label string contains internal null bytes.
     pub fn new(label: &str) -> Self {
         Self {
-            label: CString::new(label)
-                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            label: intern(label),
+            style: ItemStyle::default(),
             flags: PlotLineFlags::NONE,
+            stride: 1,
+            offset: 0,
         }
     }
 
@@ -43,31 +234,310 @@ impl PlotLine {
         self
     }
 
+    /// Connect the points around NaN values, instead of leaving a gap in the line there, e.g.
+    /// when NaN marks a sample that is invalid rather than missing.
+    pub fn skip_nan(mut self) -> Self {
+        self.flags |= PlotLineFlags::SKIP_NAN;
+        self
+    }
+
+    /// Draw the points as separate segments, the first from point 0 to point 1, the next
+    /// from point 2 to point 3 and so on, instead of one connected line.
+    pub fn segments(mut self) -> Self {
+        self.flags |= PlotLineFlags::SEGMENTS;
+        self
+    }
+
+    /// Only plot every `stride`-th value of the data, e.g. to plot one channel of interleaved
+    /// samples such as `[x0, y0, x1, y1, ...]` by passing `&data[0..]` and `&data[1..]` with a
+    /// stride of 2. Defaults to 1. For plotting fields of a slice of structs, see [`StridedData`].
+    ///
+    /// # Panics
+    /// Will panic if `stride` is zero.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "PlotLine stride must be at least 1");
+        self.stride = stride;
+        self
+    }
+
+    /// Start plotting at the value with the given index and wrap around at the end of the data,
+    /// e.g. to plot a ring buffer in order by passing the index of its oldest value, without
+    /// rotating the buffer. Defaults to 0. The index counts values taken with the stride.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
     /// Plot a line. Use this in closures passed to [`Plot::build()`](struct.Plot.html#method.build)
-    pub fn plot(&self, x: &[f64], y: &[f64]) {
+    ///
+    /// The data can be of any [`PlotScalar`] type and is handed to ImPlot as it is, so e.g.
+    /// `f32` audio buffers don't have to be converted to `f64` every frame:
+    /// ```no_run
+    /// # use implot::PlotLine;
+    /// let samples: Vec<f32> = (0..512).map(|i| (i as f32 * 0.05).sin()).collect();
+    /// let times: Vec<f32> = (0..512).map(|i| i as f32 / 48_000.0).collect();
+    /// PlotLine::new("left channel").plot(&times, &samples);
+    /// ```
+    pub fn plot<T: PlotScalar>(&self, x: &[T], y: &[T]) {
+        let number_of_points = strided_count(x.len().min(y.len()), self.stride);
+        // If there is no data to plot, we stop here
+        if number_of_points == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_line(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y.as_ptr(),
+                number_of_points as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                self.flags.0 as sys::ImPlotLineFlags,
+                (self.offset % number_of_points) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Plot a line through uniformly sampled values, without an array of X values: the X value
+    /// of `values[i]` is `x_start + i * x_scale`. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    /// ```no_run
+    /// # use implot::PlotLine;
+    /// // Sampled at 1 kHz, starting at t = 2 s
+    /// let samples = vec![0.0f32, 0.5, 0.7, 0.4];
+    /// PlotLine::new("sensor").plot_values(&samples, 0.001, 2.0);
+    /// ```
+    #[rustversion::attr(since(1.48), doc(alias = "PlotLine"))]
+    pub fn plot_values<T: PlotScalar>(&self, values: &[T], x_scale: f64, x_start: f64) {
+        let number_of_points = strided_count(values.len(), self.stride);
+        // If there is no data to plot, we stop here
+        if number_of_points == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_line_values(
+                self.label.as_ptr() as *const c_char,
+                values.as_ptr(),
+                number_of_points as i32,
+                x_scale,
+                x_start,
+                self.flags.0 as sys::ImPlotLineFlags,
+                (self.offset % number_of_points) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Plot a line that is broken wherever consecutive X values are more than `max_step` apart,
+    /// e.g. where a sensor dropped out, see [`insert_nan_gaps`]. The stride and offset are not
+    /// applied, and [`PlotLine::skip_nan`] is ignored. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_with_gaps(&self, x: &[f64], y: &[f64], max_step: f64) {
+        let (x, y) = insert_nan_gaps(x, y, max_step);
+        let line = PlotLine {
+            label: Arc::clone(&self.label),
+            flags: self.flags & sys::ImPlotLineFlags_(!PlotLineFlags::SKIP_NAN.0),
+            stride: 1,
+            offset: 0,
+            style: self.style,
+        };
+        line.plot(&x, &y);
+    }
+
+    /// Plot a line through the visible part of a huge series, reduced to the minimum and maximum
+    /// of each pixel column with [`decimate_minmax`], so that plotting millions of points stays
+    /// interactive without losing spikes. The X values are expected to be sorted. Fitting the
+    /// axes still takes the whole series into account. The stride and offset are not applied.
+    /// Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_decimated(&self, x: &[f64], y: &[f64]) {
+        let len = x.len().min(y.len());
+        let mut limits = sys::ImPlotRect::new([0.0, 0.0], [0.0, 0.0]);
+        let mut plot_size = ImVec2 { x: 0.0, y: 0.0 };
+        unsafe {
+            sys::ImPlot_GetPlotLimits(&mut limits, IMPLOT_AUTO, IMPLOT_AUTO);
+            sys::ImPlot_GetPlotSize(&mut plot_size);
+            // Only the visible part is submitted, so fitting has to cover the whole series
+            if len > 0 && sys::ImPlot_FitThisFrame() {
+                let (min, max) = y[..len]
+                    .iter()
+                    .filter(|value| value.is_finite())
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                        (min.min(*value), max.max(*value))
+                    });
+                if min <= max {
+                    sys::ImPlot_FitPoint(ImPlotPoint { x: x[0], y: min });
+                    sys::ImPlot_FitPoint(ImPlotPoint {
+                        x: x[len - 1],
+                        y: max,
+                    });
+                }
+            }
+        }
+        // One more point on each side, so the line continues to the plot edges
+        let start = x[..len]
+            .partition_point(|value| *value < limits.X.Min)
+            .saturating_sub(1);
+        let end = (x[..len].partition_point(|value| *value <= limits.X.Max) + 1).min(len);
+        if start >= end {
+            return;
+        }
+        let (x, y) = decimate_minmax(
+            &x[start..end],
+            &y[start..end],
+            plot_size.x.max(1.0) as usize,
+        );
+        let line = PlotLine {
+            label: Arc::clone(&self.label),
+            flags: self.flags,
+            stride: 1,
+            offset: 0,
+            style: self.style,
+        };
+        line.plot(&x, &y);
+    }
+
+    /// Plot a line through `count` points computed by `getter` from their index, for data that
+    /// is not stored in slices, e.g. in a map or computed on the fly. Use this in closures
+    /// passed to [`Plot::build()`](struct.Plot.html#method.build)
+    #[rustversion::attr(since(1.48), doc(alias = "PlotLineG"))]
+    pub fn plot_with<F: FnMut(usize) -> ImPlotPoint>(&self, count: usize, mut getter: F) {
+        if count == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotLineG(
+                self.label.as_ptr() as *const c_char,
+                Some(call_getter::<F>),
+                &mut getter as *mut F as *mut c_void,
+                count as i32,
+                self.flags.0 as sys::ImPlotLineFlags,
+            );
+        }
+        resume_callback_panic();
+    }
+
+    /// Plot strided data, such as fields of a slice of structs (see [`StridedData`]). Use this
+    /// in closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    ///
+    /// # Panics
+    /// Will panic if `x` and `y` have different strides, ImPlot only supports one stride.
+    pub fn plot_strided(&self, x: StridedData, y: StridedData) {
+        let count = x.len().min(y.len());
         // If there is no data to plot, we stop here
-        if x.len().min(y.len()) == 0 {
+        if count == 0 {
             return;
         }
+        assert_eq!(
+            x.stride(),
+            y.stride(),
+            "X and Y data must have the same stride"
+        );
+        self.style.apply();
         unsafe {
             sys::ImPlot_PlotLine_doublePtrdoublePtr(
                 self.label.as_ptr() as *const c_char,
                 x.as_ptr(),
                 y.as_ptr(),
-                x.len().min(y.len()) as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                count as i32,
                 self.flags.0 as sys::ImPlotLineFlags,
-                0,                                 // No offset
-                std::mem::size_of::<f64>() as i32, // Stride, set to one f64 for the standard use case
+                (self.offset % count) as i32,
+                x.stride() as i32,
             );
         }
     }
+
+    /// Plot a line through interleaved XY data, such as `[[f64; 2]]` or `[(f64, f64)]` (see
+    /// [`IntoPlotData`]). Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_xy<D: IntoPlotData + ?Sized>(&self, data: &D) {
+        let (x, y) = data.plot_data();
+        self.plot_strided(x, y);
+    }
+}
+
+/// Struct to provide functionality for plotting the rows or columns of a matrix as lines, e.g.
+/// multi-channel telemetry stored as one contiguous block. All lines share the X values:
+/// ```no_run
+/// # use implot::PlotLines;
+/// let time = [0.0, 1.0, 2.0];
+/// // One row per channel
+/// let channels = [0.1, 0.2, 0.3, 5.0, 4.0, 3.0];
+/// PlotLines::new(&["pressure", "flow"]).plot_matrix(&time, &channels, true);
+/// ```
+pub struct PlotLines {
+    /// Labels of the lines, shown in the legend
+    labels: Vec<Arc<CStr>>,
+    flags: PlotLineFlags,
+}
+
+impl PlotLines {
+    /// Create new lines with the given labels, one per row or column of the matrix that is
+    /// plotted. Does not draw anything yet.
+    ///
+    /// # Panics
+    /// Will panic if any of the labels contain internal null bytes.
+    pub fn new<S: AsRef<str>>(labels: &[S]) -> Self {
+        Self {
+            labels: labels.iter().map(|label| intern(label.as_ref())).collect(),
+            flags: PlotLineFlags::NONE,
+        }
+    }
+
+    pub fn with_flags(mut self, flags: PlotLineFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Plot one line per label. With `row_major`, each line is a row of `values`, otherwise
+    /// each line is a column, i.e. the values of the lines are interleaved. Each line has
+    /// `values.len()` divided by the number of labels points, or as many as there are X values
+    /// if there are fewer. Neither layout is copied. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_matrix<T: PlotScalar>(&self, xs: &[T], values: &[T], row_major: bool) {
+        let line_count = self.labels.len();
+        if line_count == 0 {
+            return;
+        }
+        let points_per_line = values.len() / line_count;
+
+        for (line, label) in self.labels.iter().enumerate() {
+            let plot_line = PlotLine {
+                label: Arc::clone(label),
+                flags: self.flags,
+                stride: 1,
+                offset: 0,
+                style: ItemStyle::default(),
+            };
+            if row_major {
+                let start = line * points_per_line;
+                plot_line.plot(xs, &values[start..start + points_per_line]);
+            } else {
+                // ImPlot takes a single stride for X and Y, so the interleaved Y values are read
+                // through a getter instead
+                let count = xs.len().min(points_per_line);
+                plot_line.plot_with(count, |index| ImPlotPoint {
+                    x: xs[index].to_f64(),
+                    y: values[index * line_count + line].to_f64(),
+                });
+            }
+        }
+    }
 }
 
 /// Struct to provide functionality for plotting a line in a plot with stairs style.
 pub struct PlotStairs {
     /// Label to show in the legend for this line
-    label: CString,
+    label: Arc<CStr>,
     flags: PlotStairsFlags,
+    /// Only every `stride`-th value is plotted, see `with_stride`
+    stride: usize,
+    /// Index of the value to start plotting at, see `with_offset`
+    offset: usize,
+    /// Per-item style overrides, see `with_color` etc.
+    style: ItemStyle,
 }
 
 pub type PlotStairsFlags = sys::ImPlotStairsFlags_;
@@ -79,9 +549,11 @@ impl PlotStairs {
     /// Will panic if the label string contains internal null bytes.
     pub fn new(label: &str) -> Self {
         Self {
-            label: CString::new(label)
-                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            label: intern(label),
+            style: ItemStyle::default(),
             flags: PlotStairsFlags::NONE,
+            stride: 1,
+            offset: 0,
         }
     }
 
@@ -90,25 +562,124 @@ impl PlotStairs {
         self
     }
 
+    /// Only plot every `stride`-th value of the data, see [`PlotLine::with_stride`].
+    ///
+    /// # Panics
+    /// Will panic if `stride` is zero.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "PlotStairs stride must be at least 1");
+        self.stride = stride;
+        self
+    }
+
+    /// Start plotting at the value with the given index, see [`PlotLine::with_offset`].
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
     /// Plot a stairs style line. Use this in closures passed to
     /// [`Plot::build()`](struct.Plot.html#method.build)
-    pub fn plot(&self, x: &[f64], y: &[f64]) {
+    pub fn plot<T: PlotScalar>(&self, x: &[T], y: &[T]) {
+        let number_of_points = strided_count(x.len().min(y.len()), self.stride);
         // If there is no data to plot, we stop here
-        if x.len().min(y.len()) == 0 {
+        if number_of_points == 0 {
             return;
         }
+        self.style.apply();
+        unsafe {
+            T::plot_stairs(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y.as_ptr(),
+                number_of_points as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                self.flags.0 as sys::ImPlotStairsFlags,
+                (self.offset % number_of_points) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Plot a stairs style line through uniformly sampled values, see [`PlotLine::plot_values`].
+    #[rustversion::attr(since(1.48), doc(alias = "PlotStairs"))]
+    pub fn plot_values<T: PlotScalar>(&self, values: &[T], x_scale: f64, x_start: f64) {
+        let number_of_points = strided_count(values.len(), self.stride);
+        // If there is no data to plot, we stop here
+        if number_of_points == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_stairs_values(
+                self.label.as_ptr() as *const c_char,
+                values.as_ptr(),
+                number_of_points as i32,
+                x_scale,
+                x_start,
+                self.flags.0 as sys::ImPlotStairsFlags,
+                (self.offset % number_of_points) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Plot a stairs style line through `count` points computed by `getter` from their index,
+    /// see [`PlotLine::plot_with`].
+    #[rustversion::attr(since(1.48), doc(alias = "PlotStairsG"))]
+    pub fn plot_with<F: FnMut(usize) -> ImPlotPoint>(&self, count: usize, mut getter: F) {
+        if count == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotStairsG(
+                self.label.as_ptr() as *const c_char,
+                Some(call_getter::<F>),
+                &mut getter as *mut F as *mut c_void,
+                count as i32,
+                self.flags.0 as sys::ImPlotStairsFlags,
+            );
+        }
+        resume_callback_panic();
+    }
+
+    /// Plot strided data, such as fields of a slice of structs (see [`StridedData`]). Use this
+    /// in closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    ///
+    /// # Panics
+    /// Will panic if `x` and `y` have different strides, ImPlot only supports one stride.
+    pub fn plot_strided(&self, x: StridedData, y: StridedData) {
+        let count = x.len().min(y.len());
+        // If there is no data to plot, we stop here
+        if count == 0 {
+            return;
+        }
+        assert_eq!(
+            x.stride(),
+            y.stride(),
+            "X and Y data must have the same stride"
+        );
+        self.style.apply();
         unsafe {
             sys::ImPlot_PlotStairs_doublePtrdoublePtr(
                 self.label.as_ptr() as *const c_char,
                 x.as_ptr(),
                 y.as_ptr(),
-                x.len().min(y.len()) as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                count as i32,
                 self.flags.0 as sys::ImPlotStairsFlags,
-                0,                                 // No offset
-                std::mem::size_of::<f64>() as i32, // Stride, set to one f64 for the standard use case
+                (self.offset % count) as i32,
+                x.stride() as i32,
             );
         }
     }
+
+    /// Plot stairs through interleaved XY data, such as `[[f64; 2]]` or `[(f64, f64)]` (see
+    /// [`IntoPlotData`]). Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_xy<D: IntoPlotData + ?Sized>(&self, data: &D) {
+        let (x, y) = data.plot_data();
+        self.plot_strided(x, y);
+    }
 }
 
 /// Struct to provide functionality for creating a scatter plot
@@ -117,8 +688,14 @@ pub struct PlotScatter {
     ///
     /// # Panics
     /// Will panic if the label string contains internal null bytes.
-    label: CString,
+    label: Arc<CStr>,
     flags: PlotScatterFlags,
+    /// Only every `stride`-th value is plotted, see `with_stride`
+    stride: usize,
+    /// Index of the value to start plotting at, see `with_offset`
+    offset: usize,
+    /// Per-item style overrides, see `with_color` etc.
+    style: ItemStyle,
 }
 
 pub type PlotScatterFlags = sys::ImPlotScatterFlags_;
@@ -127,9 +704,11 @@ impl PlotScatter {
     /// Create a new scatter plot to be shown. Does not draw anything yet.
     pub fn new(label: &str) -> Self {
         Self {
-            label: CString::new(label)
-                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            label: intern(label),
+            style: ItemStyle::default(),
             flags: PlotScatterFlags::NONE,
+            stride: 1,
+            offset: 0,
         }
     }
 
@@ -138,37 +717,220 @@ impl PlotScatter {
         self
     }
 
+    /// Only plot every `stride`-th value of the data, see [`PlotLine::with_stride`].
+    ///
+    /// # Panics
+    /// Will panic if `stride` is zero.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "PlotScatter stride must be at least 1");
+        self.stride = stride;
+        self
+    }
+
+    /// Start plotting at the value with the given index, see [`PlotLine::with_offset`].
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
     /// Draw a previously-created scatter plot. Use this in closures passed to
-    /// [`Plot::build()`](struct.Plot.html#method.build)
-    pub fn plot(&self, x: &[f64], y: &[f64]) {
+    /// [`Plot::build()`](struct.Plot.html#method.build). Like for [`PlotLine::plot`], `f32` and
+    /// integer data is plotted without conversion.
+    pub fn plot<T: PlotScalar>(&self, x: &[T], y: &[T]) {
+        let number_of_points = strided_count(x.len().min(y.len()), self.stride);
+        // If there is no data to plot, we stop here
+        if number_of_points == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_scatter(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y.as_ptr(),
+                number_of_points as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                self.flags.0 as sys::ImPlotScatterFlags,
+                (self.offset % number_of_points) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Draw a scatter plot of uniformly sampled values, see [`PlotLine::plot_values`].
+    #[rustversion::attr(since(1.48), doc(alias = "PlotScatter"))]
+    pub fn plot_values<T: PlotScalar>(&self, values: &[T], x_scale: f64, x_start: f64) {
+        let number_of_points = strided_count(values.len(), self.stride);
+        // If there is no data to plot, we stop here
+        if number_of_points == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_scatter_values(
+                self.label.as_ptr() as *const c_char,
+                values.as_ptr(),
+                number_of_points as i32,
+                x_scale,
+                x_start,
+                self.flags.0 as sys::ImPlotScatterFlags,
+                (self.offset % number_of_points) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Draw a scatter plot of `count` points computed by `getter` from their index, see
+    /// [`PlotLine::plot_with`].
+    #[rustversion::attr(since(1.48), doc(alias = "PlotScatterG"))]
+    pub fn plot_with<F: FnMut(usize) -> ImPlotPoint>(&self, count: usize, mut getter: F) {
+        if count == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotScatterG(
+                self.label.as_ptr() as *const c_char,
+                Some(call_getter::<F>),
+                &mut getter as *mut F as *mut c_void,
+                count as i32,
+                self.flags.0 as sys::ImPlotScatterFlags,
+            );
+        }
+        resume_callback_panic();
+    }
+
+    /// Plot strided data, such as fields of a slice of structs (see [`StridedData`]). Use this
+    /// in closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    ///
+    /// # Panics
+    /// Will panic if `x` and `y` have different strides, ImPlot only supports one stride.
+    pub fn plot_strided(&self, x: StridedData, y: StridedData) {
+        let count = x.len().min(y.len());
         // If there is no data to plot, we stop here
-        if x.len().min(y.len()) == 0 {
+        if count == 0 {
             return;
         }
+        assert_eq!(
+            x.stride(),
+            y.stride(),
+            "X and Y data must have the same stride"
+        );
+        self.style.apply();
         unsafe {
             sys::ImPlot_PlotScatter_doublePtrdoublePtr(
                 self.label.as_ptr() as *const c_char,
                 x.as_ptr(),
                 y.as_ptr(),
-                x.len().min(y.len()) as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
+                count as i32,
                 self.flags.0 as sys::ImPlotScatterFlags,
-                0,                                 // No offset
-                std::mem::size_of::<f64>() as i32, // Stride, set to one f64 for the standard use case
+                (self.offset % count) as i32,
+                x.stride() as i32,
             );
         }
     }
+
+    /// Plot points of interleaved XY data, such as `[[f64; 2]]` or `[(f64, f64)]` (see
+    /// [`IntoPlotData`]). Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot_xy<D: IntoPlotData + ?Sized>(&self, data: &D) {
+        let (x, y) = data.plot_data();
+        self.plot_strided(x, y);
+    }
+}
+
+/// Struct to provide functionality for digital plots, e.g. of logic signals. Digital plots are
+/// drawn in pixels stacked at the bottom of the plot instead of in plot coordinates, and do not
+/// respond to scaling the Y axis.
+pub struct PlotDigital {
+    /// Label to show in the legend for this line
+    label: Arc<CStr>,
+    flags: PlotDigitalFlags,
+    /// Per-item style overrides, see `with_color` etc.
+    style: ItemStyle,
+}
+
+pub type PlotDigitalFlags = sys::ImPlotDigitalFlags_;
+
+impl PlotDigital {
+    /// Create a new digital plot to be shown. Does not draw anything by itself, call
+    /// [`PlotDigital::plot`] on the struct for that.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: intern(label),
+            style: ItemStyle::default(),
+            flags: PlotDigitalFlags::NONE,
+        }
+    }
+
+    pub fn with_flags(mut self, flags: PlotDigitalFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Draw the digital plot. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    #[rustversion::attr(since(1.48), doc(alias = "PlotDigital"))]
+    pub fn plot<T: PlotScalar>(&self, x: &[T], y: &[T]) {
+        let number_of_points = x.len().min(y.len());
+        // If there is no data to plot, we stop here
+        if number_of_points == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_digital(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y.as_ptr(),
+                number_of_points as i32,
+                self.flags.0 as sys::ImPlotDigitalFlags,
+                0,
+                std::mem::size_of::<T>() as i32,
+            );
+        }
+    }
+
+    /// Draw a digital plot of `count` points computed by `getter` from their index, see
+    /// [`PlotLine::plot_with`].
+    #[rustversion::attr(since(1.48), doc(alias = "PlotDigitalG"))]
+    pub fn plot_with<F: FnMut(usize) -> ImPlotPoint>(&self, count: usize, mut getter: F) {
+        if count == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotDigitalG(
+                self.label.as_ptr() as *const c_char,
+                Some(call_getter::<F>),
+                &mut getter as *mut F as *mut c_void,
+                count as i32,
+                self.flags.0 as sys::ImPlotDigitalFlags,
+            );
+        }
+        resume_callback_panic();
+    }
 }
 
 /// Struct to provide bar plotting functionality.
 pub struct PlotBars {
     /// Label to show in the legend for this line
-    label: CString,
+    label: Arc<CStr>,
 
     /// Width of the bars, in plot coordinate terms
     bar_width: f64,
+    flags: PlotBarsFlags,
+    /// Only every `stride`-th value is plotted, see `with_stride`
+    stride: usize,
+    /// Index of the value to start plotting at, see `with_offset`
+    offset: usize,
+    /// Per-item style overrides, see `with_color` etc.
+    style: ItemStyle,
 }
 
-pub type PlotBarsFlags = sys::ImPlotBarGroupsFlags_;
+pub type PlotBarsFlags = sys::ImPlotBarsFlags_;
 
 impl PlotBars {
     /// Create a new bar plot to be shown. Defaults to drawing vertical bars.
@@ -178,9 +940,12 @@ impl PlotBars {
     /// Will panic if the label string contains internal null bytes.
     pub fn new(label: &str) -> Self {
         Self {
-            label: CString::new(label)
-                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            label: intern(label),
+            style: ItemStyle::default(),
             bar_width: 0.67, // Default value taken from C++ implot
+            flags: PlotBarsFlags::NONE,
+            stride: 1,
+            offset: 0,
         }
     }
 
@@ -190,33 +955,197 @@ impl PlotBars {
         self
     }
 
+    /// Set the flags of the bars. The `horizontal` argument of the plot functions is combined
+    /// with these, so it does not have to be repeated here.
+    pub fn with_flags(mut self, flags: PlotBarsFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// The flags to plot with, including the `HORIZONTAL` flag if requested.
+    fn flags_for(&self, horizontal: bool) -> PlotBarsFlags {
+        if horizontal {
+            self.flags | PlotBarsFlags::HORIZONTAL
+        } else {
+            self.flags
+        }
+    }
+
+    /// Only plot every `stride`-th value of the data, see [`PlotLine::with_stride`].
+    ///
+    /// # Panics
+    /// Will panic if `stride` is zero.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "PlotBars stride must be at least 1");
+        self.stride = stride;
+        self
+    }
+
+    /// Start plotting at the value with the given index, see [`PlotLine::with_offset`].
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
     /// Draw a previously-created bar plot. Use this in closures passed to
     /// [`Plot::build()`](struct.Plot.html#method.build). The `axis_positions`
     /// specify where on the corresponding axis (X for vertical mode, Y for horizontal mode) the
     /// bar is drawn, and the `bar_values` specify what values the bars have.
-    pub fn plot(&self, axis_positions: &[f64], bar_values: &[f64], horizontal: bool) {
-        let number_of_points = axis_positions.len().min(bar_values.len());
+    pub fn plot<T: PlotScalar>(&self, axis_positions: &[T], bar_values: &[T], horizontal: bool) {
+        let number_of_points =
+            strided_count(axis_positions.len().min(bar_values.len()), self.stride);
         // If there is no data to plot, we stop here
         if number_of_points == 0 {
             return;
         }
 
-        let flags = if horizontal {
-            PlotBarsFlags::HORIZONTAL
-        } else {
-            PlotBarsFlags::NONE
-        };
-
+        let flags = self.flags_for(horizontal);
+        self.style.apply();
         unsafe {
-            sys::ImPlot_PlotBars_doublePtrdoublePtr(
+            T::plot_bars(
                 self.label.as_ptr() as *const c_char,
                 axis_positions.as_ptr(),
                 bar_values.as_ptr(),
                 number_of_points as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
                 self.bar_width,
                 flags.0 as sys::ImPlotBarsFlags,
-                0,                                 // No offset
-                std::mem::size_of::<f64>() as i32, // Stride, set to one f64 for the standard use case
+                (self.offset % number_of_points) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Draw one bar per value, without an array of axis positions: the bar of `values[i]` is
+    /// drawn at `x_start + i`. Unlike for lines, ImPlot has no scale for the positions of bars,
+    /// so they are always one unit apart; with X values in other units, use
+    /// [`PlotBars::plot`] instead.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotBars"))]
+    pub fn plot_values<T: PlotScalar>(&self, values: &[T], x_start: f64, horizontal: bool) {
+        let number_of_points = strided_count(values.len(), self.stride);
+        // If there is no data to plot, we stop here
+        if number_of_points == 0 {
+            return;
+        }
+
+        let flags = self.flags_for(horizontal);
+        self.style.apply();
+        unsafe {
+            T::plot_bars_values(
+                self.label.as_ptr() as *const c_char,
+                values.as_ptr(),
+                number_of_points as i32,
+                self.bar_width,
+                x_start,
+                flags.0 as sys::ImPlotBarsFlags,
+                (self.offset % number_of_points) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Draw `count` bars computed by `getter` from their index, see [`PlotLine::plot_with`].
+    /// The X coordinate of the points is the axis position (Y for horizontal bars), the Y
+    /// coordinate the bar value.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotBarsG"))]
+    pub fn plot_with<F: FnMut(usize) -> ImPlotPoint>(
+        &self,
+        count: usize,
+        horizontal: bool,
+        mut getter: F,
+    ) {
+        if count == 0 {
+            return;
+        }
+        let flags = self.flags_for(horizontal);
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotBarsG(
+                self.label.as_ptr() as *const c_char,
+                Some(call_getter::<F>),
+                &mut getter as *mut F as *mut c_void,
+                count as i32,
+                self.bar_width,
+                flags.0 as sys::ImPlotBarsFlags,
+            );
+        }
+        resume_callback_panic();
+    }
+}
+
+/// Struct to provide functionality for grouped bar charts: for each group (e.g. a year), one bar
+/// per item (e.g. a product) is drawn next to the others, or stacked on top of them with the
+/// `STACKED` flag.
+pub struct PlotBarGroups {
+    /// Labels of the items, shown in the legend
+    item_labels: Vec<Arc<CStr>>,
+    group_size: f64,
+    shift: f64,
+    flags: PlotBarGroupsFlags,
+}
+
+pub type PlotBarGroupsFlags = sys::ImPlotBarGroupsFlags_;
+
+impl PlotBarGroups {
+    /// Create new bar groups with the given item labels. Does not draw anything by itself,
+    /// call [`PlotBarGroups::plot`] on the struct for that.
+    ///
+    /// # Panics
+    /// Will panic if any of the labels contain internal null bytes.
+    pub fn new<S: AsRef<str>>(item_labels: &[S]) -> Self {
+        Self {
+            item_labels: intern_labels(item_labels),
+            group_size: 0.67,
+            shift: 0.0,
+            flags: PlotBarGroupsFlags::NONE,
+        }
+    }
+
+    /// Set the width of each group, in plot coordinate terms. Groups are placed at 0, 1, 2, ...
+    pub fn with_group_size(mut self, group_size: f64) -> Self {
+        self.group_size = group_size;
+        self
+    }
+
+    /// Shift all groups along the axis they are placed on, in plot coordinate terms.
+    pub fn with_shift(mut self, shift: f64) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    pub fn with_flags(mut self, flags: PlotBarGroupsFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Draw the bar groups. `values` is a row-major matrix with one row per item and one
+    /// column per group, so it has to contain `item_labels.len() * group_count` values.
+    ///
+    /// # Panics
+    /// Will panic if `values` is too short for the number of items and groups.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotBarGroups"))]
+    pub fn plot<T: PlotScalar>(&self, values: &[T], group_count: usize) {
+        let item_count = self.item_labels.len();
+        if item_count == 0 || group_count == 0 {
+            return;
+        }
+        assert!(
+            values.len() >= item_count * group_count,
+            "Bar groups need {} values for {} items and {} groups, got {}",
+            item_count * group_count,
+            item_count,
+            group_count,
+            values.len()
+        );
+        let labels: Vec<_> = self.item_labels.iter().map(|s| s.as_ptr()).collect();
+        unsafe {
+            T::plot_bar_groups(
+                labels.as_ptr(),
+                values.as_ptr(),
+                item_count as i32,
+                group_count as i32,
+                self.group_size,
+                self.shift,
+                self.flags.0 as sys::ImPlotBarGroupsFlags,
             );
         }
     }
@@ -225,7 +1154,7 @@ impl PlotBars {
 /// Struct to provide functionality for adding text within a plot
 pub struct PlotText {
     /// Label to show in plot
-    label: CString,
+    label: Arc<CStr>,
 
     /// X component of the pixel offset to be used. Will be used independently of the actual plot
     /// scaling. Defaults to 0.
@@ -245,8 +1174,7 @@ impl PlotText {
     /// Will panic if the label string contains internal null bytes.
     pub fn new(label: &str) -> Self {
         Self {
-            label: CString::new(label)
-                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            label: intern(label),
             pixel_offset_x: 0.0,
             pixel_offset_y: 0.0,
         }
@@ -264,37 +1192,103 @@ impl PlotText {
     /// closures passed to [`Plot::build()`](struct.Plot.html#method.build)
     pub fn plot(&self, x: f64, y: f64, vertical: bool) {
         // If there is nothing to show, don't do anything
-        if self.label.as_bytes().is_empty() {
+        if self.label.to_bytes().is_empty() {
             return;
         }
 
-        let flags = if vertical {
-            PlotTextFlags::VERTICAL
-        } else {
-            PlotTextFlags::NONE
+        let flags = if vertical {
+            PlotTextFlags::VERTICAL
+        } else {
+            PlotTextFlags::NONE
+        };
+
+        unsafe {
+            sys::ImPlot_PlotText(
+                self.label.as_ptr() as *const c_char,
+                x,
+                y,
+                sys::ImVec2 {
+                    x: self.pixel_offset_x,
+                    y: self.pixel_offset_y,
+                },
+                flags.0 as sys::ImPlotFlags,
+            );
+        }
+    }
+}
+
+pub type PlotHeatmapFlags = sys::ImPlotHeatmapFlags_;
+
+/// Struct to provide functionality for annotations: text in a callout box at a point in plot
+/// coordinates, optionally offset by some pixels.
+pub struct PlotAnnotation {
+    /// Text of the annotation
+    text: Arc<CStr>,
+    color: ImVec4,
+    pixel_offset: ImVec2,
+    clamp: bool,
+}
+
+impl PlotAnnotation {
+    /// Create a new annotation with the given text. Does not draw anything by itself, call
+    /// [`PlotAnnotation::place`] on the struct for that.
+    ///
+    /// # Panics
+    /// Will panic if the text contains internal null bytes.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: intern(text),
+            color: IMPLOT_AUTO_COL,
+            pixel_offset: IMVEC2_ZERO,
+            clamp: false,
+        }
+    }
+
+    /// Set the background color of the annotation. By default, the color of the previous item
+    /// is used.
+    pub fn with_color(mut self, color: [f32; 4]) -> Self {
+        let [x, y, z, w] = color;
+        self.color = ImVec4 { x, y, z, w };
+        self
+    }
+
+    /// Offset the annotation from its point by the given amount of pixels.
+    pub fn with_offset(mut self, offset_x: f32, offset_y: f32) -> Self {
+        self.pixel_offset = ImVec2 {
+            x: offset_x,
+            y: offset_y,
         };
+        self
+    }
+
+    /// Keep the annotation within the plot area, even if the point is outside of it.
+    pub fn with_clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
 
+    /// Place the annotation at the given point in plot coordinates.
+    #[rustversion::attr(since(1.48), doc(alias = "Annotation"))]
+    pub fn place(&self, x: f64, y: f64) {
         unsafe {
-            sys::ImPlot_PlotText(
-                self.label.as_ptr() as *const c_char,
+            // The text is passed as an argument so that any % in it is not interpreted
+            sys::ImPlot_Annotation_Str(
                 x,
                 y,
-                sys::ImVec2 {
-                    x: self.pixel_offset_x,
-                    y: self.pixel_offset_y,
-                },
-                flags.0 as sys::ImPlotFlags,
+                self.color,
+                self.pixel_offset,
+                self.clamp,
+                b"%s\0".as_ptr() as *const c_char,
+                self.text.as_ptr(),
             );
         }
     }
 }
 
-pub type PlotHeatmapFlags = sys::ImPlotHeatmapFlags_;
-
 /// Struct to provide functionality for creating headmaps.
 pub struct PlotHeatmap {
     /// Label to show in plot
-    label: CString,
+    label: Arc<CStr>,
 
     /// Scale range of the values shown. If this is set to `None`, the scale
     /// is computed based on the values given to the `plot` function. If there
@@ -311,6 +1305,10 @@ pub struct PlotHeatmap {
 
     /// Upper right point for the bounding rectangle. This is called `bounds_max` in the C++ code.
     drawarea_upper_right: ImPlotPoint,
+
+    /// Whether the drawing area is taken from the current axis limits at plot time, see
+    /// `with_drawing_area_from_axes`.
+    drawarea_from_axes: bool,
 }
 
 impl PlotHeatmap {
@@ -320,12 +1318,12 @@ impl PlotHeatmap {
     /// anything yet.
     pub fn new(label: &str) -> Self {
         Self {
-            label: CString::new(label)
-                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            label: intern(label),
             scale_range: None,
             label_format: Some(CString::new("%.1f").unwrap()),
             drawarea_lower_left: ImPlotPoint { x: 0.0, y: 0.0 },
             drawarea_upper_right: ImPlotPoint { x: 1.0, y: 1.0 },
+            drawarea_from_axes: false,
         }
     }
 
@@ -359,19 +1357,70 @@ impl PlotHeatmap {
     pub fn with_drawing_area(mut self, lower_left: ImPlotPoint, upper_right: ImPlotPoint) -> Self {
         self.drawarea_lower_left = lower_left;
         self.drawarea_upper_right = upper_right;
+        self.drawarea_from_axes = false;
+        self
+    }
+
+    /// Fill the visible region of the plot, i.e. use the limits of the current axes as the
+    /// drawing area, as found when plotting. Since the heatmap then follows the axes, axes that
+    /// are fit to the data are not changed by it.
+    pub fn with_drawing_area_from_axes(mut self) -> Self {
+        self.drawarea_from_axes = true;
         self
     }
 
+    /// The drawing area to plot with, as lower left and upper right point.
+    fn drawing_area(&self) -> (ImPlotPoint, ImPlotPoint) {
+        if !self.drawarea_from_axes {
+            return (self.drawarea_lower_left, self.drawarea_upper_right);
+        }
+        let mut limits = sys::ImPlotRect::new([0.0, 0.0], [0.0, 0.0]);
+        unsafe {
+            sys::ImPlot_GetPlotLimits(&mut limits, IMPLOT_AUTO, IMPLOT_AUTO);
+        }
+        (
+            ImPlotPoint {
+                x: limits.X.Min,
+                y: limits.Y.Min,
+            },
+            ImPlotPoint {
+                x: limits.X.Max,
+                y: limits.Y.Max,
+            },
+        )
+    }
+
     /// Plot the heatmap, with the given values (assumed to be in row-major order),
     /// number of rows and number of columns.
-    pub fn plot(&self, values: &[f64], number_of_rows: u32, number_of_cols: u32, col_major: bool) {
+    ///
+    /// Integer and `f32` matrices are plotted as they are, e.g. raw sensor images or occupancy
+    /// grids. Without a scale range set, the range of the values is used:
+    /// ```no_run
+    /// # use implot::PlotHeatmap;
+    /// let occupancy: Vec<u8> = vec![0, 0, 255, 0, 128, 255];
+    /// PlotHeatmap::new("occupancy")
+    ///     .with_scale(0.0, 255.0)
+    ///     .plot(&occupancy, 2, 3, false);
+    /// ```
+    pub fn plot<T: PlotScalar>(
+        &self,
+        values: &[T],
+        number_of_rows: u32,
+        number_of_cols: u32,
+        col_major: bool,
+    ) {
+        // If there is no data to plot, we stop here
+        if values.is_empty() {
+            return;
+        }
+
         // If no range was given, determine that range
         let scale_range = self.scale_range.unwrap_or_else(|| {
-            let mut min_seen = values[0];
-            let mut max_seen = values[0];
+            let mut min_seen = values[0].to_f64();
+            let mut max_seen = values[0].to_f64();
             values.iter().for_each(|value| {
-                min_seen = min_seen.min(*value);
-                max_seen = max_seen.max(*value);
+                min_seen = min_seen.min(value.to_f64());
+                max_seen = max_seen.max(value.to_f64());
             });
             (min_seen, max_seen)
         });
@@ -381,9 +1430,10 @@ impl PlotHeatmap {
         } else {
             PlotHeatmapFlags::NONE
         };
+        let (lower_left, upper_right) = self.drawing_area();
 
         unsafe {
-            sys::ImPlot_PlotHeatmap_doublePtr(
+            T::plot_heatmap(
                 self.label.as_ptr() as *const c_char,
                 values.as_ptr(),
                 number_of_rows as i32, // Not sure why C++ code uses a signed value here
@@ -397,21 +1447,116 @@ impl PlotHeatmap {
                 } else {
                     std::ptr::null()
                 },
-                self.drawarea_lower_left,
-                self.drawarea_upper_right,
+                lower_left,
+                upper_right,
                 flags.0 as sys::ImPlotHeatmapFlags,
             );
         }
     }
+
+    /// Plot the heatmap from a slice of rows, e.g. `&[Vec<f64>]` or `&[&[u8]]`. The rows are
+    /// copied into one buffer for ImPlot, so for large heatmaps that are plotted every frame,
+    /// storing the values in one buffer and using [`PlotHeatmap::plot`] is faster.
+    ///
+    /// # Panics
+    /// Will panic if the rows don't all have the same length.
+    pub fn plot_rows<T: PlotScalar, R: AsRef<[T]>>(&self, rows: &[R]) {
+        let number_of_cols = rows.first().map_or(0, |row| row.as_ref().len());
+        assert!(
+            rows.iter().all(|row| row.as_ref().len() == number_of_cols),
+            "Heatmap rows must all have the same length"
+        );
+        let values: Vec<T> = rows
+            .iter()
+            .flat_map(|row| row.as_ref().iter().copied())
+            .collect();
+        self.plot(&values, rows.len() as u32, number_of_cols as u32, false);
+    }
+}
+
+/// Struct to provide functionality for drawing an imgui texture in plot coordinates, e.g. a
+/// camera frame or a spectrogram rendered into a texture.
+pub struct PlotImage {
+    /// Label to show in plot
+    label: Arc<CStr>,
+    uv0: ImVec2,
+    uv1: ImVec2,
+    tint: ImVec4,
+    flags: PlotImageFlags,
+}
+
+pub type PlotImageFlags = sys::ImPlotImageFlags_;
+
+impl PlotImage {
+    /// Create a new image to be shown. By default, the whole texture is shown without tint.
+    /// Does not draw anything by itself, call [`PlotImage::plot`] on the struct for that.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: intern(label),
+            uv0: ImVec2 { x: 0.0, y: 0.0 },
+            uv1: ImVec2 { x: 1.0, y: 1.0 },
+            tint: ImVec4 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+                w: 1.0,
+            },
+            flags: PlotImageFlags::NONE,
+        }
+    }
+
+    /// Set the texture coordinates of the corners of the shown part of the texture. `uv0`
+    /// is drawn at the lower left bound, `uv1` at the upper right one.
+    pub fn with_uv(mut self, uv0: ImVec2, uv1: ImVec2) -> Self {
+        self.uv0 = uv0;
+        self.uv1 = uv1;
+        self
+    }
+
+    /// Set the color the texture is multiplied with.
+    pub fn with_tint(mut self, tint: ImVec4) -> Self {
+        self.tint = tint;
+        self
+    }
+
+    pub fn with_flags(mut self, flags: PlotImageFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Draw the texture, stretched to the rectangle between the given bounds in plot
+    /// coordinates.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotImage"))]
+    pub fn plot(&self, texture: TextureId, bounds_min: ImPlotPoint, bounds_max: ImPlotPoint) {
+        unsafe {
+            sys::ImPlot_PlotImage(
+                self.label.as_ptr(),
+                texture.id() as sys::ImTextureID,
+                bounds_min,
+                bounds_max,
+                self.uv0,
+                self.uv1,
+                self.tint,
+                self.flags.0 as sys::ImPlotImageFlags,
+            );
+        }
+    }
 }
 
 /// Struct to provide stem plotting functionality.
 pub struct PlotStems {
     /// Label to show in the legend for this line
-    label: CString,
+    label: Arc<CStr>,
 
     /// Reference value for the y value, which the stems are "with respect to"
     reference_y: f64,
+    flags: PlotStemsFlags,
+    /// Only every `stride`-th value is plotted, see `with_stride`
+    stride: usize,
+    /// Index of the value to start plotting at, see `with_offset`
+    offset: usize,
+    /// Per-item style overrides, see `with_color` etc.
+    style: ItemStyle,
 }
 
 pub type PlotStemsFlags = sys::ImPlotStemsFlags_;
@@ -421,9 +1566,12 @@ impl PlotStems {
     /// [`PlotStems::plot`] on the struct for that.
     pub fn new(label: &str) -> Self {
         Self {
-            label: CString::new(label)
-                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            label: intern(label),
+            style: ItemStyle::default(),
             reference_y: 0.0, // Default value taken from C++ implot
+            flags: PlotStemsFlags::NONE,
+            stride: 1,
+            offset: 0,
         }
     }
 
@@ -433,32 +1581,57 @@ impl PlotStems {
         self
     }
 
+    /// Set the flags of the stems. The `horizontal` argument of [`PlotStems::plot`] is combined
+    /// with these, so it does not have to be repeated here.
+    pub fn with_flags(mut self, flags: PlotStemsFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Only plot every `stride`-th value of the data, see [`PlotLine::with_stride`].
+    ///
+    /// # Panics
+    /// Will panic if `stride` is zero.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "PlotStems stride must be at least 1");
+        self.stride = stride;
+        self
+    }
+
+    /// Start plotting at the value with the given index, see [`PlotLine::with_offset`].
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
     /// Draw a previously-created stem plot. Use this in closures passed to
     /// [`Plot::build()`](struct.Plot.html#method.build). The `axis_positions` specify where on the
     /// X axis the stems are drawn, and the `stem_values` specify what values the stems have.
-    pub fn plot(&self, axis_positions: &[f64], stem_values: &[f64], horizontal: bool) {
-        let number_of_points = axis_positions.len().min(stem_values.len());
+    pub fn plot<T: PlotScalar>(&self, axis_positions: &[T], stem_values: &[T], horizontal: bool) {
+        let number_of_points =
+            strided_count(axis_positions.len().min(stem_values.len()), self.stride);
         // If there is no data to plot, we stop here
         if number_of_points == 0 {
             return;
         }
 
         let flags = if horizontal {
-            PlotStemsFlags::HORIZONTAL
+            self.flags | PlotStemsFlags::HORIZONTAL
         } else {
-            PlotStemsFlags::NONE
+            self.flags
         };
 
+        self.style.apply();
         unsafe {
-            sys::ImPlot_PlotStems_doublePtrdoublePtr(
+            T::plot_stems(
                 self.label.as_ptr() as *const c_char,
                 axis_positions.as_ptr(),
                 stem_values.as_ptr(),
                 number_of_points as i32, // "as" casts saturate as of Rust 1.45. This is safe here.
                 self.reference_y,
                 flags.0 as sys::ImPlotStemsFlags,
-                0,                                 // No offset
-                std::mem::size_of::<f64>() as i32, // Stride, set to one f64 for the standard use case
+                (self.offset % number_of_points) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
             );
         }
     }
@@ -467,8 +1640,14 @@ impl PlotStems {
 /// Struct to provide functionality for shaded plots.
 pub struct PlotShaded {
     /// Label to show in plot
-    label: CString,
+    label: Arc<CStr>,
     flags: PlotShadedFlags,
+    /// Only every `stride`-th value is plotted, see `with_stride`
+    stride: usize,
+    /// Index of the value to start plotting at, see `with_offset`
+    offset: usize,
+    /// Per-item style overrides, see `with_color` etc.
+    style: ItemStyle,
 }
 
 pub type PlotShadedFlags = sys::ImPlotShadedFlags_;
@@ -478,9 +1657,11 @@ impl PlotShaded {
     /// [`PlotShaded::plot`] on the struct for that.
     pub fn new(label: &str) -> Self {
         Self {
-            label: CString::new(label)
-                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            label: intern(label),
+            style: ItemStyle::default(),
             flags: PlotShadedFlags::NONE,
+            stride: 1,
+            offset: 0,
         }
     }
 
@@ -489,20 +1670,248 @@ impl PlotShaded {
         self
     }
 
-    pub fn plot(&self, xs: &[f64], ys1: &[f64], ys2: &[f64]) {
-        if xs.is_empty() || ys1.is_empty() || ys2.is_empty() {
+    /// Only plot every `stride`-th value of the data, see [`PlotLine::with_stride`].
+    ///
+    /// # Panics
+    /// Will panic if `stride` is zero.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "PlotShaded stride must be at least 1");
+        self.stride = stride;
+        self
+    }
+
+    /// Start plotting at the value with the given index, see [`PlotLine::with_offset`].
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Shade the area between two lines sharing their X values. Like for [`PlotLine::plot`],
+    /// `f32` and integer data is plotted without conversion.
+    pub fn plot<T: PlotScalar>(&self, xs: &[T], ys1: &[T], ys2: &[T]) {
+        let count = strided_count(xs.len().min(ys1.len()).min(ys2.len()), self.stride);
+        if count == 0 {
             return;
         }
+        self.style.apply();
         unsafe {
-            sys::ImPlot_PlotShaded_doublePtrdoublePtrdoublePtr(
+            T::plot_shaded(
                 self.label.as_ptr(),
                 xs.as_ptr(),
                 ys1.as_ptr(),
                 ys2.as_ptr(),
-                xs.len().min(ys1.len()).min(ys2.len()) as i32,
+                count as i32,
                 self.flags.0 as sys::ImPlotShadedFlags,
-                0,
-                std::mem::size_of::<f64>() as i32,
+                (self.offset % count) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Shade the area between a line and the constant `y_ref`, e.g. to fill down to zero.
+    /// Infinite values of `y_ref` fill to the edge of the plot.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotShaded"))]
+    pub fn plot_to_ref<T: PlotScalar>(&self, xs: &[T], ys: &[T], y_ref: f64) {
+        let count = strided_count(xs.len().min(ys.len()), self.stride);
+        if count == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_shaded_to_ref(
+                self.label.as_ptr(),
+                xs.as_ptr(),
+                ys.as_ptr(),
+                count as i32,
+                y_ref,
+                self.flags.0 as sys::ImPlotShadedFlags,
+                (self.offset % count) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Shade the area between uniformly sampled values and the constant `y_ref`, see
+    /// [`PlotShaded::plot_to_ref`] and [`PlotLine::plot_values`].
+    #[rustversion::attr(since(1.48), doc(alias = "PlotShaded"))]
+    pub fn plot_values_to_ref<T: PlotScalar>(
+        &self,
+        values: &[T],
+        y_ref: f64,
+        x_scale: f64,
+        x_start: f64,
+    ) {
+        let number_of_points = strided_count(values.len(), self.stride);
+        // If there is no data to plot, we stop here
+        if number_of_points == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            T::plot_shaded_values(
+                self.label.as_ptr() as *const c_char,
+                values.as_ptr(),
+                number_of_points as i32,
+                y_ref,
+                x_scale,
+                x_start,
+                self.flags.0 as sys::ImPlotShadedFlags,
+                (self.offset % number_of_points) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Shade the area between two lines of `count` points each, computed by `getter1` and
+    /// `getter2` from their index, see [`PlotLine::plot_with`].
+    #[rustversion::attr(since(1.48), doc(alias = "PlotShadedG"))]
+    pub fn plot_with<F1, F2>(&self, count: usize, mut getter1: F1, mut getter2: F2)
+    where
+        F1: FnMut(usize) -> ImPlotPoint,
+        F2: FnMut(usize) -> ImPlotPoint,
+    {
+        if count == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotShadedG(
+                self.label.as_ptr(),
+                Some(call_getter::<F1>),
+                &mut getter1 as *mut F1 as *mut c_void,
+                Some(call_getter::<F2>),
+                &mut getter2 as *mut F2 as *mut c_void,
+                count as i32,
+                self.flags.0 as sys::ImPlotShadedFlags,
+            );
+        }
+        resume_callback_panic();
+    }
+
+    /// Shade the area between a line through interleaved XY data (see [`IntoPlotData`]) and the
+    /// constant `y_ref`, like [`PlotShaded::plot_to_ref`].
+    pub fn plot_xy_to_ref<D: IntoPlotData + ?Sized>(&self, data: &D, y_ref: f64) {
+        let (x, y) = data.plot_data();
+        let count = x.len();
+        if count == 0 {
+            return;
+        }
+        self.style.apply();
+        unsafe {
+            sys::ImPlot_PlotShaded_doublePtrdoublePtrInt(
+                self.label.as_ptr(),
+                x.as_ptr(),
+                y.as_ptr(),
+                count as i32,
+                y_ref,
+                self.flags.0 as sys::ImPlotShadedFlags,
+                (self.offset % count) as i32,
+                x.stride() as i32,
+            );
+        }
+    }
+}
+
+/// Struct to provide functionality for error bars, e.g. for measurement data. Error bars are
+/// vertical by default, use the `HORIZONTAL` flag for horizontal ones.
+pub struct PlotErrorBars {
+    /// Label to show in plot
+    label: Arc<CStr>,
+    flags: PlotErrorBarsFlags,
+    /// Only every `stride`-th value is plotted, see `with_stride`
+    stride: usize,
+    /// Index of the value to start plotting at, see `with_offset`
+    offset: usize,
+}
+
+pub type PlotErrorBarsFlags = sys::ImPlotErrorBarsFlags_;
+
+impl PlotErrorBars {
+    /// Create new error bars to be shown. Does not draw anything by itself, call
+    /// [`PlotErrorBars::plot`] or [`PlotErrorBars::plot_asymmetric`] on the struct for that.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: intern(label),
+            flags: PlotErrorBarsFlags::NONE,
+            stride: 1,
+            offset: 0,
+        }
+    }
+
+    pub fn with_flags(mut self, flags: PlotErrorBarsFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Only plot every `stride`-th value of the data, see [`PlotLine::with_stride`].
+    ///
+    /// # Panics
+    /// Will panic if `stride` is zero.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "PlotErrorBars stride must be at least 1");
+        self.stride = stride;
+        self
+    }
+
+    /// Start plotting at the value with the given index, see [`PlotLine::with_offset`].
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Draw symmetric error bars, spanning from `y - error` to `y + error` at each `x` (or
+    /// along X for horizontal error bars).
+    #[rustversion::attr(since(1.48), doc(alias = "PlotErrorBars"))]
+    pub fn plot<T: PlotScalar>(&self, xs: &[T], ys: &[T], errors: &[T]) {
+        let count = strided_count(xs.len().min(ys.len()).min(errors.len()), self.stride);
+        if count == 0 {
+            return;
+        }
+        unsafe {
+            T::plot_error_bars(
+                self.label.as_ptr(),
+                xs.as_ptr(),
+                ys.as_ptr(),
+                errors.as_ptr(),
+                count as i32,
+                self.flags.0 as sys::ImPlotErrorBarsFlags,
+                (self.offset % count) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
+            );
+        }
+    }
+
+    /// Draw asymmetric error bars, spanning from `y - negative` to `y + positive` at each `x`
+    /// (or along X for horizontal error bars).
+    #[rustversion::attr(since(1.48), doc(alias = "PlotErrorBars"))]
+    pub fn plot_asymmetric<T: PlotScalar>(
+        &self,
+        xs: &[T],
+        ys: &[T],
+        negative: &[T],
+        positive: &[T],
+    ) {
+        let count = strided_count(
+            xs.len()
+                .min(ys.len())
+                .min(negative.len())
+                .min(positive.len()),
+            self.stride,
+        );
+        if count == 0 {
+            return;
+        }
+        unsafe {
+            T::plot_error_bars_asymmetric(
+                self.label.as_ptr(),
+                xs.as_ptr(),
+                ys.as_ptr(),
+                negative.as_ptr(),
+                positive.as_ptr(),
+                count as i32,
+                self.flags.0 as sys::ImPlotErrorBarsFlags,
+                (self.offset % count) as i32,
+                (self.stride * std::mem::size_of::<T>()) as i32,
             );
         }
     }
@@ -511,26 +1920,34 @@ impl PlotShaded {
 /// Struct to provide functionality for histogram plots.
 pub struct PlotHistogram {
     /// Label to show in plot
-    label: CString,
+    label: Arc<CStr>,
     flags: PlotHistogramFlags,
+    bins: PlotBin,
+    bar_scale: f64,
+    /// Range of values to take into account, `None` for the full range of the data
+    range: Option<ImPlotRange>,
 }
 
 pub type PlotHistogramFlags = sys::ImPlotHistogramFlags_;
 pub type PlotBinMethod = sys::ImPlotBin_;
 
+/// Number of histogram bins, or a method to determine it from the data.
+#[derive(Clone, Copy, Debug)]
 pub enum PlotBin {
     Auto(PlotBinMethod),
     Manual(u32),
 }
 
 impl PlotHistogram {
-    /// Create a new shaded plot to be shown. Does not draw anything by itself, call
-    /// [`PlotHistogram::plot`] on the struct for that.
+    /// Create a new histogram to be shown, with bins determined by Sturges' rule by default.
+    /// Does not draw anything by itself, call [`PlotHistogram::plot`] on the struct for that.
     pub fn new(label: &str) -> Self {
         Self {
-            label: CString::new(label)
-                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            label: intern(label),
             flags: PlotHistogramFlags::NONE,
+            bins: PlotBin::Auto(PlotBinMethod::Sturges), // Default value taken from C++ implot
+            bar_scale: 1.0,
+            range: None,
         }
     }
 
@@ -539,28 +1956,67 @@ impl PlotHistogram {
         self
     }
 
-    pub fn plot(
-        &self,
-        values: &[f64],
-        bins: PlotBin,
-        bar_scale: Option<f64>,
-        range: Option<ImPlotRange>,
-    ) {
-        let bar_scale = bar_scale.unwrap_or(1.0);
-        let range = range.unwrap_or(ImPlotRange { Min: 0.0, Max: 0.0 });
-        let bins = match bins {
+    /// Set the number of bins, or the method to determine it from the data.
+    pub fn with_bins(mut self, bins: PlotBin) -> Self {
+        self.bins = bins;
+        self
+    }
+
+    /// Scale the height of the bars, e.g. to make the histogram fit in with other items.
+    /// Defaults to 1.
+    pub fn with_bar_scale(mut self, bar_scale: f64) -> Self {
+        self.bar_scale = bar_scale;
+        self
+    }
+
+    /// Only take values within the given range into account. By default, the full range of
+    /// the data is used.
+    pub fn with_range<R: Into<ImPlotRange>>(mut self, range: R) -> Self {
+        self.range = Some(range.into());
+        self
+    }
+
+    /// Show the cumulative counts, i.e. each bar includes the counts of all bars before it.
+    pub fn cumulative(mut self) -> Self {
+        self.flags |= PlotHistogramFlags::CUMULATIVE;
+        self
+    }
+
+    /// Normalize the counts to a probability density, so the area of the bars adds up to 1.
+    pub fn density(mut self) -> Self {
+        self.flags |= PlotHistogramFlags::DENSITY;
+        self
+    }
+
+    /// Leave out values outside of the range set with [`PlotHistogram::with_range`] entirely,
+    /// instead of counting them towards the outermost bins.
+    pub fn no_outliers(mut self) -> Self {
+        self.flags |= PlotHistogramFlags::NO_OUTLIERS;
+        self
+    }
+
+    /// Draw the histogram of the given values. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    #[rustversion::attr(since(1.48), doc(alias = "PlotHistogram"))]
+    pub fn plot<T: PlotScalar>(&self, values: &[T]) {
+        // If there is no data to plot, we stop here
+        if values.is_empty() {
+            return;
+        }
+        let range = self.range.unwrap_or(ImPlotRange { Min: 0.0, Max: 0.0 });
+        let bins = match self.bins {
             // Auto uses negative integers
-            PlotBin::Auto(auto) => auto as sys::ImPlotBin,
+            PlotBin::Auto(auto) => auto.into(),
             // Manual uses positive integers
             PlotBin::Manual(bins) => bins as sys::ImPlotBin,
         };
         unsafe {
-            sys::ImPlot_PlotHistogram_doublePtr(
+            T::plot_histogram(
                 self.label.as_ptr(),
                 values.as_ptr(),
                 values.len() as i32,
                 bins,
-                bar_scale,
+                self.bar_scale,
                 range,
                 self.flags.0 as sys::ImPlotHistogramFlags,
             );
@@ -568,6 +2024,11 @@ impl PlotHistogram {
     }
 }
 
+/// Look up the null-terminated versions of the given labels, see `intern`.
+fn intern_labels<S: AsRef<str>>(labels: &[S]) -> Vec<Arc<CStr>> {
+    labels.iter().map(|label| intern(label.as_ref())).collect()
+}
+
 /// Struct to provide functionality for pie charts.
 pub struct PlotPieChart {
     label_fmt: Option<CString>,
@@ -596,19 +2057,27 @@ impl PlotPieChart {
         self
     }
 
-    pub fn plot(
+    /// Plot a pie chart of the values, centered at `(x, y)` with the given radius in plot
+    /// units, one slice per value and label. The first slice starts at `angle0` degrees,
+    /// defaulting to 90. The labels can be given as e.g. `&["a", "b"]` or `&[String]`; like item
+    /// labels, they are only converted for ImPlot the first time they are seen:
+    /// ```no_run
+    /// # use implot::PlotPieChart;
+    /// PlotPieChart::new().plot(&["rent", "food", "other"], &[0.5, 0.3, 0.2], 0.5, 0.5, 0.4, None);
+    /// ```
+    ///
+    /// # Panics
+    /// Will panic if any of the labels contain internal null bytes.
+    pub fn plot<T: PlotScalar, S: AsRef<str>>(
         &self,
-        labels: Vec<String>,
-        values: &[f64],
+        labels: &[S],
+        values: &[T],
         x: f64,
         y: f64,
         radius: f64,
         angle0: Option<f64>,
     ) {
-        let labels: Vec<_> = labels
-            .into_iter()
-            .map(|s| CString::new(s).unwrap())
-            .collect();
+        let labels = intern_labels(labels);
         let labels: Vec<_> = labels.iter().map(|s| s.as_ptr()).collect();
         let count = labels.len().min(values.len());
         let fmt = if let Some(fmt) = self.label_fmt.as_ref() {
@@ -618,7 +2087,7 @@ impl PlotPieChart {
         };
 
         unsafe {
-            sys::ImPlot_PlotPieChart_doublePtrStr(
+            T::plot_pie_chart(
                 labels.as_ptr(),
                 values.as_ptr(),
                 count as i32,
@@ -630,6 +2099,61 @@ impl PlotPieChart {
                 self.flags.0 as sys::ImPlotPieChartFlags,
             )
         }
+        resume_callback_panic();
+    }
+
+    /// Plot the pie chart like [`PlotPieChart::plot`], with the slice labels produced by
+    /// `formatter` from the slice values instead of a format string. Labels longer than ImPlot's
+    /// buffer (32 bytes currently) are truncated. To show percentages, divide by the sum of the
+    /// values in the closure:
+    /// ```no_run
+    /// # use implot::PlotPieChart;
+    /// let values = [3.0, 1.0];
+    /// let total: f64 = values.iter().sum();
+    /// PlotPieChart::new().plot_with_formatter(
+    ///     &["a", "b"],
+    ///     &values,
+    ///     0.5,
+    ///     0.5,
+    ///     0.4,
+    ///     None,
+    ///     |value| format!("{:.0}%", value / total * 100.0),
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    /// Will panic if any of the labels contain internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "PlotPieChart"))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn plot_with_formatter<T: PlotScalar, S: AsRef<str>, F: FnMut(f64) -> String>(
+        &self,
+        labels: &[S],
+        values: &[T],
+        x: f64,
+        y: f64,
+        radius: f64,
+        angle0: Option<f64>,
+        mut formatter: F,
+    ) {
+        let labels = intern_labels(labels);
+        let labels: Vec<_> = labels.iter().map(|s| s.as_ptr()).collect();
+        let count = labels.len().min(values.len());
+
+        unsafe {
+            T::plot_pie_chart_with_formatter(
+                labels.as_ptr(),
+                values.as_ptr(),
+                count as i32,
+                x,
+                y,
+                radius,
+                Some(call_formatter::<F>),
+                &mut formatter as *mut F as *mut c_void,
+                angle0.unwrap_or(90.0),
+                self.flags.0 as sys::ImPlotPieChartFlags,
+            )
+        }
+        resume_callback_panic();
     }
 }
 
@@ -642,7 +2166,7 @@ impl Default for PlotPieChart {
 /// Struct to provide functionality for colormap plots.
 pub struct PlotColormap {
     /// Label to show in plot
-    label: CString,
+    label: Arc<CStr>,
     scale_flags: PlotColormapScaleFlags,
     fmt: Option<CString>,
 }
@@ -654,8 +2178,7 @@ impl PlotColormap {
     /// [`PlotColormap::plot`] on the struct for that.
     pub fn new(label: &str) -> Self {
         Self {
-            label: CString::new(label)
-                .unwrap_or_else(|_| panic!("Label string has internal null bytes: {}", label)),
+            label: intern(label),
             scale_flags: PlotColormapScaleFlags::NONE,
             fmt: None,
         }
@@ -702,3 +2225,75 @@ impl PlotColormap {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs `formatter` through `call_formatter` with a buffer of the given size.
+    fn format_into<F: FnMut(f64) -> String>(mut formatter: F, value: f64, size: usize) -> Vec<u8> {
+        let mut buffer = vec![1 as c_char; size.max(1)];
+        let length = unsafe {
+            call_formatter::<F>(
+                value,
+                buffer.as_mut_ptr(),
+                size as c_int,
+                &mut formatter as *mut F as *mut c_void,
+            )
+        };
+        buffer[..length as usize].iter().map(|c| *c as u8).collect()
+    }
+
+    #[test]
+    fn test_formatter_truncates_to_buffer() {
+        let formatter = |value: f64| format!("{}µs", value);
+        assert_eq!(format_into(formatter, 12.0, 32), "12µs".as_bytes());
+        // "µ" takes two bytes and must not be split
+        assert_eq!(format_into(formatter, 12.0, 4), b"12");
+        assert_eq!(format_into(formatter, 12.0, 0), b"");
+        assert_eq!(format_into(|_| "a\0b".to_owned(), 0.0, 32), b"a");
+    }
+    #[test]
+    fn test_getter_panic_is_resumed() {
+        let mut calls = 0;
+        let mut getter = |index: usize| {
+            calls += 1;
+            if index == 1 {
+                panic!("getter failed");
+            }
+            ImPlotPoint { x: 1.0, y: 2.0 }
+        };
+        let data = &mut getter as *mut _ as *mut c_void;
+        let mut points = [ImPlotPoint { x: -1.0, y: -1.0 }; 3];
+        for (index, point) in points.iter_mut().enumerate() {
+            unsafe { call_getter_of(&getter, data, index as c_int, point) };
+        }
+        assert_eq!(calls, 2);
+        assert_eq!((points[0].x, points[1].x, points[2].x), (1.0, 0.0, 0.0));
+
+        let payload = panic::catch_unwind(resume_callback_panic).unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"getter failed"));
+        // The panic is only resumed once
+        resume_callback_panic();
+    }
+
+    #[test]
+    fn test_formatter_panic_is_resumed() {
+        assert_eq!(format_into(|_| panic!("formatter failed"), 1.0, 32), b"");
+        // Further labels of the same ImPlot call are left empty without calling the closure
+        assert_eq!(format_into(|_| "1".to_owned(), 1.0, 32), b"");
+        let payload = panic::catch_unwind(resume_callback_panic).unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"formatter failed"));
+        assert_eq!(format_into(|_| "1".to_owned(), 1.0, 32), b"1");
+    }
+
+    /// Calls `call_getter` for the type of `_getter`, which can't be named.
+    unsafe fn call_getter_of<F: FnMut(usize) -> ImPlotPoint>(
+        _getter: &F,
+        data: *mut c_void,
+        index: c_int,
+        point: *mut ImPlotPoint,
+    ) {
+        call_getter::<F>(data, index, point);
+    }
+}
@@ -0,0 +1,1037 @@
+//! # Plot helpers module
+//!
+//! This module contains composite chart types that are not plot elements in ImPlot itself, but
+//! are built from them (and from the plot's draw list where ImPlot has no suitable primitive).
+//! They are used the same way as the elements in `plot_elements`.
+
+use crate::intern::intern;
+use crate::{
+    sys, Plot, PlotColormap, PlotHeatmap, PlotLine, PlotScalar, PlotShaded, PlotUi, IMPLOT_AUTO,
+};
+use std::ffi::CStr;
+use std::ops::Range;
+use std::os::raw::c_char;
+use std::sync::Arc;
+
+use crate::sys::{ImPlotPoint, ImVec2, ImVec4};
+
+/// One step of a waterfall chart.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WaterfallStep {
+    /// A change relative to the running total, drawn as a floating bar.
+    Delta(f64),
+    /// A bar showing the running total so far, drawn from zero.
+    Total,
+}
+
+/// Kind of a computed waterfall bar, which decides the color it is drawn with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WaterfallBarKind {
+    Increase,
+    Decrease,
+    Total,
+}
+
+/// A single computed waterfall bar, spanning from `start` to `end` in Y.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WaterfallBar {
+    pub start: f64,
+    pub end: f64,
+    pub kind: WaterfallBarKind,
+}
+
+/// Compute the floating bars of a waterfall chart from a list of steps. Deltas float on top of
+/// the running total, totals are drawn from zero up to the running total.
+pub fn waterfall_bars(steps: &[WaterfallStep]) -> Vec<WaterfallBar> {
+    let mut running_total = 0.0;
+    steps
+        .iter()
+        .map(|step| match *step {
+            WaterfallStep::Delta(delta) => {
+                let start = running_total;
+                running_total += delta;
+                WaterfallBar {
+                    start,
+                    end: running_total,
+                    kind: if delta >= 0.0 {
+                        WaterfallBarKind::Increase
+                    } else {
+                        WaterfallBarKind::Decrease
+                    },
+                }
+            }
+            WaterfallStep::Total => WaterfallBar {
+                start: 0.0,
+                end: running_total,
+                kind: WaterfallBarKind::Total,
+            },
+        })
+        .collect()
+}
+
+/// Struct to provide functionality for waterfall (bridge) charts, as commonly used for
+/// financial data. Each step is drawn as a bar at X positions `0, 1, 2, ...`, colored by
+/// whether it increases or decreases the running total or shows the total itself.
+pub struct PlotWaterfall {
+    /// Label to show in the legend for this chart
+    label: Arc<CStr>,
+    /// Width of the bars, in plot coordinate terms
+    bar_width: f64,
+    increase_color: ImVec4,
+    decrease_color: ImVec4,
+    total_color: ImVec4,
+    /// Whether to draw lines connecting the end of one bar to the start of the next
+    connectors: bool,
+}
+
+impl PlotWaterfall {
+    /// Create a new waterfall chart to be shown. Does not draw anything by itself, call
+    /// [`PlotWaterfall::plot`] on the struct for that.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: intern(label),
+            bar_width: 0.67, // Same default as for regular bars
+            increase_color: ImVec4 {
+                x: 0.20,
+                y: 0.65,
+                z: 0.30,
+                w: 1.0,
+            },
+            decrease_color: ImVec4 {
+                x: 0.80,
+                y: 0.25,
+                z: 0.25,
+                w: 1.0,
+            },
+            total_color: ImVec4 {
+                x: 0.25,
+                y: 0.45,
+                z: 0.80,
+                w: 1.0,
+            },
+            connectors: true,
+        }
+    }
+
+    /// Set the width of the bars
+    pub fn with_bar_width(mut self, bar_width: f64) -> Self {
+        self.bar_width = bar_width;
+        self
+    }
+
+    /// Set the colors used for increasing steps, decreasing steps and totals.
+    pub fn with_colors(mut self, increase: ImVec4, decrease: ImVec4, total: ImVec4) -> Self {
+        self.increase_color = increase;
+        self.decrease_color = decrease;
+        self.total_color = total;
+        self
+    }
+
+    /// Enable or disable the connector lines between consecutive bars.
+    pub fn with_connectors(mut self, connectors: bool) -> Self {
+        self.connectors = connectors;
+        self
+    }
+
+    /// Draw the waterfall chart for the given steps. Use this in closures passed to
+    /// [`Plot::build()`](struct.Plot.html#method.build)
+    pub fn plot(&self, steps: &[WaterfallStep]) {
+        let bars = waterfall_bars(steps);
+        // If there is no data to plot, we stop here
+        if bars.is_empty() {
+            return;
+        }
+
+        let half_width = self.bar_width / 2.0;
+        unsafe {
+            // BeginItem registers the legend entry and pushes the plot clip rect, which
+            // EndItem pops again.
+            if !sys::ImPlot_BeginItem(
+                self.label.as_ptr() as *const c_char,
+                sys::ImPlotItemFlags_::NONE.0 as sys::ImPlotItemFlags,
+                sys::ImPlotCol_::Fill.into(),
+            ) {
+                return;
+            }
+
+            if sys::ImPlot_FitThisFrame() {
+                for (x, bar) in bars.iter().enumerate() {
+                    let x = x as f64;
+                    sys::ImPlot_FitPoint(ImPlotPoint {
+                        x: x - half_width,
+                        y: bar.start,
+                    });
+                    sys::ImPlot_FitPoint(ImPlotPoint {
+                        x: x + half_width,
+                        y: bar.end,
+                    });
+                }
+            }
+
+            let draw_list = sys::ImPlot_GetPlotDrawList();
+            let to_pixels = |x: f64, y: f64| {
+                let mut pixels = sys::ImVec2 { x: 0.0, y: 0.0 };
+                sys::ImPlot_PlotToPixels_double(
+                    &mut pixels as *mut sys::ImVec2,
+                    x,
+                    y,
+                    IMPLOT_AUTO as sys::ImAxis,
+                    IMPLOT_AUTO as sys::ImAxis,
+                );
+                pixels
+            };
+
+            for (x, bar) in bars.iter().enumerate() {
+                let x = x as f64;
+                let color = match bar.kind {
+                    WaterfallBarKind::Increase => self.increase_color,
+                    WaterfallBarKind::Decrease => self.decrease_color,
+                    WaterfallBarKind::Total => self.total_color,
+                };
+                // Pixel Y coordinates grow downwards, so min/max have to be sorted out here
+                let corner_a = to_pixels(x - half_width, bar.start);
+                let corner_b = to_pixels(x + half_width, bar.end);
+                sys::ImDrawList_AddRectFilled(
+                    draw_list,
+                    sys::ImVec2 {
+                        x: corner_a.x.min(corner_b.x),
+                        y: corner_a.y.min(corner_b.y),
+                    },
+                    sys::ImVec2 {
+                        x: corner_a.x.max(corner_b.x),
+                        y: corner_a.y.max(corner_b.y),
+                    },
+                    sys::igColorConvertFloat4ToU32(color),
+                    0.0,
+                    0,
+                );
+            }
+
+            if self.connectors {
+                let color = sys::ImPlot_GetStyleColorU32(sys::ImPlotCol_::AxisGrid.into());
+                for (x, pair) in bars.windows(2).enumerate() {
+                    let x = x as f64;
+                    sys::ImDrawList_AddLine(
+                        draw_list,
+                        to_pixels(x + half_width, pair[0].end),
+                        to_pixels(x + 1.0 - half_width, pair[0].end),
+                        color,
+                        1.0,
+                    );
+                }
+            }
+
+            sys::ImPlot_EndItem();
+        }
+    }
+}
+
+/// Lower and upper bounds of an error band, `mean - deviation` and `mean + deviation`.
+fn error_band_bounds(mean: &[f64], deviation: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    mean.iter()
+        .zip(deviation)
+        .map(|(mean, deviation)| (mean - deviation, mean + deviation))
+        .unzip()
+}
+
+/// Struct to provide functionality for error bands, i.e. a line for the mean of some
+/// measurement with the area of plus/minus its deviation shaded around it. Both share one
+/// legend entry and color:
+/// ```no_run
+/// # use implot::{Context, Plot, PlotErrorBand};
+/// # let context = Context::create();
+/// # let plot_ui = context.get_plot_ui();
+/// let (x, mean, deviation) = (vec![0.0, 1.0, 2.0], vec![1.0, 3.0, 2.0], vec![0.2, 0.5, 0.3]);
+/// Plot::new("Measurement").build(&plot_ui, |_| {
+///     PlotErrorBand::new("latency").plot(&x, &mean, &deviation);
+/// });
+/// ```
+pub struct PlotErrorBand {
+    /// Label of the line and the band, which makes them share their legend entry and color
+    label: String,
+    color: Option<[f32; 4]>,
+    line_weight: Option<f32>,
+    /// Factor the alpha of the band's color is multiplied with
+    fill_alpha: f32,
+}
+
+impl PlotErrorBand {
+    /// Create a new error band to be shown. Does not draw anything by itself, call
+    /// [`PlotErrorBand::plot`] on the struct for that.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_owned(),
+            color: None,
+            line_weight: None,
+            fill_alpha: 0.25,
+        }
+    }
+
+    /// Set the color of the line and the band, instead of taking the next one from the
+    /// colormap.
+    pub fn with_color(mut self, color: [f32; 4]) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the line weight of the mean line, in pixels.
+    pub fn with_line_weight(mut self, weight: f32) -> Self {
+        self.line_weight = Some(weight);
+        self
+    }
+
+    /// Set the factor the alpha of the band's color is multiplied with. Defaults to 0.25.
+    pub fn with_fill_alpha(mut self, alpha: f32) -> Self {
+        self.fill_alpha = alpha;
+        self
+    }
+
+    /// Draw the mean line and shade the area from `mean - deviation` to `mean + deviation`
+    /// around it. Use this in closures passed to [`Plot::build()`](struct.Plot.html#method.build).
+    /// If the slices differ in length, the shortest one decides the number of points.
+    ///
+    /// # Panics
+    /// Will panic if the label contains internal null bytes.
+    pub fn plot(&self, xs: &[f64], mean: &[f64], deviation: &[f64]) {
+        let count = xs.len().min(mean.len()).min(deviation.len());
+        // If there is no data to plot, we stop here
+        if count == 0 {
+            return;
+        }
+        let (lower, upper) = error_band_bounds(&mean[..count], &deviation[..count]);
+
+        let mut band = PlotShaded::new(&self.label).with_fill_alpha(self.fill_alpha);
+        let mut line = PlotLine::new(&self.label);
+        if let Some(color) = self.color {
+            band = band.with_color(color);
+            line = line.with_color(color);
+        }
+        if let Some(weight) = self.line_weight {
+            line = line.with_line_weight(weight);
+        }
+        band.plot(&xs[..count], &lower, &upper);
+        line.plot(&xs[..count], &mean[..count]);
+    }
+}
+
+/// The triangle of an arrow head pointing from `tail` to `tip`, in pixels, and the point where
+/// the shaft should end so it does not poke through the tip. The head is `head_size` pixels
+/// long and wide, but at most as long as the arrow. `None` if the arrow has no length.
+fn arrow_head(tail: ImVec2, tip: ImVec2, head_size: f32) -> Option<([ImVec2; 3], ImVec2)> {
+    let (dx, dy) = (tip.x - tail.x, tip.y - tail.y);
+    let length = (dx * dx + dy * dy).sqrt();
+    if length <= f32::EPSILON {
+        return None;
+    }
+    let (ux, uy) = (dx / length, dy / length);
+    let head_length = head_size.min(length);
+    let base = ImVec2 {
+        x: tip.x - ux * head_length,
+        y: tip.y - uy * head_length,
+    };
+    let half_width = head_size / 2.0;
+    Some((
+        [
+            tip,
+            ImVec2 {
+                x: base.x - uy * half_width,
+                y: base.y + ux * half_width,
+            },
+            ImVec2 {
+                x: base.x + uy * half_width,
+                y: base.y - ux * half_width,
+            },
+        ],
+        base,
+    ))
+}
+
+/// Position of `value` within `range`, clamped to `[0, 1]`, for sampling a colormap.
+fn colormap_position(value: f64, range: (f64, f64)) -> f32 {
+    let span = range.1 - range.0;
+    if span <= 0.0 {
+        return 0.0;
+    }
+    ((value - range.0) / span).clamp(0.0, 1.0) as f32
+}
+
+/// Struct to provide functionality for quiver plots of vector fields, e.g. flow fields or
+/// gradients. Each vector `(u, v)` is drawn as an arrow starting at its position `(x, y)`,
+/// colored by its magnitude using the current colormap:
+/// ```no_run
+/// # use implot::{Context, Plot, PlotQuiver};
+/// # let context = Context::create();
+/// # let plot_ui = context.get_plot_ui();
+/// let (x, y) = (vec![0.0, 1.0, 0.0, 1.0], vec![0.0, 0.0, 1.0, 1.0]);
+/// let (u, v) = (vec![1.0, 0.0, -1.0, 0.5], vec![0.0, 1.0, 0.5, -1.0]);
+/// Plot::new("Flow").build(&plot_ui, |_| {
+///     PlotQuiver::new("velocity").with_scale(0.4).plot(&x, &y, &u, &v);
+/// });
+/// ```
+pub struct PlotQuiver {
+    /// Label to show in the legend for this plot
+    label: Arc<CStr>,
+    /// Factor from vector length to arrow length, in plot coordinate terms
+    scale: f64,
+    /// Length and width of the arrow heads, in pixels
+    head_size: f32,
+    line_weight: f32,
+    /// Fixed color for all arrows, or `None` to color them by magnitude
+    color: Option<ImVec4>,
+    /// Magnitudes mapped to the ends of the colormap, or `None` to use the range of the data
+    magnitude_range: Option<(f64, f64)>,
+}
+
+impl PlotQuiver {
+    /// Create a new quiver plot to be shown. Does not draw anything by itself, call
+    /// [`PlotQuiver::plot`] on the struct for that.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: intern(label),
+            scale: 1.0,
+            head_size: 8.0,
+            line_weight: 1.0,
+            color: None,
+            magnitude_range: None,
+        }
+    }
+
+    /// Set the factor from vector length to arrow length. Defaults to 1, so arrows end at
+    /// `(x + u, y + v)`.
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the length and width of the arrow heads, in pixels. Defaults to 8.
+    pub fn with_head_size(mut self, head_size: f32) -> Self {
+        self.head_size = head_size;
+        self
+    }
+
+    /// Set the thickness of the arrow shafts, in pixels. Defaults to 1.
+    pub fn with_line_weight(mut self, line_weight: f32) -> Self {
+        self.line_weight = line_weight;
+        self
+    }
+
+    /// Draw all arrows in one color instead of coloring them by magnitude.
+    pub fn with_color(mut self, color: [f32; 4]) -> Self {
+        let [x, y, z, w] = color;
+        self.color = Some(ImVec4 { x, y, z, w });
+        self
+    }
+
+    /// Set the magnitudes that are mapped to the lowest and highest colormap color. Smaller
+    /// and larger magnitudes are clamped. By default, the range of the data is used.
+    pub fn with_magnitude_range(mut self, min: f64, max: f64) -> Self {
+        self.magnitude_range = Some((min, max));
+        self
+    }
+
+    /// Draw an arrow for each vector `(u, v)` at position `(x, y)`. Use this in closures passed
+    /// to [`Plot::build()`](struct.Plot.html#method.build). If the slices differ in length, the
+    /// shortest one decides the number of arrows.
+    pub fn plot(&self, x: &[f64], y: &[f64], u: &[f64], v: &[f64]) {
+        let count = x.len().min(y.len()).min(u.len()).min(v.len());
+        // If there is no data to plot, we stop here
+        if count == 0 {
+            return;
+        }
+
+        let magnitudes: Vec<f64> = (0..count).map(|i| u[i].hypot(v[i])).collect();
+        let range = self.magnitude_range.unwrap_or_else(|| {
+            magnitudes
+                .iter()
+                .fold((magnitudes[0], magnitudes[0]), |(min, max), magnitude| {
+                    (min.min(*magnitude), max.max(*magnitude))
+                })
+        });
+
+        unsafe {
+            // BeginItem registers the legend entry and pushes the plot clip rect, which
+            // EndItem pops again.
+            if !sys::ImPlot_BeginItem(
+                self.label.as_ptr() as *const c_char,
+                sys::ImPlotItemFlags_::NONE.0 as sys::ImPlotItemFlags,
+                sys::ImPlotCol_::Line.into(),
+            ) {
+                return;
+            }
+
+            if sys::ImPlot_FitThisFrame() {
+                for i in 0..count {
+                    sys::ImPlot_FitPoint(ImPlotPoint { x: x[i], y: y[i] });
+                    sys::ImPlot_FitPoint(ImPlotPoint {
+                        x: x[i] + u[i] * self.scale,
+                        y: y[i] + v[i] * self.scale,
+                    });
+                }
+            }
+
+            let draw_list = sys::ImPlot_GetPlotDrawList();
+            let to_pixels = |x: f64, y: f64| {
+                let mut pixels = ImVec2 { x: 0.0, y: 0.0 };
+                sys::ImPlot_PlotToPixels_double(
+                    &mut pixels as *mut ImVec2,
+                    x,
+                    y,
+                    IMPLOT_AUTO as sys::ImAxis,
+                    IMPLOT_AUTO as sys::ImAxis,
+                );
+                pixels
+            };
+            let fixed_color = self
+                .color
+                .map(|color| sys::igColorConvertFloat4ToU32(color));
+
+            for i in 0..count {
+                let tail = to_pixels(x[i], y[i]);
+                let tip = to_pixels(x[i] + u[i] * self.scale, y[i] + v[i] * self.scale);
+                let (head, shaft_end) = match arrow_head(tail, tip, self.head_size) {
+                    Some(arrow) => arrow,
+                    None => continue,
+                };
+                let color = fixed_color.unwrap_or_else(|| {
+                    sys::ImPlot_SampleColormapU32(
+                        colormap_position(magnitudes[i], range),
+                        IMPLOT_AUTO as sys::ImPlotColormap,
+                    )
+                });
+                sys::ImDrawList_AddLine(draw_list, tail, shaft_end, color, self.line_weight);
+                sys::ImDrawList_AddTriangleFilled(draw_list, head[0], head[1], head[2], color);
+            }
+
+            sys::ImPlot_EndItem();
+        }
+    }
+}
+
+/// Scale range after dragging the colormap scale by `pixel_delta` pixels (positive downwards),
+/// for a scale that is `height` pixels tall. The range follows the mouse.
+fn pan_scale_range(range: (f64, f64), pixel_delta: f32, height: f32) -> (f64, f64) {
+    if height <= 0.0 {
+        return range;
+    }
+    let delta = (range.1 - range.0) * pixel_delta as f64 / height as f64;
+    (range.0 + delta, range.1 + delta)
+}
+
+/// Scale range after scrolling the mouse wheel over the colormap scale, zooming around the
+/// center of the range.
+fn zoom_scale_range(range: (f64, f64), wheel: f32) -> (f64, f64) {
+    let center = (range.0 + range.1) / 2.0;
+    let half_size = (range.1 - range.0) / 2.0 * 1.1f64.powf(-wheel as f64);
+    (center - half_size, center + half_size)
+}
+
+/// A heatmap plot with a colormap scale next to it, sharing the same value range. The scale
+/// can be used to change the range interactively: dragging it pans the range, scrolling zooms
+/// it and double-clicking resets it to the range of the data. Keep the struct around between
+/// frames to keep the edited range:
+/// ```no_run
+/// # use implot::{Context, HeatmapWithScale, Plot};
+/// # let context = Context::create();
+/// # let plot_ui = context.get_plot_ui();
+/// let mut heatmap = HeatmapWithScale::new("temperature").with_size([500.0, 400.0]);
+/// let values = vec![0.0, 1.0, 2.0, 3.0];
+///
+/// // Every frame:
+/// heatmap.show(&plot_ui, Plot::new("Temperature"), &values, 2, 2);
+/// ```
+/// Both the heatmap and the scale use the current colormap, so push a colormap around the
+/// call to change it.
+pub struct HeatmapWithScale {
+    label: String,
+    size: [f32; 2],
+    scale_width: f32,
+    scale_range: Option<(f64, f64)>,
+}
+
+impl HeatmapWithScale {
+    /// Create a heatmap with a scale. The label is used for the heatmap item, the scale
+    /// is labelled with it as well.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_owned(),
+            size: [crate::DEFAULT_PLOT_SIZE_X, crate::DEFAULT_PLOT_SIZE_Y],
+            scale_width: 80.0,
+            scale_range: None,
+        }
+    }
+
+    /// Set the size of the whole layout (plot and scale), given as [size_x, size_y].
+    pub fn with_size(mut self, size: [f32; 2]) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the width of the colormap scale, including its tick labels.
+    pub fn with_scale_width(mut self, scale_width: f32) -> Self {
+        self.scale_width = scale_width;
+        self
+    }
+
+    /// Fix the scale range, or `None` to fit it to the data.
+    pub fn with_scale_range(mut self, scale_range: Option<(f64, f64)>) -> Self {
+        self.scale_range = scale_range;
+        self
+    }
+
+    /// The current scale range, `None` if it is fitted to the data.
+    pub fn scale_range(&self) -> Option<(f64, f64)> {
+        self.scale_range
+    }
+
+    /// Set the scale range, or `None` to fit it to the data.
+    pub fn set_scale_range(&mut self, scale_range: Option<(f64, f64)>) {
+        self.scale_range = scale_range;
+    }
+
+    /// Draw the plot with the heatmap (values in row-major order) and the scale next to it.
+    /// The size of `plot` is overridden to make room for the scale. Returns true if the scale
+    /// range was changed interactively this frame. Nothing is drawn if there are fewer than
+    /// `number_of_rows * number_of_cols` values.
+    ///
+    /// # Panics
+    /// Will panic if the label contains internal null bytes.
+    pub fn show(
+        &mut self,
+        plot_ui: &PlotUi,
+        plot: Plot,
+        values: &[f64],
+        number_of_rows: u32,
+        number_of_cols: u32,
+    ) -> bool {
+        if values.len() < number_of_rows as usize * number_of_cols as usize {
+            return false;
+        }
+        let range = match self.scale_range {
+            Some(range) => range,
+            None if values.is_empty() => (0.0, 1.0),
+            None => values
+                .iter()
+                .fold((values[0], values[0]), |(min, max), value| {
+                    (min.min(*value), max.max(*value))
+                }),
+        };
+
+        let spacing = unsafe { (*sys::igGetStyle()).ItemSpacing.x };
+        let plot_width = (self.size[0] - self.scale_width - spacing).max(1.0);
+        plot.size([plot_width, self.size[1]]).build(plot_ui, |_| {
+            PlotHeatmap::new(&self.label)
+                .with_scale(range.0, range.1)
+                .plot(values, number_of_rows, number_of_cols, false);
+        });
+
+        unsafe {
+            sys::igSameLine(0.0, -1.0);
+        }
+        PlotColormap::new(&self.label).plot(
+            range.0,
+            range.1,
+            Some(sys::ImVec2 {
+                x: self.scale_width,
+                y: self.size[1],
+            }),
+            None,
+        );
+
+        // The scale is a plain imgui item, so interaction is handled here
+        unsafe {
+            if !sys::igIsItemHovered(0) {
+                return false;
+            }
+            let io = &*sys::igGetIO();
+            if sys::igIsMouseDoubleClicked(0) {
+                let changed = self.scale_range.is_some();
+                self.scale_range = None;
+                return changed;
+            }
+            let mut new_range = range;
+            if io.MouseWheel != 0.0 {
+                new_range = zoom_scale_range(new_range, io.MouseWheel);
+            }
+            if sys::igIsMouseDragging(0, -1.0) && io.MouseDelta.y != 0.0 {
+                new_range = pan_scale_range(new_range, io.MouseDelta.y, self.size[1]);
+            }
+            if new_range != range {
+                self.scale_range = Some(new_range);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// How the cells of a block are combined when a heatmap is downsampled, see [`PlotHeatmapLod`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeatmapPooling {
+    /// The mean of the cells, which keeps the overall picture.
+    Mean,
+    /// The maximum of the cells, which keeps single hot cells visible.
+    Max,
+}
+
+/// Downsample a matrix (row-major, `rows` by `cols`) by combining blocks of `factor` by
+/// `factor` cells into one. Blocks at the right and bottom edges may be smaller. Returns the
+/// pooled values, row-major, with their number of rows and columns.
+pub fn pool_matrix<T: PlotScalar>(
+    values: &[T],
+    rows: usize,
+    cols: usize,
+    factor: usize,
+    pooling: HeatmapPooling,
+) -> (Vec<f64>, usize, usize) {
+    let factor = factor.max(1);
+    // Rounded up, without `usize::div_ceil` since that needs a newer Rust than this crate does
+    let div_ceil = |n: usize| n / factor + usize::from(n % factor != 0);
+    let (pooled_rows, pooled_cols) = (div_ceil(rows), div_ceil(cols));
+    let mut pooled = match pooling {
+        HeatmapPooling::Mean => vec![0.0; pooled_rows * pooled_cols],
+        HeatmapPooling::Max => vec![f64::NEG_INFINITY; pooled_rows * pooled_cols],
+    };
+    for row in 0..rows.min(values.len() / cols.max(1)) {
+        let pooled_row = &mut pooled[row / factor * pooled_cols..][..pooled_cols];
+        for (col, value) in values[row * cols..][..cols].iter().enumerate() {
+            let cell = &mut pooled_row[col / factor];
+            match pooling {
+                HeatmapPooling::Mean => *cell += value.to_f64(),
+                HeatmapPooling::Max => *cell = cell.max(value.to_f64()),
+            }
+        }
+    }
+    if pooling == HeatmapPooling::Mean {
+        for (index, cell) in pooled.iter_mut().enumerate() {
+            let block_rows = (rows - index / pooled_cols * factor).min(factor);
+            let block_cols = (cols - index % pooled_cols * factor).min(factor);
+            *cell /= (block_rows * block_cols) as f64;
+        }
+    }
+    (pooled, pooled_rows, pooled_cols)
+}
+
+/// The pooling factor for showing `visible` cells (columns, rows) on `pixels` pixels (width,
+/// height): the smallest power of two that leaves at most one cell per pixel.
+fn lod_factor(visible: [f64; 2], pixels: [f32; 2]) -> usize {
+    let cells_per_pixel = (visible[0] / pixels[0].max(1.0) as f64)
+        .max(visible[1] / pixels[1].max(1.0) as f64)
+        .ceil();
+    if cells_per_pixel > 1.0 {
+        (cells_per_pixel as usize).next_power_of_two()
+    } else {
+        1
+    }
+}
+
+/// The range of cells of size `cell_size` that overlap `start..end`, where both are offsets from
+/// the edge of the first cell, limited to `count` cells.
+fn visible_cells(start: f64, end: f64, cell_size: f64, count: usize) -> Range<usize> {
+    let first = ((start / cell_size).floor() as usize).min(count);
+    let last = ((end / cell_size).ceil() as usize).min(count);
+    first..last
+}
+
+/// Minimum and maximum of the finite values.
+fn finite_range<I: Iterator<Item = f64>>(values: I) -> (f64, f64) {
+    values
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+/// A downsampled level of the matrix shown by a [`PlotHeatmapLod`].
+struct HeatmapLevel {
+    factor: usize,
+    /// Shape of the matrix it was pooled from
+    shape: (usize, usize),
+    /// The pooled values, empty for a factor of 1, where the matrix is used as it is
+    values: Vec<f64>,
+    rows: usize,
+    cols: usize,
+    /// Range of the pooled values
+    range: (f64, f64),
+}
+
+/// A heatmap for matrices much larger than the plot, e.g. 4096 by 4096 cells. Blocks of cells
+/// are pooled so that there is about one cell per pixel, and only the visible part of the
+/// pooled matrix is drawn. The pooled matrix is kept until the zoom level changes by a power of
+/// two, so panning does not pool again. Keep the struct around between frames:
+/// ```no_run
+/// # use implot::{Context, HeatmapPooling, Plot, PlotHeatmapLod};
+/// # let context = Context::create();
+/// # let plot_ui = context.get_plot_ui();
+/// let values = vec![0.0f32; 4096 * 4096];
+/// let mut heatmap = PlotHeatmapLod::new("intensity").with_pooling(HeatmapPooling::Max);
+///
+/// // Every frame:
+/// Plot::new("Detector").build(&plot_ui, |_| {
+///     heatmap.plot(&values, 4096, 4096);
+/// });
+/// ```
+/// The pooled matrix is not tied to the slice that is passed in: it is only rebuilt when the
+/// zoom level or the shape of the matrix changes. Call [`PlotHeatmapLod::invalidate`] whenever
+/// the values change, whether in place or by passing different ones. No value labels are drawn
+/// on the cells, since they would refer to pooled values.
+pub struct PlotHeatmapLod {
+    label: String,
+    scale_range: Option<(f64, f64)>,
+    lower_left: ImPlotPoint,
+    upper_right: ImPlotPoint,
+    pooling: HeatmapPooling,
+    level: Option<HeatmapLevel>,
+    /// The visible part of the level, copied for plotting
+    window: Vec<f64>,
+}
+
+impl PlotHeatmapLod {
+    /// Create a new heatmap, drawn in the unit square and pooled with the mean. Does not draw
+    /// anything by itself, call [`PlotHeatmapLod::plot`] on the struct for that.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_owned(),
+            scale_range: None,
+            lower_left: ImPlotPoint { x: 0.0, y: 0.0 },
+            upper_right: ImPlotPoint { x: 1.0, y: 1.0 },
+            pooling: HeatmapPooling::Mean,
+            level: None,
+            window: Vec::new(),
+        }
+    }
+
+    /// Specify the scale for the shown colors by minimum and maximum value. By default, the
+    /// range of the pooled values is used.
+    pub fn with_scale(mut self, scale_min: f64, scale_max: f64) -> Self {
+        self.scale_range = Some((scale_min, scale_max));
+        self
+    }
+
+    /// Specify the area the whole matrix is drawn in, as the lower left and upper right point.
+    pub fn with_drawing_area(mut self, lower_left: ImPlotPoint, upper_right: ImPlotPoint) -> Self {
+        self.lower_left = lower_left;
+        self.upper_right = upper_right;
+        self.level = None;
+        self
+    }
+
+    /// Set how blocks of cells are combined. Defaults to [`HeatmapPooling::Mean`].
+    pub fn with_pooling(mut self, pooling: HeatmapPooling) -> Self {
+        self.pooling = pooling;
+        self.level = None;
+        self
+    }
+
+    /// Pool the values again on the next call to [`PlotHeatmapLod::plot`]. Has to be called
+    /// whenever the values change.
+    pub fn invalidate(&mut self) {
+        self.level = None;
+    }
+
+    /// Plot the visible part of the heatmap of the given values, in row-major order. Nothing is
+    /// drawn if there are fewer than `number_of_rows * number_of_cols` values. Use this in
+    /// closures passed to [`Plot::build()`](struct.Plot.html#method.build)
+    ///
+    /// # Panics
+    /// Will panic if the label contains internal null bytes.
+    pub fn plot<T: PlotScalar>(&mut self, values: &[T], number_of_rows: u32, number_of_cols: u32) {
+        let (rows, cols) = (number_of_rows as usize, number_of_cols as usize);
+        if rows == 0 || cols == 0 || values.len() < rows * cols {
+            return;
+        }
+        let mut limits = sys::ImPlotRect::new([0.0, 0.0], [0.0, 0.0]);
+        let mut plot_size = ImVec2 { x: 0.0, y: 0.0 };
+        unsafe {
+            sys::ImPlot_GetPlotLimits(&mut limits, IMPLOT_AUTO, IMPLOT_AUTO);
+            sys::ImPlot_GetPlotSize(&mut plot_size);
+            // Only the visible part is submitted, so the whole matrix has to be fitted by hand
+            if sys::ImPlot_FitThisFrame() {
+                sys::ImPlot_FitPoint(self.lower_left);
+                sys::ImPlot_FitPoint(self.upper_right);
+            }
+        }
+        let cell_width = (self.upper_right.x - self.lower_left.x) / cols as f64;
+        let cell_height = (self.upper_right.y - self.lower_left.y) / rows as f64;
+        let visible = [
+            (limits.X.size() / cell_width).min(cols as f64),
+            (limits.Y.size() / cell_height).min(rows as f64),
+        ];
+        let factor = lod_factor(visible, [plot_size.x, plot_size.y]);
+
+        let level = match &mut self.level {
+            Some(level) if level.factor == factor && level.shape == (rows, cols) => level,
+            level => {
+                // Without pooling, the visible cells are copied from the values directly
+                let (pooled, pooled_rows, pooled_cols, range) = if factor == 1 {
+                    let cells = values[..rows * cols].iter().map(|value| value.to_f64());
+                    (Vec::new(), rows, cols, finite_range(cells))
+                } else {
+                    let (pooled, pooled_rows, pooled_cols) =
+                        pool_matrix(values, rows, cols, factor, self.pooling);
+                    let range = finite_range(pooled.iter().copied());
+                    (pooled, pooled_rows, pooled_cols, range)
+                };
+                level.insert(HeatmapLevel {
+                    factor,
+                    shape: (rows, cols),
+                    values: pooled,
+                    rows: pooled_rows,
+                    cols: pooled_cols,
+                    range,
+                })
+            }
+        };
+
+        // Row 0 is drawn at the top, so rows count down from the upper edge
+        let (block_width, block_height) = (cell_width * factor as f64, cell_height * factor as f64);
+        let visible_cols = visible_cells(
+            limits.X.Min - self.lower_left.x,
+            limits.X.Max - self.lower_left.x,
+            block_width,
+            level.cols,
+        );
+        let visible_rows = visible_cells(
+            self.upper_right.y - limits.Y.Max,
+            self.upper_right.y - limits.Y.Min,
+            block_height,
+            level.rows,
+        );
+        if visible_cols.is_empty() || visible_rows.is_empty() {
+            return;
+        }
+        self.window.clear();
+        for row in visible_rows.clone() {
+            let cells = row * level.cols + visible_cols.start..row * level.cols + visible_cols.end;
+            if level.factor == 1 {
+                self.window
+                    .extend(values[cells].iter().map(|value| value.to_f64()));
+            } else {
+                self.window.extend_from_slice(&level.values[cells]);
+            }
+        }
+
+        let (scale_min, scale_max) = self.scale_range.unwrap_or(level.range);
+        // Safety: no format string is used at all
+        unsafe { PlotHeatmap::new(&self.label).with_label_format(None) }
+            .with_scale(scale_min, scale_max)
+            .with_drawing_area(
+                ImPlotPoint {
+                    x: self.lower_left.x + visible_cols.start as f64 * block_width,
+                    y: self.upper_right.y - visible_rows.end as f64 * block_height,
+                },
+                ImPlotPoint {
+                    x: self.lower_left.x + visible_cols.end as f64 * block_width,
+                    y: self.upper_right.y - visible_rows.start as f64 * block_height,
+                },
+            )
+            .plot(
+                &self.window,
+                visible_rows.len() as u32,
+                visible_cols.len() as u32,
+                false,
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_range_interaction() {
+        assert_eq!(pan_scale_range((0.0, 10.0), 20.0, 200.0), (1.0, 11.0));
+        assert_eq!(pan_scale_range((0.0, 10.0), 20.0, 0.0), (0.0, 10.0));
+        let zoomed = zoom_scale_range((0.0, 10.0), 1.0);
+        assert!(zoomed.0 > 0.0 && zoomed.1 < 10.0);
+        assert!((zoomed.0 + zoomed.1 - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_error_band_bounds() {
+        let (lower, upper) = error_band_bounds(&[1.0, 2.0], &[0.5, 0.25]);
+        assert_eq!(lower, vec![0.5, 1.75]);
+        assert_eq!(upper, vec![1.5, 2.25]);
+    }
+
+    #[test]
+    fn test_arrow_head() {
+        let at = |x, y| ImVec2 { x, y };
+        let (head, shaft_end) = arrow_head(at(0.0, 0.0), at(20.0, 0.0), 8.0).unwrap();
+        assert_eq!((shaft_end.x, shaft_end.y), (12.0, 0.0));
+        assert_eq!((head[0].x, head[0].y), (20.0, 0.0));
+        assert_eq!((head[1].x, head[1].y), (12.0, 4.0));
+        assert_eq!((head[2].x, head[2].y), (12.0, -4.0));
+        // Heads are not longer than the arrow
+        let (_, shaft_end) = arrow_head(at(0.0, 0.0), at(0.0, 4.0), 8.0).unwrap();
+        assert_eq!((shaft_end.x, shaft_end.y), (0.0, 0.0));
+        assert!(arrow_head(at(1.0, 1.0), at(1.0, 1.0), 8.0).is_none());
+    }
+
+    #[test]
+    fn test_colormap_position() {
+        assert_eq!(colormap_position(5.0, (0.0, 10.0)), 0.5);
+        assert_eq!(colormap_position(-1.0, (0.0, 10.0)), 0.0);
+        assert_eq!(colormap_position(11.0, (0.0, 10.0)), 1.0);
+        assert_eq!(colormap_position(3.0, (3.0, 3.0)), 0.0);
+    }
+
+    #[test]
+    fn test_pool_matrix() {
+        // 3 by 3, pooled in blocks of 2 by 2 with partial blocks at the edges
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let (mean, rows, cols) = pool_matrix(&values, 3, 3, 2, HeatmapPooling::Mean);
+        assert_eq!((rows, cols), (2, 2));
+        assert_eq!(mean, vec![3.0, 4.5, 7.5, 9.0]);
+        let (max, _, _) = pool_matrix(&values, 3, 3, 2, HeatmapPooling::Max);
+        assert_eq!(max, vec![5.0, 6.0, 8.0, 9.0]);
+        assert_eq!(
+            pool_matrix(&values, 3, 3, 1, HeatmapPooling::Mean).0,
+            values.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_lod_factor_and_visible_cells() {
+        assert_eq!(lod_factor([4096.0, 4096.0], [500.0, 400.0]), 16);
+        assert_eq!(lod_factor([100.0, 50.0], [500.0, 400.0]), 1);
+        assert_eq!(lod_factor([1000.0, 10.0], [500.0, 0.0]), 16);
+        assert_eq!(visible_cells(-1.0, 2.5, 1.0, 10), 0..3);
+        assert_eq!(visible_cells(3.5, 20.0, 2.0, 5), 1..5);
+        assert!(visible_cells(12.0, 20.0, 1.0, 10).is_empty());
+    }
+
+    #[test]
+    fn test_waterfall_bars() {
+        let bars = waterfall_bars(&[
+            WaterfallStep::Delta(10.0),
+            WaterfallStep::Delta(-4.0),
+            WaterfallStep::Total,
+        ]);
+        assert_eq!(
+            bars,
+            vec![
+                WaterfallBar {
+                    start: 0.0,
+                    end: 10.0,
+                    kind: WaterfallBarKind::Increase
+                },
+                WaterfallBar {
+                    start: 10.0,
+                    end: 6.0,
+                    kind: WaterfallBarKind::Decrease
+                },
+                WaterfallBar {
+                    start: 0.0,
+                    end: 6.0,
+                    kind: WaterfallBarKind::Total
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,254 @@
+//! # Retained-mode module
+//!
+//! This module defines `PlotModel`, an optional retained layer on top of the immediate-mode API.
+//! Series data, styling and the plot configuration are registered once and stored in the form
+//! the C++ side needs, so rendering an unchanged model each frame is a single call that does no
+//! label or data conversion work. This is mostly useful for dashboards with many plots whose
+//! data only changes occasionally.
+
+use crate::intern::intern;
+use crate::{Plot, PlotBars, PlotLine, PlotScatter, PlotStairs, PlotStems, PlotUi};
+use implot_sys::ImVec4;
+use std::ffi::CStr;
+use std::sync::Arc;
+
+/// The kind of plot element a series of a [`PlotModel`] is drawn as.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SeriesKind {
+    Line,
+    Scatter,
+    Stairs,
+    /// Vertical bars of the given width, in plot coordinate terms
+    Bars(f64),
+    Stems,
+}
+
+/// Handle to a series registered with a [`PlotModel`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SeriesId(usize);
+
+/// A series registered with a [`PlotModel`]: its data in plot-ready form, and the label and
+/// style its element is prepared from.
+struct Series {
+    label: Arc<CStr>,
+    kind: SeriesKind,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    color: Option<ImVec4>,
+    line_weight: Option<f32>,
+}
+
+/// Applies the color and line weight of a series to an element.
+macro_rules! with_series_style {
+    ($element:expr, $series:expr) => {{
+        let mut element = $element;
+        if let Some(color) = $series.color {
+            element = element.with_color([color.x, color.y, color.z, color.w]);
+        }
+        if let Some(weight) = $series.line_weight {
+            element = element.with_line_weight(weight);
+        }
+        element
+    }};
+}
+
+/// Plot element prepared from a series, so its label and style do not have to be converted
+/// every frame.
+enum SeriesElement {
+    Line(PlotLine),
+    Scatter(PlotScatter),
+    Stairs(PlotStairs),
+    Bars(PlotBars),
+    Stems(PlotStems),
+}
+
+impl SeriesElement {
+    fn new(series: &Series) -> Self {
+        // Labels are interned, so this finds the already converted label
+        let label = series.label.to_str().unwrap_or_default();
+        match series.kind {
+            SeriesKind::Line => {
+                SeriesElement::Line(with_series_style!(PlotLine::new(label), series))
+            }
+            SeriesKind::Scatter => {
+                SeriesElement::Scatter(with_series_style!(PlotScatter::new(label), series))
+            }
+            SeriesKind::Stairs => {
+                SeriesElement::Stairs(with_series_style!(PlotStairs::new(label), series))
+            }
+            SeriesKind::Bars(width) => SeriesElement::Bars(with_series_style!(
+                PlotBars::new(label).with_bar_width(width),
+                series
+            )),
+            SeriesKind::Stems => {
+                SeriesElement::Stems(with_series_style!(PlotStems::new(label), series))
+            }
+        }
+    }
+
+    fn plot(&self, x: &[f64], y: &[f64]) {
+        match self {
+            SeriesElement::Line(line) => line.plot(x, y),
+            SeriesElement::Scatter(scatter) => scatter.plot(x, y),
+            SeriesElement::Stairs(stairs) => stairs.plot(x, y),
+            SeriesElement::Bars(bars) => bars.plot(x, y, false),
+            SeriesElement::Stems(stems) => stems.plot(x, y, false),
+        }
+    }
+}
+
+/// A retained plot: configuration and series are registered up front and the whole plot is
+/// then drawn each frame with [`PlotModel::render`]:
+/// ```no_run
+/// # use implot::{Context, Plot, PlotModel, SeriesKind};
+/// # let context = Context::create();
+/// # let plot_ui = context.get_plot_ui();
+/// let mut model = PlotModel::new(Plot::new("Dashboard").x_label("time"));
+/// let prices = model.add_series("prices", SeriesKind::Line);
+/// model.set_series_data(prices, vec![0.0, 1.0, 2.0], vec![10.0, 11.5, 9.8]);
+///
+/// // Every frame:
+/// model.render(&plot_ui);
+/// ```
+pub struct PlotModel {
+    plot: Plot,
+    series: Vec<Series>,
+    /// Elements prepared from `series`, rebuilt by `render` only when the model is dirty
+    elements: Vec<SeriesElement>,
+    /// Whether anything changed since the last call to `render`
+    dirty: bool,
+}
+
+impl PlotModel {
+    /// Create a model drawing into a plot with the given configuration.
+    pub fn new(plot: Plot) -> Self {
+        Self {
+            plot,
+            series: Vec::new(),
+            elements: Vec::new(),
+            dirty: true,
+        }
+    }
+
+    /// Replace the plot configuration (axes, labels, flags, ...).
+    pub fn set_plot(&mut self, plot: Plot) {
+        self.plot = plot;
+        self.dirty = true;
+    }
+
+    /// Register a new, initially empty series. Returns a handle used to update it later.
+    ///
+    /// # Panics
+    /// Will panic if the label string contains internal null bytes.
+    pub fn add_series(&mut self, label: &str, kind: SeriesKind) -> SeriesId {
+        self.series.push(Series {
+            label: intern(label),
+            kind,
+            x: Vec::new(),
+            y: Vec::new(),
+            color: None,
+            line_weight: None,
+        });
+        self.dirty = true;
+        SeriesId(self.series.len() - 1)
+    }
+
+    /// Replace the data of a series. Any conversion of the data into `f64` happens here, and
+    /// not on every frame.
+    pub fn set_series_data<X, Y>(&mut self, id: SeriesId, x: X, y: Y)
+    where
+        X: IntoIterator,
+        X::Item: Into<f64>,
+        Y: IntoIterator,
+        Y::Item: Into<f64>,
+    {
+        let series = &mut self.series[id.0];
+        series.x.clear();
+        series.x.extend(x.into_iter().map(Into::into));
+        series.y.clear();
+        series.y.extend(y.into_iter().map(Into::into));
+        self.dirty = true;
+    }
+
+    /// Append data points to a series, e.g. for streaming data.
+    pub fn extend_series_data(&mut self, id: SeriesId, points: &[(f64, f64)]) {
+        let series = &mut self.series[id.0];
+        series.x.extend(points.iter().map(|point| point.0));
+        series.y.extend(points.iter().map(|point| point.1));
+        self.dirty = true;
+    }
+
+    /// Set the color of a series, or `None` to use the colormap's automatic color.
+    pub fn set_series_color(&mut self, id: SeriesId, color: Option<ImVec4>) {
+        self.series[id.0].color = color;
+        self.dirty = true;
+    }
+
+    /// Set the line weight of a series, or `None` to use the style's line weight.
+    pub fn set_series_line_weight(&mut self, id: SeriesId, weight: Option<f32>) {
+        self.series[id.0].line_weight = weight;
+        self.dirty = true;
+    }
+
+    /// Remove all series from the model.
+    pub fn clear_series(&mut self) {
+        self.series.clear();
+        self.dirty = true;
+    }
+
+    /// Returns true if the model changed since the last call to [`PlotModel::render`]. Useful
+    /// for deciding whether dependent work (e.g. exporting) has to be redone.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Draw the plot and all of its series. Call this once per frame, within an imgui window.
+    pub fn render(&mut self, plot_ui: &PlotUi) {
+        self.prepare();
+        if let Some(token) = self.plot.begin(plot_ui) {
+            self.series
+                .iter()
+                .zip(&self.elements)
+                .for_each(|(series, element)| element.plot(&series.x, &series.y));
+            token.end();
+        }
+    }
+
+    /// Rebuild the prepared elements if anything changed since they were last built.
+    fn prepare(&mut self) {
+        if self.dirty {
+            self.elements = self.series.iter().map(SeriesElement::new).collect();
+            self.dirty = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prepare_only_when_dirty() {
+        let mut model = PlotModel::new(Plot::new("Model"));
+        let line = model.add_series("line", SeriesKind::Line);
+        model.add_series("bars", SeriesKind::Bars(0.5));
+        assert!(model.is_dirty());
+
+        model.prepare();
+        assert!(!model.is_dirty());
+        assert_eq!(model.elements.len(), 2);
+        assert!(matches!(model.elements[0], SeriesElement::Line(_)));
+        assert!(matches!(model.elements[1], SeriesElement::Bars(_)));
+
+        // Without any change, the prepared elements are kept as they are
+        model.series.truncate(1);
+        model.prepare();
+        assert_eq!(model.elements.len(), 2);
+
+        model.set_series_line_weight(line, Some(2.0));
+        assert!(model.is_dirty());
+        model.prepare();
+        assert!(!model.is_dirty());
+        assert_eq!(model.elements.len(), 1);
+    }
+}
@@ -0,0 +1,862 @@
+//! # Scalar module
+//!
+//! This module defines [`PlotScalar`], the trait for the value types the plot elements accept.
+//! ImPlot has a plotting function per value type, so data stored as e.g. `i16` or `f32` can be
+//! plotted as it is, without converting it to `f64` every frame:
+//! ```no_run
+//! # use implot::PlotLine;
+//! let samples: Vec<i16> = vec![0, 120, -340, 20];
+//! let indices: Vec<i16> = (0..samples.len() as i16).collect();
+//! PlotLine::new("adc").plot(&indices, &samples);
+//! ```
+
+use crate::{sys, ImPlotPoint, ImPlotRange};
+use std::os::raw::{c_char, c_int, c_void};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Value types that can be plotted: `f32`, `f64` and the 8 to 64 bit integers. This trait is
+/// sealed, its functions dispatch to the ImPlot function for the type and are not meant to be
+/// called directly.
+pub trait PlotScalar: Copy + private::Sealed {
+    /// Convert the value, e.g. for computing scale ranges.
+    fn to_f64(self) -> f64;
+
+    #[doc(hidden)]
+    unsafe fn plot_line(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: c_int,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    unsafe fn plot_stairs(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: c_int,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    unsafe fn plot_scatter(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: c_int,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    unsafe fn plot_digital(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: c_int,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_bars(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: c_int,
+        bar_size: f64,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    unsafe fn plot_bar_groups(
+        labels: *const *const c_char,
+        values: *const Self,
+        item_count: c_int,
+        group_count: c_int,
+        group_size: f64,
+        shift: f64,
+        flags: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_stems(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: c_int,
+        reference: f64,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_shaded(
+        label: *const c_char,
+        xs: *const Self,
+        ys1: *const Self,
+        ys2: *const Self,
+        count: c_int,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_shaded_to_ref(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        count: c_int,
+        y_ref: f64,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_error_bars(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        errors: *const Self,
+        count: c_int,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_error_bars_asymmetric(
+        label: *const c_char,
+        xs: *const Self,
+        ys: *const Self,
+        negative: *const Self,
+        positive: *const Self,
+        count: c_int,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_heatmap(
+        label: *const c_char,
+        values: *const Self,
+        rows: c_int,
+        cols: c_int,
+        scale_min: f64,
+        scale_max: f64,
+        label_format: *const c_char,
+        bounds_min: ImPlotPoint,
+        bounds_max: ImPlotPoint,
+        flags: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_histogram(
+        label: *const c_char,
+        values: *const Self,
+        count: c_int,
+        bins: c_int,
+        bar_scale: f64,
+        range: ImPlotRange,
+        flags: c_int,
+    ) -> f64;
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_pie_chart(
+        labels: *const *const c_char,
+        values: *const Self,
+        count: c_int,
+        x: f64,
+        y: f64,
+        radius: f64,
+        label_format: *const c_char,
+        angle0: f64,
+        flags: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_pie_chart_with_formatter(
+        labels: *const *const c_char,
+        values: *const Self,
+        count: c_int,
+        x: f64,
+        y: f64,
+        radius: f64,
+        formatter: sys::ImPlotFormatter,
+        formatter_data: *mut c_void,
+        angle0: f64,
+        flags: c_int,
+    );
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_line_values(
+        label: *const c_char,
+        values: *const Self,
+        count: c_int,
+        xscale: f64,
+        xstart: f64,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_stairs_values(
+        label: *const c_char,
+        values: *const Self,
+        count: c_int,
+        xscale: f64,
+        xstart: f64,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_scatter_values(
+        label: *const c_char,
+        values: *const Self,
+        count: c_int,
+        xscale: f64,
+        xstart: f64,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_bars_values(
+        label: *const c_char,
+        values: *const Self,
+        count: c_int,
+        bar_size: f64,
+        shift: f64,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn plot_shaded_values(
+        label: *const c_char,
+        values: *const Self,
+        count: c_int,
+        y_ref: f64,
+        xscale: f64,
+        xstart: f64,
+        flags: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+}
+
+// Implements the trait for one type, given the ImPlot functions for it in the order of the
+// trait functions.
+macro_rules! impl_plot_scalar {
+    (
+        $type:ty,
+        $line:ident,
+        $stairs:ident,
+        $scatter:ident,
+        $digital:ident,
+        $bars:ident,
+        $bar_groups:ident,
+        $stems:ident,
+        $shaded:ident,
+        $shaded_to_ref:ident,
+        $error_bars:ident,
+        $error_bars_asymmetric:ident,
+        $heatmap:ident,
+        $histogram:ident,
+        $pie_chart:ident,
+        $pie_chart_with_formatter:ident,
+        $line_values:ident,
+        $stairs_values:ident,
+        $scatter_values:ident,
+        $bars_values:ident,
+        $shaded_values:ident,
+    ) => {
+        impl private::Sealed for $type {}
+
+        impl PlotScalar for $type {
+            #[allow(clippy::unnecessary_cast)]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            unsafe fn plot_line(
+                label: *const c_char,
+                xs: *const Self,
+                ys: *const Self,
+                count: c_int,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$line(label, xs, ys, count, flags, offset, stride)
+            }
+
+            unsafe fn plot_stairs(
+                label: *const c_char,
+                xs: *const Self,
+                ys: *const Self,
+                count: c_int,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$stairs(label, xs, ys, count, flags, offset, stride)
+            }
+
+            unsafe fn plot_scatter(
+                label: *const c_char,
+                xs: *const Self,
+                ys: *const Self,
+                count: c_int,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$scatter(label, xs, ys, count, flags, offset, stride)
+            }
+
+            unsafe fn plot_digital(
+                label: *const c_char,
+                xs: *const Self,
+                ys: *const Self,
+                count: c_int,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$digital(label, xs, ys, count, flags, offset, stride)
+            }
+
+            unsafe fn plot_bars(
+                label: *const c_char,
+                xs: *const Self,
+                ys: *const Self,
+                count: c_int,
+                bar_size: f64,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$bars(label, xs, ys, count, bar_size, flags, offset, stride)
+            }
+
+            unsafe fn plot_bar_groups(
+                labels: *const *const c_char,
+                values: *const Self,
+                item_count: c_int,
+                group_count: c_int,
+                group_size: f64,
+                shift: f64,
+                flags: c_int,
+            ) {
+                sys::$bar_groups(
+                    labels,
+                    values,
+                    item_count,
+                    group_count,
+                    group_size,
+                    shift,
+                    flags,
+                )
+            }
+
+            unsafe fn plot_stems(
+                label: *const c_char,
+                xs: *const Self,
+                ys: *const Self,
+                count: c_int,
+                reference: f64,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$stems(label, xs, ys, count, reference, flags, offset, stride)
+            }
+
+            unsafe fn plot_shaded(
+                label: *const c_char,
+                xs: *const Self,
+                ys1: *const Self,
+                ys2: *const Self,
+                count: c_int,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$shaded(label, xs, ys1, ys2, count, flags, offset, stride)
+            }
+
+            unsafe fn plot_shaded_to_ref(
+                label: *const c_char,
+                xs: *const Self,
+                ys: *const Self,
+                count: c_int,
+                y_ref: f64,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$shaded_to_ref(label, xs, ys, count, y_ref, flags, offset, stride)
+            }
+
+            unsafe fn plot_error_bars(
+                label: *const c_char,
+                xs: *const Self,
+                ys: *const Self,
+                errors: *const Self,
+                count: c_int,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$error_bars(label, xs, ys, errors, count, flags, offset, stride)
+            }
+
+            unsafe fn plot_error_bars_asymmetric(
+                label: *const c_char,
+                xs: *const Self,
+                ys: *const Self,
+                negative: *const Self,
+                positive: *const Self,
+                count: c_int,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$error_bars_asymmetric(
+                    label, xs, ys, negative, positive, count, flags, offset, stride,
+                )
+            }
+
+            unsafe fn plot_heatmap(
+                label: *const c_char,
+                values: *const Self,
+                rows: c_int,
+                cols: c_int,
+                scale_min: f64,
+                scale_max: f64,
+                label_format: *const c_char,
+                bounds_min: ImPlotPoint,
+                bounds_max: ImPlotPoint,
+                flags: c_int,
+            ) {
+                sys::$heatmap(
+                    label,
+                    values,
+                    rows,
+                    cols,
+                    scale_min,
+                    scale_max,
+                    label_format,
+                    bounds_min,
+                    bounds_max,
+                    flags,
+                )
+            }
+
+            unsafe fn plot_histogram(
+                label: *const c_char,
+                values: *const Self,
+                count: c_int,
+                bins: c_int,
+                bar_scale: f64,
+                range: ImPlotRange,
+                flags: c_int,
+            ) -> f64 {
+                sys::$histogram(label, values, count, bins, bar_scale, range, flags)
+            }
+
+            unsafe fn plot_pie_chart(
+                labels: *const *const c_char,
+                values: *const Self,
+                count: c_int,
+                x: f64,
+                y: f64,
+                radius: f64,
+                label_format: *const c_char,
+                angle0: f64,
+                flags: c_int,
+            ) {
+                sys::$pie_chart(
+                    labels,
+                    values,
+                    count,
+                    x,
+                    y,
+                    radius,
+                    label_format,
+                    angle0,
+                    flags,
+                )
+            }
+
+            unsafe fn plot_pie_chart_with_formatter(
+                labels: *const *const c_char,
+                values: *const Self,
+                count: c_int,
+                x: f64,
+                y: f64,
+                radius: f64,
+                formatter: sys::ImPlotFormatter,
+                formatter_data: *mut c_void,
+                angle0: f64,
+                flags: c_int,
+            ) {
+                sys::$pie_chart_with_formatter(
+                    labels,
+                    values,
+                    count,
+                    x,
+                    y,
+                    radius,
+                    formatter,
+                    formatter_data,
+                    angle0,
+                    flags,
+                )
+            }
+            unsafe fn plot_line_values(
+                label: *const c_char,
+                values: *const Self,
+                count: c_int,
+                xscale: f64,
+                xstart: f64,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$line_values(label, values, count, xscale, xstart, flags, offset, stride)
+            }
+
+            unsafe fn plot_stairs_values(
+                label: *const c_char,
+                values: *const Self,
+                count: c_int,
+                xscale: f64,
+                xstart: f64,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$stairs_values(label, values, count, xscale, xstart, flags, offset, stride)
+            }
+
+            unsafe fn plot_scatter_values(
+                label: *const c_char,
+                values: *const Self,
+                count: c_int,
+                xscale: f64,
+                xstart: f64,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$scatter_values(label, values, count, xscale, xstart, flags, offset, stride)
+            }
+
+            unsafe fn plot_bars_values(
+                label: *const c_char,
+                values: *const Self,
+                count: c_int,
+                bar_size: f64,
+                shift: f64,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$bars_values(label, values, count, bar_size, shift, flags, offset, stride)
+            }
+
+            unsafe fn plot_shaded_values(
+                label: *const c_char,
+                values: *const Self,
+                count: c_int,
+                y_ref: f64,
+                xscale: f64,
+                xstart: f64,
+                flags: c_int,
+                offset: c_int,
+                stride: c_int,
+            ) {
+                sys::$shaded_values(
+                    label, values, count, y_ref, xscale, xstart, flags, offset, stride,
+                )
+            }
+        }
+    };
+}
+
+impl_plot_scalar!(
+    f32,
+    ImPlot_PlotLine_FloatPtrFloatPtr,
+    ImPlot_PlotStairs_FloatPtrFloatPtr,
+    ImPlot_PlotScatter_FloatPtrFloatPtr,
+    ImPlot_PlotDigital_FloatPtr,
+    ImPlot_PlotBars_FloatPtrFloatPtr,
+    ImPlot_PlotBarGroups_FloatPtr,
+    ImPlot_PlotStems_FloatPtrFloatPtr,
+    ImPlot_PlotShaded_FloatPtrFloatPtrFloatPtr,
+    ImPlot_PlotShaded_FloatPtrFloatPtrInt,
+    ImPlot_PlotErrorBars_FloatPtrFloatPtrFloatPtrInt,
+    ImPlot_PlotErrorBars_FloatPtrFloatPtrFloatPtrFloatPtr,
+    ImPlot_PlotHeatmap_FloatPtr,
+    ImPlot_PlotHistogram_FloatPtr,
+    ImPlot_PlotPieChart_FloatPtrStr,
+    ImPlot_PlotPieChart_FloatPtrPlotFormatter,
+    ImPlot_PlotLine_FloatPtrInt,
+    ImPlot_PlotStairs_FloatPtrInt,
+    ImPlot_PlotScatter_FloatPtrInt,
+    ImPlot_PlotBars_FloatPtrInt,
+    ImPlot_PlotShaded_FloatPtrInt,
+);
+
+impl_plot_scalar!(
+    f64,
+    ImPlot_PlotLine_doublePtrdoublePtr,
+    ImPlot_PlotStairs_doublePtrdoublePtr,
+    ImPlot_PlotScatter_doublePtrdoublePtr,
+    ImPlot_PlotDigital_doublePtr,
+    ImPlot_PlotBars_doublePtrdoublePtr,
+    ImPlot_PlotBarGroups_doublePtr,
+    ImPlot_PlotStems_doublePtrdoublePtr,
+    ImPlot_PlotShaded_doublePtrdoublePtrdoublePtr,
+    ImPlot_PlotShaded_doublePtrdoublePtrInt,
+    ImPlot_PlotErrorBars_doublePtrdoublePtrdoublePtrInt,
+    ImPlot_PlotErrorBars_doublePtrdoublePtrdoublePtrdoublePtr,
+    ImPlot_PlotHeatmap_doublePtr,
+    ImPlot_PlotHistogram_doublePtr,
+    ImPlot_PlotPieChart_doublePtrStr,
+    ImPlot_PlotPieChart_doublePtrPlotFormatter,
+    ImPlot_PlotLine_doublePtrInt,
+    ImPlot_PlotStairs_doublePtrInt,
+    ImPlot_PlotScatter_doublePtrInt,
+    ImPlot_PlotBars_doublePtrInt,
+    ImPlot_PlotShaded_doublePtrInt,
+);
+
+impl_plot_scalar!(
+    i8,
+    ImPlot_PlotLine_S8PtrS8Ptr,
+    ImPlot_PlotStairs_S8PtrS8Ptr,
+    ImPlot_PlotScatter_S8PtrS8Ptr,
+    ImPlot_PlotDigital_S8Ptr,
+    ImPlot_PlotBars_S8PtrS8Ptr,
+    ImPlot_PlotBarGroups_S8Ptr,
+    ImPlot_PlotStems_S8PtrS8Ptr,
+    ImPlot_PlotShaded_S8PtrS8PtrS8Ptr,
+    ImPlot_PlotShaded_S8PtrS8PtrInt,
+    ImPlot_PlotErrorBars_S8PtrS8PtrS8PtrInt,
+    ImPlot_PlotErrorBars_S8PtrS8PtrS8PtrS8Ptr,
+    ImPlot_PlotHeatmap_S8Ptr,
+    ImPlot_PlotHistogram_S8Ptr,
+    ImPlot_PlotPieChart_S8PtrStr,
+    ImPlot_PlotPieChart_S8PtrPlotFormatter,
+    ImPlot_PlotLine_S8PtrInt,
+    ImPlot_PlotStairs_S8PtrInt,
+    ImPlot_PlotScatter_S8PtrInt,
+    ImPlot_PlotBars_S8PtrInt,
+    ImPlot_PlotShaded_S8PtrInt,
+);
+
+impl_plot_scalar!(
+    u8,
+    ImPlot_PlotLine_U8PtrU8Ptr,
+    ImPlot_PlotStairs_U8PtrU8Ptr,
+    ImPlot_PlotScatter_U8PtrU8Ptr,
+    ImPlot_PlotDigital_U8Ptr,
+    ImPlot_PlotBars_U8PtrU8Ptr,
+    ImPlot_PlotBarGroups_U8Ptr,
+    ImPlot_PlotStems_U8PtrU8Ptr,
+    ImPlot_PlotShaded_U8PtrU8PtrU8Ptr,
+    ImPlot_PlotShaded_U8PtrU8PtrInt,
+    ImPlot_PlotErrorBars_U8PtrU8PtrU8PtrInt,
+    ImPlot_PlotErrorBars_U8PtrU8PtrU8PtrU8Ptr,
+    ImPlot_PlotHeatmap_U8Ptr,
+    ImPlot_PlotHistogram_U8Ptr,
+    ImPlot_PlotPieChart_U8PtrStr,
+    ImPlot_PlotPieChart_U8PtrPlotFormatter,
+    ImPlot_PlotLine_U8PtrInt,
+    ImPlot_PlotStairs_U8PtrInt,
+    ImPlot_PlotScatter_U8PtrInt,
+    ImPlot_PlotBars_U8PtrInt,
+    ImPlot_PlotShaded_U8PtrInt,
+);
+
+impl_plot_scalar!(
+    i16,
+    ImPlot_PlotLine_S16PtrS16Ptr,
+    ImPlot_PlotStairs_S16PtrS16Ptr,
+    ImPlot_PlotScatter_S16PtrS16Ptr,
+    ImPlot_PlotDigital_S16Ptr,
+    ImPlot_PlotBars_S16PtrS16Ptr,
+    ImPlot_PlotBarGroups_S16Ptr,
+    ImPlot_PlotStems_S16PtrS16Ptr,
+    ImPlot_PlotShaded_S16PtrS16PtrS16Ptr,
+    ImPlot_PlotShaded_S16PtrS16PtrInt,
+    ImPlot_PlotErrorBars_S16PtrS16PtrS16PtrInt,
+    ImPlot_PlotErrorBars_S16PtrS16PtrS16PtrS16Ptr,
+    ImPlot_PlotHeatmap_S16Ptr,
+    ImPlot_PlotHistogram_S16Ptr,
+    ImPlot_PlotPieChart_S16PtrStr,
+    ImPlot_PlotPieChart_S16PtrPlotFormatter,
+    ImPlot_PlotLine_S16PtrInt,
+    ImPlot_PlotStairs_S16PtrInt,
+    ImPlot_PlotScatter_S16PtrInt,
+    ImPlot_PlotBars_S16PtrInt,
+    ImPlot_PlotShaded_S16PtrInt,
+);
+
+impl_plot_scalar!(
+    u16,
+    ImPlot_PlotLine_U16PtrU16Ptr,
+    ImPlot_PlotStairs_U16PtrU16Ptr,
+    ImPlot_PlotScatter_U16PtrU16Ptr,
+    ImPlot_PlotDigital_U16Ptr,
+    ImPlot_PlotBars_U16PtrU16Ptr,
+    ImPlot_PlotBarGroups_U16Ptr,
+    ImPlot_PlotStems_U16PtrU16Ptr,
+    ImPlot_PlotShaded_U16PtrU16PtrU16Ptr,
+    ImPlot_PlotShaded_U16PtrU16PtrInt,
+    ImPlot_PlotErrorBars_U16PtrU16PtrU16PtrInt,
+    ImPlot_PlotErrorBars_U16PtrU16PtrU16PtrU16Ptr,
+    ImPlot_PlotHeatmap_U16Ptr,
+    ImPlot_PlotHistogram_U16Ptr,
+    ImPlot_PlotPieChart_U16PtrStr,
+    ImPlot_PlotPieChart_U16PtrPlotFormatter,
+    ImPlot_PlotLine_U16PtrInt,
+    ImPlot_PlotStairs_U16PtrInt,
+    ImPlot_PlotScatter_U16PtrInt,
+    ImPlot_PlotBars_U16PtrInt,
+    ImPlot_PlotShaded_U16PtrInt,
+);
+
+impl_plot_scalar!(
+    i32,
+    ImPlot_PlotLine_S32PtrS32Ptr,
+    ImPlot_PlotStairs_S32PtrS32Ptr,
+    ImPlot_PlotScatter_S32PtrS32Ptr,
+    ImPlot_PlotDigital_S32Ptr,
+    ImPlot_PlotBars_S32PtrS32Ptr,
+    ImPlot_PlotBarGroups_S32Ptr,
+    ImPlot_PlotStems_S32PtrS32Ptr,
+    ImPlot_PlotShaded_S32PtrS32PtrS32Ptr,
+    ImPlot_PlotShaded_S32PtrS32PtrInt,
+    ImPlot_PlotErrorBars_S32PtrS32PtrS32PtrInt,
+    ImPlot_PlotErrorBars_S32PtrS32PtrS32PtrS32Ptr,
+    ImPlot_PlotHeatmap_S32Ptr,
+    ImPlot_PlotHistogram_S32Ptr,
+    ImPlot_PlotPieChart_S32PtrStr,
+    ImPlot_PlotPieChart_S32PtrPlotFormatter,
+    ImPlot_PlotLine_S32PtrInt,
+    ImPlot_PlotStairs_S32PtrInt,
+    ImPlot_PlotScatter_S32PtrInt,
+    ImPlot_PlotBars_S32PtrInt,
+    ImPlot_PlotShaded_S32PtrInt,
+);
+
+impl_plot_scalar!(
+    u32,
+    ImPlot_PlotLine_U32PtrU32Ptr,
+    ImPlot_PlotStairs_U32PtrU32Ptr,
+    ImPlot_PlotScatter_U32PtrU32Ptr,
+    ImPlot_PlotDigital_U32Ptr,
+    ImPlot_PlotBars_U32PtrU32Ptr,
+    ImPlot_PlotBarGroups_U32Ptr,
+    ImPlot_PlotStems_U32PtrU32Ptr,
+    ImPlot_PlotShaded_U32PtrU32PtrU32Ptr,
+    ImPlot_PlotShaded_U32PtrU32PtrInt,
+    ImPlot_PlotErrorBars_U32PtrU32PtrU32PtrInt,
+    ImPlot_PlotErrorBars_U32PtrU32PtrU32PtrU32Ptr,
+    ImPlot_PlotHeatmap_U32Ptr,
+    ImPlot_PlotHistogram_U32Ptr,
+    ImPlot_PlotPieChart_U32PtrStr,
+    ImPlot_PlotPieChart_U32PtrPlotFormatter,
+    ImPlot_PlotLine_U32PtrInt,
+    ImPlot_PlotStairs_U32PtrInt,
+    ImPlot_PlotScatter_U32PtrInt,
+    ImPlot_PlotBars_U32PtrInt,
+    ImPlot_PlotShaded_U32PtrInt,
+);
+
+impl_plot_scalar!(
+    i64,
+    ImPlot_PlotLine_S64PtrS64Ptr,
+    ImPlot_PlotStairs_S64PtrS64Ptr,
+    ImPlot_PlotScatter_S64PtrS64Ptr,
+    ImPlot_PlotDigital_S64Ptr,
+    ImPlot_PlotBars_S64PtrS64Ptr,
+    ImPlot_PlotBarGroups_S64Ptr,
+    ImPlot_PlotStems_S64PtrS64Ptr,
+    ImPlot_PlotShaded_S64PtrS64PtrS64Ptr,
+    ImPlot_PlotShaded_S64PtrS64PtrInt,
+    ImPlot_PlotErrorBars_S64PtrS64PtrS64PtrInt,
+    ImPlot_PlotErrorBars_S64PtrS64PtrS64PtrS64Ptr,
+    ImPlot_PlotHeatmap_S64Ptr,
+    ImPlot_PlotHistogram_S64Ptr,
+    ImPlot_PlotPieChart_S64PtrStr,
+    ImPlot_PlotPieChart_S64PtrPlotFormatter,
+    ImPlot_PlotLine_S64PtrInt,
+    ImPlot_PlotStairs_S64PtrInt,
+    ImPlot_PlotScatter_S64PtrInt,
+    ImPlot_PlotBars_S64PtrInt,
+    ImPlot_PlotShaded_S64PtrInt,
+);
+
+impl_plot_scalar!(
+    u64,
+    ImPlot_PlotLine_U64PtrU64Ptr,
+    ImPlot_PlotStairs_U64PtrU64Ptr,
+    ImPlot_PlotScatter_U64PtrU64Ptr,
+    ImPlot_PlotDigital_U64Ptr,
+    ImPlot_PlotBars_U64PtrU64Ptr,
+    ImPlot_PlotBarGroups_U64Ptr,
+    ImPlot_PlotStems_U64PtrU64Ptr,
+    ImPlot_PlotShaded_U64PtrU64PtrU64Ptr,
+    ImPlot_PlotShaded_U64PtrU64PtrInt,
+    ImPlot_PlotErrorBars_U64PtrU64PtrU64PtrInt,
+    ImPlot_PlotErrorBars_U64PtrU64PtrU64PtrU64Ptr,
+    ImPlot_PlotHeatmap_U64Ptr,
+    ImPlot_PlotHistogram_U64Ptr,
+    ImPlot_PlotPieChart_U64PtrStr,
+    ImPlot_PlotPieChart_U64PtrPlotFormatter,
+    ImPlot_PlotLine_U64PtrInt,
+    ImPlot_PlotStairs_U64PtrInt,
+    ImPlot_PlotScatter_U64PtrInt,
+    ImPlot_PlotBars_U64PtrInt,
+    ImPlot_PlotShaded_U64PtrInt,
+);
@@ -0,0 +1,200 @@
+//! Serde helpers for the imgui and ImPlot types used in serializable structs, which don't
+//! implement `Serialize` and `Deserialize` themselves. Vectors are written as arrays, markers
+//! and mouse buttons by name. They are used through `#[serde(with = "...")]` attributes.
+
+use crate::{ImVec2, ImVec4, Marker};
+use imgui::MouseButton;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub(crate) mod vec2 {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &ImVec2,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        [value.x, value.y].serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ImVec2, D::Error> {
+        let [x, y] = <[f32; 2]>::deserialize(deserializer)?;
+        Ok(ImVec2 { x, y })
+    }
+}
+
+fn to_array(color: &ImVec4) -> [f32; 4] {
+    [color.x, color.y, color.z, color.w]
+}
+
+fn from_array([x, y, z, w]: [f32; 4]) -> ImVec4 {
+    ImVec4 { x, y, z, w }
+}
+
+/// Colors as a sequence of `[r, g, b, a]` arrays, for both fixed-size arrays and vectors.
+pub(crate) mod colors {
+    use super::*;
+    use std::convert::TryInto;
+
+    pub(crate) fn serialize<S: Serializer>(
+        colors: &[ImVec4],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(colors.iter().map(to_array))
+    }
+
+    pub(crate) fn deserialize<'de, D, C>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+        C: FromColors,
+    {
+        let colors: Vec<ImVec4> = Vec::<[f32; 4]>::deserialize(deserializer)?
+            .into_iter()
+            .map(from_array)
+            .collect();
+        C::from_colors(colors).map_err(D::Error::custom)
+    }
+
+    /// Containers colors can be deserialized into.
+    pub(crate) trait FromColors: Sized {
+        fn from_colors(colors: Vec<ImVec4>) -> Result<Self, String>;
+    }
+
+    impl FromColors for Vec<ImVec4> {
+        fn from_colors(colors: Vec<ImVec4>) -> Result<Self, String> {
+            Ok(colors)
+        }
+    }
+
+    impl<const N: usize> FromColors for [ImVec4; N] {
+        fn from_colors(colors: Vec<ImVec4>) -> Result<Self, String> {
+            let count = colors.len();
+            colors
+                .try_into()
+                .map_err(|_| format!("expected {} colors, got {}", N, count))
+        }
+    }
+}
+
+pub(crate) mod marker {
+    use super::*;
+
+    const NAMES: [(Marker, &str); 11] = [
+        (Marker::None, "none"),
+        (Marker::Circle, "circle"),
+        (Marker::Square, "square"),
+        (Marker::Diamond, "diamond"),
+        (Marker::Up, "up"),
+        (Marker::Down, "down"),
+        (Marker::Left, "left"),
+        (Marker::Right, "right"),
+        (Marker::Cross, "cross"),
+        (Marker::Plus, "plus"),
+        (Marker::Asterisk, "asterisk"),
+    ];
+
+    /// Writes the name of a raw ImPlot marker, or `none` for values that are no marker.
+    pub(crate) fn serialize<S: Serializer>(marker: &i32, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = NAMES
+            .iter()
+            .find(|(known, _)| *known as i32 == *marker)
+            .map_or("none", |(_, name)| name);
+        serializer.serialize_str(name)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i32, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        NAMES
+            .iter()
+            .find(|(_, known)| *known == name)
+            .map(|(marker, _)| *marker as i32)
+            .ok_or_else(|| D::Error::custom(format!("unknown marker: {}", name)))
+    }
+}
+
+pub(crate) mod mouse_button {
+    use super::*;
+
+    const NAMES: [(MouseButton, &str); 5] = [
+        (MouseButton::Left, "left"),
+        (MouseButton::Right, "right"),
+        (MouseButton::Middle, "middle"),
+        (MouseButton::Extra1, "extra1"),
+        (MouseButton::Extra2, "extra2"),
+    ];
+
+    pub(crate) fn serialize<S: Serializer>(
+        button: &MouseButton,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let name = NAMES
+            .iter()
+            .find(|(known, _)| known == button)
+            .map_or("left", |(_, name)| name);
+        serializer.serialize_str(name)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<MouseButton, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        NAMES
+            .iter()
+            .find(|(_, known)| *known == name)
+            .map(|(button, _)| *button)
+            .ok_or_else(|| D::Error::custom(format!("unknown mouse button: {}", name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColormapDefinition, InputMap, KeyModifiers, Style};
+    use imgui::MouseButton;
+
+    #[test]
+    fn test_style_and_input_map_round_trip() {
+        let mut style: Style = unsafe { std::mem::zeroed() };
+        style.set_marker(crate::Marker::Cross);
+        style.plot_padding.x = 10.0;
+        style.colors[3].w = 0.5;
+        let json = serde_json::to_string(&style).unwrap();
+        assert!(json.contains(r#""marker":"cross""#));
+        assert!(json.contains(r#""plot_padding":[10.0,0.0]"#));
+        let restored: Style = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.colors[3].w, 0.5);
+        assert_eq!(restored.marker(), Some(crate::Marker::Cross));
+
+        let input_map = InputMap {
+            pan: MouseButton::Middle,
+            pan_mod: KeyModifiers::NONE,
+            fit: MouseButton::Left,
+            select: MouseButton::Left,
+            select_cancel: MouseButton::Right,
+            select_mod: KeyModifiers::NONE,
+            select_horz_mod: KeyModifiers::ALT,
+            select_vert_mod: KeyModifiers::SHIFT,
+            menu: MouseButton::Right,
+            override_mod: KeyModifiers::CTRL,
+            zoom_mod: KeyModifiers::NONE,
+            zoom_rate: 0.1,
+        };
+        let json = serde_json::to_string(&input_map).unwrap();
+        assert_eq!(serde_json::from_str::<InputMap>(&json).unwrap(), input_map);
+    }
+
+    #[test]
+    fn test_colormap_definition_from_json() {
+        let definition: ColormapDefinition = serde_json::from_str(
+            r#"{ "name": "traffic", "colors": [[0, 1, 0, 1], [1, 0, 0, 1]], "discrete": true }"#,
+        )
+        .unwrap();
+        assert_eq!(definition.colors.len(), 2);
+        assert_eq!(definition.colors[1].x, 1.0);
+        assert!(serde_json::from_str::<ColormapDefinition>(
+            r#"{ "name": "broken", "colors": [[0, 1, 0]], "discrete": false }"#
+        )
+        .is_err());
+    }
+}
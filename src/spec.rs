@@ -0,0 +1,284 @@
+//! # Chart specification module
+//!
+//! This module (enabled by the `spec` feature) defines a serde-deserializable description of a
+//! plot: its axes, its series and their styling. Series refer to their data by name, and the
+//! data is looked up at render time, so a chart can be described in a JSON or TOML file that is
+//! written by end users or generated by tooling, without recompiling the application:
+//! ```no_run
+//! # use implot::{ChartSpec, Context};
+//! # use std::collections::HashMap;
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! let spec: ChartSpec = serde_json::from_str(
+//!     r#"{
+//!         "title": "Prices",
+//!         "axes": [{ "axis": "x1", "label": "time" }],
+//!         "series": [{ "label": "price", "type": "line", "x": "time", "y": "price" }]
+//!     }"#,
+//! )
+//! .unwrap();
+//! let mut data = HashMap::new();
+//! data.insert("time".to_string(), vec![0.0, 1.0, 2.0]);
+//! data.insert("price".to_string(), vec![10.0, 11.5, 9.8]);
+//! spec.render(&plot_ui, &data);
+//! ```
+//!
+//! Deserializing itself is left to the format crate of choice (`serde_json`, `toml`, ...).
+
+use crate::{
+    AxisChoice, AxisScale, Plot, PlotBars, PlotColorElement, PlotCond, PlotLine, PlotScatter,
+    PlotShaded, PlotStairs, PlotStems, PlotUi, StyleVar,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Axis names as used in chart specifications.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AxisName {
+    X1,
+    X2,
+    X3,
+    Y1,
+    Y2,
+    Y3,
+}
+
+impl From<AxisName> for AxisChoice {
+    fn from(name: AxisName) -> Self {
+        match name {
+            AxisName::X1 => AxisChoice::X1,
+            AxisName::X2 => AxisChoice::X2,
+            AxisName::X3 => AxisChoice::X3,
+            AxisName::Y1 => AxisChoice::Y1,
+            AxisName::Y2 => AxisChoice::Y2,
+            AxisName::Y3 => AxisChoice::Y3,
+        }
+    }
+}
+
+/// Axis scales as used in chart specifications.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScaleName {
+    Linear,
+    Time,
+    Log10,
+    SymLog,
+}
+
+impl From<ScaleName> for AxisScale {
+    fn from(name: ScaleName) -> Self {
+        match name {
+            ScaleName::Linear => AxisScale::Linear,
+            ScaleName::Time => AxisScale::Time,
+            ScaleName::Log10 => AxisScale::Log10,
+            ScaleName::SymLog => AxisScale::SymLog,
+        }
+    }
+}
+
+/// Configuration of one axis of a chart.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AxisSpec {
+    pub axis: AxisName,
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Initial limits as `[min, max]`
+    #[serde(default)]
+    pub limits: Option<[f64; 2]>,
+    #[serde(default)]
+    pub scale: Option<ScaleName>,
+}
+
+/// Type of a series, along with type-specific settings.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SeriesType {
+    Line,
+    Scatter,
+    Stairs,
+    Bars {
+        #[serde(default)]
+        width: Option<f64>,
+        #[serde(default)]
+        horizontal: bool,
+    },
+    Stems {
+        #[serde(default)]
+        horizontal: bool,
+    },
+    /// Shaded area between the `y` data and the data named by `y2`
+    Shaded {
+        y2: String,
+    },
+}
+
+/// One series of a chart. `x` and `y` are the names of the data the series is bound to.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SeriesSpec {
+    pub label: String,
+    #[serde(flatten)]
+    pub series_type: SeriesType,
+    pub x: String,
+    pub y: String,
+    /// Y axis to plot the series on, the first Y axis if not given
+    #[serde(default)]
+    pub y_axis: Option<AxisName>,
+    /// Color as `[r, g, b, a]` with components between 0 and 1
+    #[serde(default)]
+    pub color: Option<[f32; 4]>,
+    #[serde(default)]
+    pub line_weight: Option<f32>,
+}
+
+/// Description of a whole chart.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChartSpec {
+    pub title: String,
+    /// Size as `[width, height]`, the default plot size if not given
+    #[serde(default)]
+    pub size: Option<[f32; 2]>,
+    #[serde(default)]
+    pub axes: Vec<AxisSpec>,
+    #[serde(default)]
+    pub series: Vec<SeriesSpec>,
+}
+
+/// Source of the named data that series in a [`ChartSpec`] are bound to.
+pub trait DataBindings {
+    /// Look up data by name, `None` if there is no data with that name.
+    fn data(&self, name: &str) -> Option<&[f64]>;
+}
+
+impl DataBindings for HashMap<String, Vec<f64>> {
+    fn data(&self, name: &str) -> Option<&[f64]> {
+        self.get(name).map(|data| data.as_slice())
+    }
+}
+
+impl<'a> DataBindings for HashMap<&'a str, &'a [f64]> {
+    fn data(&self, name: &str) -> Option<&[f64]> {
+        self.get(name).copied()
+    }
+}
+
+impl ChartSpec {
+    /// Create the plot builder for this chart, without any of the series.
+    ///
+    /// # Panics
+    /// Will panic if the title or any of the axis labels contain internal null bytes.
+    pub fn to_plot(&self) -> Plot {
+        let mut plot = Plot::new(&self.title);
+        if let Some(size) = self.size {
+            plot = plot.size(size);
+        }
+        for axis in &self.axes {
+            let choice: AxisChoice = axis.axis.into();
+            plot = plot.with_axis(choice);
+            if let Some(label) = &axis.label {
+                plot = plot.axis_label(label, choice);
+            }
+            if let Some(limits) = axis.limits {
+                plot = plot.axis_limits(limits, choice, PlotCond::Once);
+            }
+            if let Some(scale) = axis.scale {
+                plot = plot.with_axis_scale(choice, &scale.into());
+            }
+        }
+        // Axes that series are plotted on have to be enabled even if they are not configured
+        for y_axis in self.series.iter().filter_map(|series| series.y_axis) {
+            plot = plot.with_axis(y_axis.into());
+        }
+        plot
+    }
+
+    /// Draw the chart, looking up the series data in `data`. Series whose data can't be found
+    /// are skipped. Call this once per frame, within an imgui window.
+    ///
+    /// # Panics
+    /// Will panic if any of the strings in the spec contain internal null bytes.
+    pub fn render<D: DataBindings + ?Sized>(&self, plot_ui: &PlotUi, data: &D) {
+        self.to_plot().build(plot_ui, |plot| {
+            for series in &self.series {
+                let (x, y) = match (data.data(&series.x), data.data(&series.y)) {
+                    (Some(x), Some(y)) => (x, y),
+                    _ => continue,
+                };
+                plot.set_axis(series.y_axis.map_or(AxisChoice::Y1, Into::into));
+                series.render(plot_ui, x, y, data);
+            }
+        });
+    }
+}
+
+impl SeriesSpec {
+    fn render<D: DataBindings + ?Sized>(&self, plot_ui: &PlotUi, x: &[f64], y: &[f64], data: &D) {
+        let _color_tokens = self.color.map(|[red, green, blue, alpha]| {
+            (
+                plot_ui.push_style_color(&PlotColorElement::Line, red, green, blue, alpha),
+                plot_ui.push_style_color(&PlotColorElement::Fill, red, green, blue, alpha),
+            )
+        });
+        let _weight_token = self
+            .line_weight
+            .map(|weight| plot_ui.push_style_var_f32(&StyleVar::LineWeight, weight));
+
+        match &self.series_type {
+            SeriesType::Line => PlotLine::new(&self.label).plot(x, y),
+            SeriesType::Scatter => PlotScatter::new(&self.label).plot(x, y),
+            SeriesType::Stairs => PlotStairs::new(&self.label).plot(x, y),
+            SeriesType::Bars { width, horizontal } => {
+                let mut bars = PlotBars::new(&self.label);
+                if let Some(width) = width {
+                    bars = bars.with_bar_width(*width);
+                }
+                bars.plot(x, y, *horizontal)
+            }
+            SeriesType::Stems { horizontal } => PlotStems::new(&self.label).plot(x, y, *horizontal),
+            SeriesType::Shaded { y2 } => {
+                if let Some(y2) = data.data(y2) {
+                    PlotShaded::new(&self.label).plot(x, y, y2)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_chart_spec() {
+        let spec: ChartSpec = serde_json::from_str(
+            r#"{
+                "title": "Temperatures",
+                "size": [600, 300],
+                "axes": [
+                    { "axis": "x1", "label": "time", "scale": "time" },
+                    { "axis": "y1", "limits": [-10, 40] }
+                ],
+                "series": [
+                    { "label": "outside", "type": "line", "x": "t", "y": "outside" },
+                    { "label": "rain", "type": "bars", "width": 0.5, "x": "t", "y": "rain",
+                      "y_axis": "y2", "color": [0, 0, 1, 1] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(spec.size, Some([600.0, 300.0]));
+        assert_eq!(spec.axes[0].scale, Some(ScaleName::Time));
+        assert_eq!(spec.axes[1].limits, Some([-10.0, 40.0]));
+        assert_eq!(spec.series[0].series_type, SeriesType::Line);
+        assert_eq!(
+            spec.series[1].series_type,
+            SeriesType::Bars {
+                width: Some(0.5),
+                horizontal: false
+            }
+        );
+        assert_eq!(spec.series[1].y_axis, Some(AxisName::Y2));
+    }
+}
@@ -0,0 +1,185 @@
+//! Leak detection for the colormap, style color and style variable stacks.
+//!
+//! In debug builds, every push through [`PlotUi`](crate::PlotUi) is recorded together with the
+//! location it was made from and the imgui frame it was made in, and removed again when its
+//! token is dropped. A push that is still recorded in a later frame belongs to a token that was
+//! still open when its frame ended, which usually means it was leaked, e.g. with
+//! `std::mem::forget`, and slowly grows ImPlot's stacks. Such pushes are reported once, on the
+//! next push or call to [`Context::get_plot_ui`](crate::Context::get_plot_ui): with a panic under
+//! [`TokenDropPolicy::Panic`], on stderr under [`TokenDropPolicy::LogError`], and not at all under
+//! [`TokenDropPolicy::AutoEnd`], for applications that hold tokens across frames on purpose. In
+//! release builds, nothing is tracked.
+
+#[cfg(debug_assertions)]
+use crate::sys;
+use crate::TokenDropPolicy;
+#[cfg(debug_assertions)]
+use std::cell::RefCell;
+use std::panic::Location;
+
+/// Which stack a push was made to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum StackKind {
+    Colormap,
+    StyleColor,
+    StyleVar,
+}
+
+/// A push that has not been popped yet.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+struct Push {
+    kind: StackKind,
+    count: i32,
+    location: &'static Location<'static>,
+    frame: i32,
+    /// Whether the push was already reported as leaked. It stays recorded until its token pops
+    /// it, so that pops keep matching the pushes they belong to.
+    reported: bool,
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static PUSHES: RefCell<Vec<Push>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Current imgui frame, or `None` if there is no imgui context to ask.
+#[cfg(debug_assertions)]
+fn current_frame() -> Option<i32> {
+    unsafe {
+        if sys::igGetCurrentContext().is_null() {
+            None
+        } else {
+            Some(sys::igGetFrameCount())
+        }
+    }
+}
+
+/// Record a push of `count` entries to a stack, made from the caller's location.
+#[track_caller]
+#[cfg_attr(not(debug_assertions), allow(unused_variables))]
+pub(crate) fn record_push(kind: StackKind, count: i32, policy: TokenDropPolicy) {
+    #[cfg(debug_assertions)]
+    {
+        check_leaks(policy);
+        let push = Push {
+            kind,
+            count,
+            location: Location::caller(),
+            frame: current_frame().unwrap_or(-1),
+            reported: false,
+        };
+        PUSHES.with(|pushes| pushes.borrow_mut().push(push));
+    }
+}
+
+/// Forget the most recent push to a stack, once its token pops it.
+#[cfg_attr(not(debug_assertions), allow(unused_variables))]
+pub(crate) fn record_pop(kind: StackKind) {
+    #[cfg(debug_assertions)]
+    PUSHES.with(|pushes| {
+        let mut pushes = pushes.borrow_mut();
+        if let Some(index) = pushes.iter().rposition(|push| push.kind == kind) {
+            pushes.remove(index);
+        }
+    });
+}
+
+/// Report pushes made in earlier frames that were never popped. Each leak is reported once, and
+/// nothing is reported under [`TokenDropPolicy::AutoEnd`].
+#[cfg_attr(not(debug_assertions), allow(unused_variables))]
+pub(crate) fn check_leaks(policy: TokenDropPolicy) {
+    #[cfg(debug_assertions)]
+    {
+        if policy == TokenDropPolicy::AutoEnd {
+            return;
+        }
+        let frame = match current_frame() {
+            Some(frame) => frame,
+            None => return,
+        };
+        let leaked = PUSHES.with(|pushes| take_unreported_leaks(&mut pushes.borrow_mut(), frame));
+        if leaked.is_empty() {
+            return;
+        }
+        let message = leak_message(&leaked);
+        match policy {
+            TokenDropPolicy::Panic => {
+                if !std::thread::panicking() {
+                    panic!("{}", message);
+                }
+            }
+            TokenDropPolicy::LogError => eprintln!("{}", message),
+            TokenDropPolicy::AutoEnd => {}
+        }
+    }
+}
+
+/// Mark the pushes made before the given frame as reported, returning those that were not yet.
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+fn take_unreported_leaks(pushes: &mut [Push], frame: i32) -> Vec<Push> {
+    pushes
+        .iter_mut()
+        .filter(|push| push.frame < frame && !push.reported)
+        .map(|push| {
+            push.reported = true;
+            *push
+        })
+        .collect()
+}
+
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+fn leak_message(leaked: &[Push]) -> String {
+    let mut message =
+        String::from("Error: Tokens were leaked across frames, their pushes were never popped:");
+    for push in leaked {
+        let what = match push.kind {
+            StackKind::Colormap => "colormap",
+            StackKind::StyleColor => "style color",
+            StackKind::StyleVar => "style var",
+        };
+        message.push_str(&format!(
+            "\n  {} {}{} pushed at {} in frame {}",
+            push.count,
+            what,
+            if push.count == 1 { "" } else { "s" },
+            push.location,
+            push.frame
+        ));
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_unreported_leaks() {
+        let location = Location::caller();
+        let push = |kind, count, frame| Push {
+            kind,
+            count,
+            location,
+            frame,
+            reported: false,
+        };
+        let mut pushes = vec![
+            push(StackKind::Colormap, 1, 3),
+            push(StackKind::StyleVar, 2, 4),
+            push(StackKind::StyleColor, 1, 5),
+        ];
+
+        let leaked = take_unreported_leaks(&mut pushes, 5);
+        assert_eq!(leaked.len(), 2);
+        assert_eq!(leaked[1].kind, StackKind::StyleVar);
+        // Reported pushes are kept for their pops, but not reported again
+        assert_eq!(pushes.len(), 3);
+        assert!(take_unreported_leaks(&mut pushes, 5).is_empty());
+        assert_eq!(take_unreported_leaks(&mut pushes, 6).len(), 1);
+
+        let message = leak_message(&leaked);
+        assert!(message.contains(&format!("1 colormap pushed at {} in frame 3", location)));
+        assert!(message.contains("2 style vars pushed at"));
+    }
+}
@@ -0,0 +1,251 @@
+//! # Strided data module
+//!
+//! This module defines `StridedData`, a view of one `f64` field across a slice of structs, the
+//! `Plottable` trait that the `Plottable` derive macro (behind the `derive` feature) implements,
+//! and the `IntoPlotData` trait for interleaved XY data. ImPlot can read data with a stride
+//! directly, so plotting a field of a slice of structs this way does not need any copies:
+//! ```no_run
+//! # use implot::{PlotLine, StridedData};
+//! struct Telemetry {
+//!     time: f64,
+//!     speed: f64,
+//! }
+//!
+//! # let samples: Vec<Telemetry> = Vec::new();
+//! // Both are plain fields of the struct, so they are at the same offset in every sample
+//! let time = unsafe { StridedData::from_field(&samples, |sample| &sample.time) };
+//! let speed = unsafe { StridedData::from_field(&samples, |sample| &sample.speed) };
+//! PlotLine::new("speed").plot_strided(time, speed);
+//! ```
+
+use crate::ImPlotPoint;
+use std::marker::PhantomData;
+
+/// A view of `f64` values that are `stride` bytes apart in memory, borrowed from a slice.
+#[derive(Clone, Copy, Debug)]
+pub struct StridedData<'a> {
+    ptr: *const f64,
+    len: usize,
+    stride: usize,
+    _marker: PhantomData<&'a f64>,
+}
+
+impl<'a> StridedData<'a> {
+    /// View a contiguous slice of values, i.e. with a stride of one `f64`.
+    pub fn from_slice(data: &'a [f64]) -> Self {
+        Self {
+            ptr: data.as_ptr(),
+            len: data.len(),
+            stride: std::mem::size_of::<f64>(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// View one field of every element of `data`. The `field` closure has to return a
+    /// reference to a field of the element it is given. It is only called on the first
+    /// element, the values of the others are read at the same offset.
+    ///
+    /// Deriving [`Plottable`] generates these views for the `#[plot]` fields of a struct without
+    /// any unsafe code.
+    ///
+    /// # Safety
+    /// The reference returned by `field` has to be at the same offset, and to an initialized
+    /// `f64`, in every element of `data`. This holds for plain fields of structs, tuples and
+    /// arrays, but not e.g. for values inside enum variants such as `Option<f64>`, where other
+    /// elements may not have a value at that offset.
+    ///
+    /// # Panics
+    /// Will panic if `field` returns a reference to something that is not part of the element.
+    pub unsafe fn from_field<T, F: Fn(&T) -> &f64>(data: &'a [T], field: F) -> Self {
+        let first = match data.first() {
+            Some(first) => first,
+            None => return Self::from_slice(&[]),
+        };
+        let ptr = field(first) as *const f64;
+
+        // The same field of all following elements is then at a fixed distance each, which is
+        // what makes handing the pointer and stride to ImPlot sound.
+        let element_start = first as *const T as usize;
+        let field_start = ptr as usize;
+        assert!(
+            field_start >= element_start
+                && field_start + std::mem::size_of::<f64>()
+                    <= element_start + std::mem::size_of::<T>(),
+            "StridedData::from_field must be given a reference into the element"
+        );
+
+        Self {
+            ptr,
+            len: data.len(),
+            stride: std::mem::size_of::<T>(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Number of values in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the view contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Distance between consecutive values, in bytes.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Get the value at the given index, if it is in range.
+    pub fn get(&self, index: usize) -> Option<f64> {
+        if index < self.len {
+            // In range, and the stride was checked on construction
+            Some(unsafe { *((self.ptr as *const u8).add(index * self.stride) as *const f64) })
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over the values in the view.
+    pub fn iter(&self) -> impl Iterator<Item = f64> + 'a {
+        let data = *self;
+        (0..data.len).map(move |index| data.get(index).unwrap())
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const f64 {
+        self.ptr
+    }
+}
+
+/// Structs whose `f64` fields can be plotted straight from a slice of them. Usually derived
+/// with `#[derive(Plottable)]` (behind the `derive` feature), marking fields with `#[plot]`.
+pub trait Plottable: Sized {
+    /// Names of the plottable fields.
+    fn plot_fields() -> &'static [&'static str];
+
+    /// View of the named field across `data`, `None` if there is no such plottable field.
+    fn plot_field<'a>(data: &'a [Self], field: &str) -> Option<StridedData<'a>>;
+}
+
+/// Interleaved XY data, such as `[[f64; 2]]`, `[(f64, f64)]` or `[ImPlotPoint]` slices, arrays and
+/// vectors. The line, stairs, scatter and shaded elements plot it with their `plot_xy` methods,
+/// without unzipping it into X and Y slices first:
+/// ```no_run
+/// # use implot::PlotLine;
+/// let trajectory: Vec<(f64, f64)> = vec![(0.0, 0.0), (1.0, 0.5), (2.0, 0.8)];
+/// PlotLine::new("trajectory").plot_xy(&trajectory);
+/// ```
+pub trait IntoPlotData {
+    /// Views of the X and Y values.
+    fn plot_data(&self) -> (StridedData<'_>, StridedData<'_>);
+}
+
+impl IntoPlotData for [[f64; 2]] {
+    fn plot_data(&self) -> (StridedData<'_>, StridedData<'_>) {
+        // Plain fields, at the same offset in every element
+        unsafe {
+            (
+                StridedData::from_field(self, |point| &point[0]),
+                StridedData::from_field(self, |point| &point[1]),
+            )
+        }
+    }
+}
+
+impl IntoPlotData for [(f64, f64)] {
+    fn plot_data(&self) -> (StridedData<'_>, StridedData<'_>) {
+        // Plain fields, at the same offset in every element
+        unsafe {
+            (
+                StridedData::from_field(self, |point| &point.0),
+                StridedData::from_field(self, |point| &point.1),
+            )
+        }
+    }
+}
+
+impl IntoPlotData for [ImPlotPoint] {
+    fn plot_data(&self) -> (StridedData<'_>, StridedData<'_>) {
+        // Plain fields, at the same offset in every element
+        unsafe {
+            (
+                StridedData::from_field(self, |point| &point.x),
+                StridedData::from_field(self, |point| &point.y),
+            )
+        }
+    }
+}
+
+impl<P> IntoPlotData for Vec<P>
+where
+    [P]: IntoPlotData,
+{
+    fn plot_data(&self) -> (StridedData<'_>, StridedData<'_>) {
+        self.as_slice().plot_data()
+    }
+}
+
+impl<P, const N: usize> IntoPlotData for [P; N]
+where
+    [P]: IntoPlotData,
+{
+    fn plot_data(&self) -> (StridedData<'_>, StridedData<'_>) {
+        self[..].plot_data()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sample {
+        time: f64,
+        value: f64,
+        _flag: u8,
+    }
+
+    #[test]
+    fn test_strided_field_view() {
+        let samples = [
+            Sample {
+                time: 0.0,
+                value: 5.0,
+                _flag: 0,
+            },
+            Sample {
+                time: 1.0,
+                value: 7.0,
+                _flag: 1,
+            },
+        ];
+        let time = unsafe { StridedData::from_field(&samples, |sample| &sample.time) };
+        let value = unsafe { StridedData::from_field(&samples, |sample| &sample.value) };
+        assert_eq!(time.stride(), std::mem::size_of::<Sample>());
+        assert_eq!(time.iter().collect::<Vec<_>>(), vec![0.0, 1.0]);
+        assert_eq!(value.iter().collect::<Vec<_>>(), vec![5.0, 7.0]);
+        assert_eq!(value.get(2), None);
+    }
+
+    #[test]
+    fn test_interleaved_plot_data() {
+        let pairs = vec![[0.0, 5.0], [1.0, 7.0]];
+        let tuples = [(0.0, 5.0), (1.0, 7.0)];
+        for (x, y) in [pairs.plot_data(), tuples.plot_data()] {
+            assert_eq!(x.iter().collect::<Vec<_>>(), vec![0.0, 1.0]);
+            assert_eq!(y.iter().collect::<Vec<_>>(), vec![5.0, 7.0]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_strided_field_outside_element() {
+        static OUTSIDE: f64 = 1.0;
+        let samples = [Sample {
+            time: 0.0,
+            value: 5.0,
+            _flag: 0,
+        }];
+        unsafe { StridedData::from_field(&samples, |_| &OUTSIDE) };
+    }
+}
@@ -0,0 +1,499 @@
+//! # Style module
+//!
+//! This module gives typed access to ImPlot's style, which holds the defaults for line weights,
+//! markers, paddings and colors of all plots. The style is read with [`PlotUi::style`], which
+//! returns a copy, and changed by applying the modified copy with [`PlotUi::set_style`]:
+//! ```no_run
+//! # use implot::{Context, PlotColorElement, ImVec4};
+//! # let context = Context::create();
+//! let plot_ui = context.get_plot_ui();
+//! let mut style = plot_ui.style();
+//! style.line_weight = 2.0;
+//! style.use_24_hour_clock = true;
+//! style[PlotColorElement::PlotBg] = ImVec4 { x: 0.1, y: 0.1, z: 0.1, w: 1.0 };
+//! plot_ui.set_style(&style);
+//! ```
+//!
+//! The time-formatting options (local time, 24 hour clock, ISO 8601 dates) are part of the
+//! style as well, so time axes can follow the user's locale conventions.
+
+use crate::{sys, Colormap, ImPlotColormap, ImVec2, ImVec4, Marker, PlotColorElement, PlotUi};
+use std::convert::TryFrom;
+use std::ffi::CStr;
+use std::ops::{Index, IndexMut};
+use std::os::raw::c_char;
+
+/// All style colors, in the order of `Style::colors`.
+const COLOR_ELEMENTS: [PlotColorElement; PlotColorElement::COUNT as usize] = [
+    PlotColorElement::Line,
+    PlotColorElement::Fill,
+    PlotColorElement::MarkerOutline,
+    PlotColorElement::MarkerFill,
+    PlotColorElement::ErrorBar,
+    PlotColorElement::FrameBg,
+    PlotColorElement::PlotBg,
+    PlotColorElement::PlotBorder,
+    PlotColorElement::LegendBg,
+    PlotColorElement::LegendBorder,
+    PlotColorElement::LegendText,
+    PlotColorElement::TitleText,
+    PlotColorElement::InlayText,
+    PlotColorElement::AxisText,
+    PlotColorElement::AxisGrid,
+    PlotColorElement::AxisTick,
+    PlotColorElement::AxisBg,
+    PlotColorElement::AxisBgHovered,
+    PlotColorElement::AxisBgActive,
+    PlotColorElement::Selection,
+    PlotColorElement::Crosshairs,
+];
+
+/// ImPlot's style, with the same layout as `ImPlotStyle`. Temporary changes are better made
+/// with [`PlotUi::push_style_var_f32`] and friends, which are undone when their token is dropped.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Style {
+    /// Line weight of items, in pixels
+    pub line_weight: f32,
+    /// Marker of items, kept as ImPlot's raw value since ImPlot does not check it, see
+    /// [`Style::marker`]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::marker"))]
+    marker: i32,
+    /// Marker size of items, in pixels (roughly the radius)
+    pub marker_size: f32,
+    /// Outline weight of markers, in pixels
+    pub marker_weight: f32,
+    /// Alpha multiplier for filled areas, e.g. of shaded plots and bars
+    pub fill_alpha: f32,
+    /// Width of error bar whiskers, in pixels
+    pub error_bar_size: f32,
+    /// Thickness of error bar whiskers, in pixels
+    pub error_bar_weight: f32,
+    /// Height of digital signals when high, in pixels
+    pub digital_bit_height: f32,
+    /// Spacing between digital signals, in pixels
+    pub digital_bit_gap: f32,
+    /// Thickness of the border around the plot area
+    pub plot_border_size: f32,
+    /// Alpha multiplier applied to the minor grid lines
+    pub minor_alpha: f32,
+    /// Lengths of major ticks on the X and Y axes
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub major_tick_len: ImVec2,
+    /// Lengths of minor ticks on the X and Y axes
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub minor_tick_len: ImVec2,
+    /// Line thickness of major ticks
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub major_tick_size: ImVec2,
+    /// Line thickness of minor ticks
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub minor_tick_size: ImVec2,
+    /// Line thickness of major grid lines
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub major_grid_size: ImVec2,
+    /// Line thickness of minor grid lines
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub minor_grid_size: ImVec2,
+    /// Padding between the widget frame and the plot area, labels or outside legends
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub plot_padding: ImVec2,
+    /// Padding between axes labels, tick labels and the plot edge
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub label_padding: ImVec2,
+    /// Legend padding from the plot edges
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub legend_padding: ImVec2,
+    /// Legend inner padding from the legend edges
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub legend_inner_padding: ImVec2,
+    /// Spacing between legend entries
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub legend_spacing: ImVec2,
+    /// Padding between the plot edge and the mouse position text
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub mouse_pos_padding: ImVec2,
+    /// Text padding around annotation labels
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub annotation_padding: ImVec2,
+    /// Additional fit padding, as a fraction of the fitted extents
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub fit_padding: ImVec2,
+    /// Default size of plots created with a size of zero
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub plot_default_size: ImVec2,
+    /// Minimum size of the plot area
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::vec2"))]
+    pub plot_min_size: ImVec2,
+    /// Style colors, indexable by [`PlotColorElement`] through the style itself
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::colors"))]
+    pub colors: [ImVec4; PlotColorElement::COUNT as usize],
+    /// Index of the current colormap, which is checked when the style is applied with
+    /// [`PlotUi::set_style`], see also [`Style::set_colormap`].
+    pub colormap: ImPlotColormap,
+    /// Show time axes in local time instead of UTC, see also
+    /// [`Context::set_use_local_time`](crate::Context::set_use_local_time)
+    pub use_local_time: bool,
+    /// Show dates in ISO 8601 format on time axes, see also
+    /// [`Context::set_use_iso8601`](crate::Context::set_use_iso8601)
+    pub use_iso8601: bool,
+    /// Use the 24 hour clock on time axes, see also
+    /// [`Context::set_use_24_hour_clock`](crate::Context::set_use_24_hour_clock)
+    pub use_24_hour_clock: bool,
+}
+
+impl Style {
+    fn from_raw(raw: &sys::ImPlotStyle) -> &Self {
+        unsafe { &*(raw as *const sys::ImPlotStyle as *const Self) }
+    }
+
+    /// Marker of items, `Marker::None` if no markers are drawn. Returns `None` if the style
+    /// holds a value that is not a marker, e.g. one pushed with
+    /// [`PlotUi::push_style_var_i32`].
+    pub fn marker(&self) -> Option<Marker> {
+        marker_from_raw(self.marker)
+    }
+
+    /// Set the marker of items, `Marker::None` to not draw markers.
+    ///
+    /// # Panics
+    /// Will panic if the marker is `Marker::COUNT`.
+    pub fn set_marker(&mut self, marker: Marker) {
+        assert_ne!(marker, Marker::COUNT, "Marker::COUNT is not a marker");
+        self.marker = marker as i32;
+    }
+
+    /// Rust code that sets up this style, e.g. to paste a style tuned interactively in ImPlot's
+    /// style editor into the application. The code assigns all fields of a copy of the style
+    /// from [`PlotUi::style`], and applies it with [`PlotUi::set_style`]. The colormap is
+    /// looked up by name, since indices of custom colormaps depend on the order they are added
+    /// in:
+    /// ```no_run
+    /// # use implot::Context;
+    /// # let context = Context::create();
+    /// # let plot_ui = context.get_plot_ui();
+    /// println!("{}", plot_ui.style().to_rust_code(&plot_ui));
+    /// // let mut style = plot_ui.style();
+    /// // style.line_weight = 1.0;
+    /// // ...
+    /// // style.set_colormap(plot_ui.get_colormap_index("Deep").unwrap());
+    /// // ...
+    /// // plot_ui.set_style(&style);
+    /// ```
+    pub fn to_rust_code(&self, plot_ui: &PlotUi) -> String {
+        let colormap_name = if (0..plot_ui.get_colormap_count()).contains(&self.colormap) {
+            Some(plot_ui.get_colormap_name(Colormap::Custom(self.colormap)))
+        } else {
+            None
+        };
+        self.rust_code(colormap_name.as_deref())
+    }
+
+    /// [`Style::to_rust_code`], given the name of the colormap of the style. The colormap is
+    /// left out if it has none.
+    fn rust_code(&self, colormap_name: Option<&str>) -> String {
+        let vec2 = |value: ImVec2| format!("ImVec2 {{ x: {:?}, y: {:?} }}", value.x, value.y);
+        let vec4 = |value: ImVec4| {
+            format!(
+                "ImVec4 {{ x: {:?}, y: {:?}, z: {:?}, w: {:?} }}",
+                value.x, value.y, value.z, value.w
+            )
+        };
+        let mut lines = vec![
+            "let mut style = plot_ui.style();".to_owned(),
+            format!("style.line_weight = {:?};", self.line_weight),
+            format!(
+                "style.set_marker(Marker::{:?});",
+                self.marker().unwrap_or(Marker::None)
+            ),
+            format!("style.marker_size = {:?};", self.marker_size),
+            format!("style.marker_weight = {:?};", self.marker_weight),
+            format!("style.fill_alpha = {:?};", self.fill_alpha),
+            format!("style.error_bar_size = {:?};", self.error_bar_size),
+            format!("style.error_bar_weight = {:?};", self.error_bar_weight),
+            format!("style.digital_bit_height = {:?};", self.digital_bit_height),
+            format!("style.digital_bit_gap = {:?};", self.digital_bit_gap),
+            format!("style.plot_border_size = {:?};", self.plot_border_size),
+            format!("style.minor_alpha = {:?};", self.minor_alpha),
+        ];
+        let vectors = [
+            ("major_tick_len", self.major_tick_len),
+            ("minor_tick_len", self.minor_tick_len),
+            ("major_tick_size", self.major_tick_size),
+            ("minor_tick_size", self.minor_tick_size),
+            ("major_grid_size", self.major_grid_size),
+            ("minor_grid_size", self.minor_grid_size),
+            ("plot_padding", self.plot_padding),
+            ("label_padding", self.label_padding),
+            ("legend_padding", self.legend_padding),
+            ("legend_inner_padding", self.legend_inner_padding),
+            ("legend_spacing", self.legend_spacing),
+            ("mouse_pos_padding", self.mouse_pos_padding),
+            ("annotation_padding", self.annotation_padding),
+            ("fit_padding", self.fit_padding),
+            ("plot_default_size", self.plot_default_size),
+            ("plot_min_size", self.plot_min_size),
+        ];
+        for (name, value) in vectors.iter() {
+            lines.push(format!("style.{} = {};", name, vec2(*value)));
+        }
+        for (element, color) in COLOR_ELEMENTS.iter().zip(self.colors.iter()) {
+            lines.push(format!(
+                "style[PlotColorElement::{:?}] = {};",
+                element,
+                vec4(*color)
+            ));
+        }
+        if let Some(name) = colormap_name {
+            lines.push(format!(
+                "style.set_colormap(plot_ui.get_colormap_index({:?}).unwrap());",
+                name
+            ));
+        }
+        lines.push(format!("style.use_local_time = {};", self.use_local_time));
+        lines.push(format!("style.use_iso8601 = {};", self.use_iso8601));
+        lines.push(format!(
+            "style.use_24_hour_clock = {};",
+            self.use_24_hour_clock
+        ));
+        lines.push("plot_ui.set_style(&style);".to_owned());
+        lines.join("\n")
+    }
+
+    /// Set the colormap, e.g. one found with [`PlotUi::get_colormap_index`].
+    pub fn set_colormap(&mut self, colormap: Colormap) {
+        self.colormap = colormap.to_index();
+    }
+
+    /// The style as TOML, e.g. for saving it to a settings file. Colors are written as
+    /// `[r, g, b, a]` arrays and the marker by name.
+    #[cfg(feature = "style-toml")]
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Read a style written with [`Style::to_toml`].
+    #[cfg(feature = "style-toml")]
+    pub fn from_toml(text: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    /// The style as the raw ImPlot struct, for fields or functions that are not wrapped here.
+    pub fn raw_mut(&mut self) -> &mut sys::ImPlotStyle {
+        unsafe { &mut *(self as *mut Self as *mut sys::ImPlotStyle) }
+    }
+}
+
+impl Index<PlotColorElement> for Style {
+    type Output = ImVec4;
+
+    fn index(&self, element: PlotColorElement) -> &ImVec4 {
+        &self.colors[element as usize]
+    }
+}
+
+impl IndexMut<PlotColorElement> for Style {
+    fn index_mut(&mut self, element: PlotColorElement) -> &mut ImVec4 {
+        &mut self.colors[element as usize]
+    }
+}
+
+impl PlotUi<'_> {
+    /// Get a copy of the current style. Style variables and colors that are pushed at the time
+    /// are included.
+    #[rustversion::attr(since(1.48), doc(alias = "GetStyle"))]
+    pub fn style(&self) -> Style {
+        unsafe {
+            let style = sys::ImPlot_GetStyle();
+            assert_ne!(style, std::ptr::null_mut());
+            *Style::from_raw(&*style)
+        }
+    }
+
+    /// Replace the current style, to change the defaults for all plots. Usually the style is
+    /// obtained with [`PlotUi::style`] and modified before. Pushed style variables and colors
+    /// are still restored to their previous values when popped.
+    ///
+    /// # Panics
+    /// Will panic if the colormap of the style is not a registered colormap.
+    #[rustversion::attr(since(1.48), doc(alias = "GetStyle"))]
+    pub fn set_style(&self, style: &Style) {
+        let count = self.get_colormap_count();
+        assert!(
+            (0..count).contains(&style.colormap),
+            "Style colormap {} is not one of the {} registered colormaps",
+            style.colormap,
+            count
+        );
+        unsafe {
+            let current = sys::ImPlot_GetStyle();
+            assert_ne!(current, std::ptr::null_mut());
+            *current = *(style as *const Style as *const sys::ImPlotStyle);
+        }
+    }
+
+    /// Set the style colors to match imgui's current style colors, applying them to the
+    /// current style if `None` is given.
+    #[rustversion::attr(since(1.48), doc(alias = "StyleColorsAuto"))]
+    pub fn style_colors_auto(&self, style: Option<&mut Style>) {
+        unsafe { sys::ImPlot_StyleColorsAuto(raw_or_current(style)) }
+    }
+
+    /// Set the style colors to the dark preset, applying them to the current style if `None`
+    /// is given.
+    #[rustversion::attr(since(1.48), doc(alias = "StyleColorsDark"))]
+    pub fn style_colors_dark(&self, style: Option<&mut Style>) {
+        unsafe { sys::ImPlot_StyleColorsDark(raw_or_current(style)) }
+    }
+
+    /// Set the style colors to the light preset, applying them to the current style if `None`
+    /// is given.
+    #[rustversion::attr(since(1.48), doc(alias = "StyleColorsLight"))]
+    pub fn style_colors_light(&self, style: Option<&mut Style>) {
+        unsafe { sys::ImPlot_StyleColorsLight(raw_or_current(style)) }
+    }
+
+    /// Set the style colors to the classic preset, applying them to the current style if
+    /// `None` is given.
+    #[rustversion::attr(since(1.48), doc(alias = "StyleColorsClassic"))]
+    pub fn style_colors_classic(&self, style: Option<&mut Style>) {
+        unsafe { sys::ImPlot_StyleColorsClassic(raw_or_current(style)) }
+    }
+
+    /// Get the human-readable name of a style color, e.g. for labels in a custom style editor.
+    /// `PlotColorElement::COUNT` has an empty name.
+    #[rustversion::attr(since(1.48), doc(alias = "GetStyleColorName"))]
+    pub fn get_style_color_name(&self, element: PlotColorElement) -> &'static str {
+        // ImPlot looks the name up in an array without checking the index
+        if element == PlotColorElement::COUNT {
+            return "";
+        }
+        unsafe { static_str(sys::ImPlot_GetStyleColorName(element.into())) }
+    }
+
+    /// Get the human-readable name of a marker, e.g. for a marker selection combo box.
+    #[rustversion::attr(since(1.48), doc(alias = "GetMarkerName"))]
+    pub fn get_marker_name(&self, marker: Marker) -> &'static str {
+        unsafe { static_str(sys::ImPlot_GetMarkerName(marker.into())) }
+    }
+}
+
+/// The marker with the given raw value, if there is one.
+fn marker_from_raw(raw: i32) -> Option<Marker> {
+    Marker::try_from(raw)
+        .ok()
+        .filter(|marker| *marker != Marker::COUNT)
+}
+
+/// Convert a name from one of ImPlot's static name tables.
+///
+/// # Safety
+/// The pointer has to point to a null-terminated string that is never freed.
+unsafe fn static_str(name: *const c_char) -> &'static str {
+    CStr::from_ptr(name).to_str().unwrap_or_default()
+}
+
+/// Pointer to the given style, or null to make ImPlot use the current one.
+fn raw_or_current(style: Option<&mut Style>) -> *mut sys::ImPlotStyle {
+    style.map_or(std::ptr::null_mut(), |style| style.raw_mut())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_layout_matches_implot() {
+        assert_eq!(
+            std::mem::size_of::<Style>(),
+            std::mem::size_of::<sys::ImPlotStyle>()
+        );
+        assert_eq!(
+            std::mem::align_of::<Style>(),
+            std::mem::align_of::<sys::ImPlotStyle>()
+        );
+
+        let mut raw: sys::ImPlotStyle = unsafe { std::mem::zeroed() };
+        raw.LineWeight = 1.5;
+        raw.Marker = Marker::Diamond as i32;
+        raw.MinorAlpha = 0.25;
+        raw.PlotMinSize = ImVec2 { x: 3.0, y: 4.0 };
+        raw.Colors[PlotColorElement::Crosshairs as usize].w = 0.5;
+        raw.Colormap = 7;
+        raw.Use24HourClock = true;
+
+        let style = Style::from_raw(&raw);
+        assert_eq!(style.line_weight, 1.5);
+        assert_eq!(style.marker(), Some(Marker::Diamond));
+        assert_eq!(style.minor_alpha, 0.25);
+        assert_eq!(style.plot_min_size.y, 4.0);
+        assert_eq!(style[PlotColorElement::Crosshairs].w, 0.5);
+        assert_eq!(style.colormap, 7);
+        assert!(!style.use_iso8601);
+        assert!(style.use_24_hour_clock);
+    }
+
+    #[test]
+    fn test_style_to_rust_code() {
+        let mut raw: sys::ImPlotStyle = unsafe { std::mem::zeroed() };
+        raw.LineWeight = 1.5;
+        raw.Marker = Marker::Diamond as i32;
+        raw.PlotPadding = ImVec2 { x: 10.0, y: 8.0 };
+        raw.Colors[PlotColorElement::Crosshairs as usize].w = 0.5;
+        raw.Use24HourClock = true;
+
+        let code = Style::from_raw(&raw).rust_code(Some("Deep"));
+        let lines: Vec<&str> = code.lines().collect();
+        assert_eq!(lines[0], "let mut style = plot_ui.style();");
+        assert!(lines.contains(&"style.line_weight = 1.5;"));
+        assert!(lines.contains(&"style.set_marker(Marker::Diamond);"));
+        assert!(lines.contains(&"style.plot_padding = ImVec2 { x: 10.0, y: 8.0 };"));
+        assert!(lines.contains(
+            &"style[PlotColorElement::Crosshairs] = ImVec4 { x: 0.0, y: 0.0, z: 0.0, w: 0.5 };"
+        ));
+        assert!(
+            lines.contains(&"style.set_colormap(plot_ui.get_colormap_index(\"Deep\").unwrap());")
+        );
+        assert!(lines.contains(&"style.use_24_hour_clock = true;"));
+        assert_eq!(lines[lines.len() - 1], "plot_ui.set_style(&style);");
+
+        let code = Style::from_raw(&raw).rust_code(None);
+        assert!(!code.contains("colormap"));
+    }
+
+    #[cfg(feature = "style-toml")]
+    #[test]
+    fn test_style_toml_round_trip() {
+        let mut raw: sys::ImPlotStyle = unsafe { std::mem::zeroed() };
+        raw.LineWeight = 1.5;
+        raw.Marker = Marker::Diamond as i32;
+        raw.PlotPadding = ImVec2 { x: 10.0, y: 8.0 };
+        raw.Colors[PlotColorElement::Crosshairs as usize].w = 0.5;
+        raw.Colormap = 3;
+        raw.UseISO8601 = true;
+
+        let style = *Style::from_raw(&raw);
+        let text = style.to_toml().unwrap();
+        assert!(text.contains("diamond"));
+        let restored = Style::from_toml(&text).unwrap();
+        assert_eq!(restored.line_weight, 1.5);
+        assert_eq!(restored.marker(), Some(Marker::Diamond));
+        assert_eq!(restored.plot_padding.x, 10.0);
+        assert_eq!(restored[PlotColorElement::Crosshairs].w, 0.5);
+        assert_eq!(restored.colormap, 3);
+        assert!(restored.use_iso8601 && !restored.use_24_hour_clock);
+        assert!(Style::from_toml("line_weight = 1.0").is_err());
+    }
+
+    #[test]
+    fn test_style_marker() {
+        let mut raw: sys::ImPlotStyle = unsafe { std::mem::zeroed() };
+        raw.Marker = 42;
+        let mut style = *Style::from_raw(&raw);
+        assert_eq!(style.marker(), None);
+        style.set_marker(Marker::None);
+        assert_eq!(style.marker(), Some(Marker::None));
+        assert_eq!(style.raw_mut().Marker, -1);
+    }
+}
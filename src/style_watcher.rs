@@ -0,0 +1,205 @@
+//! # Style watcher module
+//!
+//! This module (enabled by the `style-watcher` feature) loads the style and custom colormaps
+//! from a file and applies them again whenever the file changes, so plot theming can be tuned
+//! in a running application. Files ending in `.json` are read as JSON; files ending in `.toml`
+//! are read as TOML if the `style-toml` feature is enabled as well:
+//! ```no_run
+//! # use implot::{Context, StyleWatcher};
+//! let context = Context::create();
+//! let mut watcher = StyleWatcher::new("plot_style.json");
+//!
+//! // Every frame:
+//! let plot_ui = context.get_plot_ui();
+//! if let Err(error) = watcher.poll(&plot_ui) {
+//!     eprintln!("Could not load the plot style: {}", error);
+//! }
+//! ```
+//!
+//! The file contains the complete style as written by serializing [`Style`] (e.g. with
+//! `Style::to_toml` from the `style-toml` feature), and optionally colormaps to add and the
+//! name of the colormap to use:
+//! ```json
+//! {
+//!     "style": { "line_weight": 1.5, "...": "..." },
+//!     "colormaps": [{ "name": "brand", "colors": [[0.1, 0.4, 0.8, 1.0]], "discrete": true }],
+//!     "colormap": "brand"
+//! }
+//! ```
+//! ImPlot cannot change colormaps once they are added, so a colormap whose colors are edited
+//! needs a new name to take effect.
+
+use crate::{ColormapDefinition, PlotUi, Style};
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Contents of a style file.
+#[derive(Clone, Debug, Deserialize)]
+struct StyleFile {
+    style: Style,
+    #[serde(default)]
+    colormaps: Vec<ColormapDefinition>,
+    /// Name of the colormap to use, instead of the index stored in the style
+    #[serde(default)]
+    colormap: Option<String>,
+}
+
+/// Error from loading a style file, see [`StyleWatcher::poll`].
+#[derive(Debug)]
+pub enum StyleFileError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file could not be parsed, with the message of the parser.
+    Parse(String),
+    /// The file extension is not one of the supported formats.
+    UnsupportedFormat,
+    /// The file selects a colormap that does not exist, by name or by the index in the style.
+    UnknownColormap(String),
+}
+
+impl fmt::Display for StyleFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StyleFileError::Io(error) => write!(f, "could not read style file: {}", error),
+            StyleFileError::Parse(message) => write!(f, "invalid style file: {}", message),
+            StyleFileError::UnsupportedFormat => write!(f, "unsupported style file format"),
+            StyleFileError::UnknownColormap(name) => write!(f, "unknown colormap \"{}\"", name),
+        }
+    }
+}
+
+impl Error for StyleFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StyleFileError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the contents of a style file, choosing the format by the extension of `path`.
+fn parse_style_file(path: &Path, text: &str) -> Result<StyleFile, StyleFileError> {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => {
+            serde_json::from_str(text).map_err(|error| StyleFileError::Parse(error.to_string()))
+        }
+        #[cfg(feature = "style-toml")]
+        Some("toml") => {
+            toml::from_str(text).map_err(|error| StyleFileError::Parse(error.to_string()))
+        }
+        _ => Err(StyleFileError::UnsupportedFormat),
+    }
+}
+
+/// Applies the style in a file, and applies it again whenever the file is modified.
+pub struct StyleWatcher {
+    path: PathBuf,
+    /// How often to check the modification time of the file
+    interval: Duration,
+    last_check: Option<Instant>,
+    /// Modification time of the file when it was last loaded
+    modified: Option<SystemTime>,
+}
+
+impl StyleWatcher {
+    /// Watch the given file. Nothing is loaded until [`StyleWatcher::poll`] is called.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            interval: Duration::from_millis(500),
+            last_check: None,
+            modified: None,
+        }
+    }
+
+    /// Set how often the modification time of the file is checked. Defaults to half a second.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The watched file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load and apply the file if it was modified since it was last loaded, or was never
+    /// loaded. Call this every frame; the file system is only checked once per interval.
+    /// Returns whether the style was applied. If loading fails, the current style is kept. An
+    /// error from checking the modification time, e.g. because the file does not exist, is
+    /// returned on every check; any other error is returned once, until the file is modified
+    /// again.
+    pub fn poll(&mut self, plot_ui: &PlotUi) -> Result<bool, StyleFileError> {
+        let now = Instant::now();
+        if let Some(last_check) = self.last_check {
+            if now.duration_since(last_check) < self.interval {
+                return Ok(false);
+            }
+        }
+        self.last_check = Some(now);
+
+        let modified = fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(StyleFileError::Io)?;
+        if self.modified == Some(modified) {
+            return Ok(false);
+        }
+        self.modified = Some(modified);
+        self.reload(plot_ui).map(|_| true)
+    }
+
+    /// Load and apply the file now, regardless of whether it was modified.
+    pub fn reload(&mut self, plot_ui: &PlotUi) -> Result<(), StyleFileError> {
+        let text = fs::read_to_string(&self.path).map_err(StyleFileError::Io)?;
+        let file = parse_style_file(&self.path, &text)?;
+
+        for definition in &file.colormaps {
+            if plot_ui.get_colormap_index(&definition.name).is_none() {
+                plot_ui.add_colormap(definition);
+            }
+        }
+        let mut style = file.style;
+        if let Some(name) = &file.colormap {
+            style.set_colormap(
+                plot_ui
+                    .get_colormap_index(name)
+                    .ok_or_else(|| StyleFileError::UnknownColormap(name.clone()))?,
+            );
+        } else if !(0..plot_ui.get_colormap_count()).contains(&style.colormap) {
+            return Err(StyleFileError::UnknownColormap(style.colormap.to_string()));
+        }
+        plot_ui.set_style(&style);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_style_file() {
+        let style: Style = unsafe { std::mem::zeroed() };
+        let text = format!(
+            r#"{{ "style": {}, "colormap": "brand" }}"#,
+            serde_json::to_string(&style).unwrap()
+        );
+        let file = parse_style_file(Path::new("style.json"), &text).unwrap();
+        assert!(file.colormaps.is_empty());
+        assert_eq!(file.colormap.as_deref(), Some("brand"));
+
+        assert!(matches!(
+            parse_style_file(Path::new("style.json"), "{}"),
+            Err(StyleFileError::Parse(_))
+        ));
+        assert!(matches!(
+            parse_style_file(Path::new("style.yaml"), &text),
+            Err(StyleFileError::UnsupportedFormat)
+        ));
+    }
+}
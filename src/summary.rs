@@ -0,0 +1,252 @@
+//! # Summary module
+//!
+//! This module generates plain-text summaries of plot content: the series, their visible value
+//! ranges, latest values and trend directions. They are computed from the same data handed to
+//! the plot elements, and are meant for screen readers, logging or tests:
+//! ```no_run
+//! # use implot::{Context, Plot, PlotLine, PlotSummary};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! # let (x, y) = (vec![0.0, 1.0, 2.0], vec![10.0, 11.5, 12.0]);
+//! Plot::new("Prices").build(&plot_ui, |plot| {
+//!     PlotLine::new("price").plot(&x, &y);
+//!     let summary = PlotSummary::new("Prices")
+//!         .with_limits(plot.get_plot_limits(None, None))
+//!         .with_series("price", &x, &y);
+//!     // e.g. "Prices: x from 0 to 2, y from 9 to 13. price: 3 points, ..., rising."
+//!     println!("{}", summary);
+//! });
+//! ```
+
+use crate::{ImPlotRange, ImPlotRect};
+use std::fmt;
+
+/// Overall direction of a series, based on a least-squares fit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Flat,
+}
+
+/// Fraction of the value range a fitted line has to change by to not count as flat.
+const FLAT_TREND_THRESHOLD: f64 = 0.05;
+
+/// Summary of one series, see [`PlotSummary`].
+#[derive(Clone, Debug)]
+pub struct SeriesSummary {
+    pub name: String,
+    /// Number of points taken into account
+    pub points: usize,
+    /// Range of the values, `None` if there are no points
+    pub y_range: Option<ImPlotRange>,
+    /// Last point, as `(x, y)`
+    pub latest: Option<(f64, f64)>,
+    pub trend: Trend,
+}
+
+impl SeriesSummary {
+    /// Summarize the given data, only taking points with an X value in `x_range` into account
+    /// if one is given. Points with NaN coordinates are skipped.
+    pub fn new(name: &str, x: &[f64], y: &[f64], x_range: Option<ImPlotRange>) -> Self {
+        let points: Vec<(f64, f64)> = x
+            .iter()
+            .zip(y.iter())
+            .map(|(x, y)| (*x, *y))
+            .filter(|(x, y)| !x.is_nan() && !y.is_nan())
+            .filter(|(x, _)| x_range.map_or(true, |range| range.contains(*x)))
+            .collect();
+
+        let y_range = points
+            .iter()
+            .fold(None, |range: Option<ImPlotRange>, (_, y)| {
+                Some(range.map_or(ImPlotRange::new(*y, *y), |range| {
+                    range.union(&ImPlotRange::new(*y, *y))
+                }))
+            });
+
+        Self {
+            name: name.to_owned(),
+            points: points.len(),
+            y_range,
+            latest: points.last().copied(),
+            trend: trend(&points, y_range),
+        }
+    }
+}
+
+fn trend(points: &[(f64, f64)], y_range: Option<ImPlotRange>) -> Trend {
+    let y_size = y_range.map_or(0.0, |range| range.size());
+    if points.len() < 2 || y_size == 0.0 {
+        return Trend::Flat;
+    }
+    let count = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let (mut covariance, mut variance) = (0.0, 0.0);
+    for (x, y) in points {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance += (x - mean_x) * (x - mean_x);
+    }
+    if variance == 0.0 {
+        return Trend::Flat;
+    }
+    let (x_min, x_max) = points
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), (x, _)| {
+            (min.min(*x), max.max(*x))
+        });
+    // Change of the fitted line over the data, relative to the spread of the values
+    let change = covariance / variance * (x_max - x_min) / y_size;
+    if change > FLAT_TREND_THRESHOLD {
+        Trend::Rising
+    } else if change < -FLAT_TREND_THRESHOLD {
+        Trend::Falling
+    } else {
+        Trend::Flat
+    }
+}
+
+/// Textual summary of a plot, built up from the data of its series. Formatting it with `{}`
+/// gives a short description in plain English.
+#[derive(Clone, Debug)]
+pub struct PlotSummary {
+    title: String,
+    limits: Option<ImPlotRect>,
+    series: Vec<SeriesSummary>,
+}
+
+impl PlotSummary {
+    /// Start a summary of the plot with the given title. Anything after a "##" in the title is
+    /// left out, as it is in the plot itself.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.split("##").next().unwrap_or_default().to_owned(),
+            limits: None,
+            series: Vec::new(),
+        }
+    }
+
+    /// Set the visible area of the plot, e.g. from
+    /// [`PlotToken::get_plot_limits`](crate::PlotToken::get_plot_limits). Series added
+    /// afterwards only take the points with visible X values into account.
+    pub fn with_limits(mut self, limits: ImPlotRect) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
+    /// Add a series, given the same data as the plot element drawing it.
+    pub fn with_series(mut self, name: &str, x: &[f64], y: &[f64]) -> Self {
+        let x_range = self.limits.map(|limits| limits.X);
+        self.series.push(SeriesSummary::new(name, x, y, x_range));
+        self
+    }
+
+    /// Summaries of the series added so far.
+    pub fn series(&self) -> &[SeriesSummary] {
+        &self.series
+    }
+}
+
+/// Formats values compactly, with at most three decimals.
+fn format_value(value: f64) -> String {
+    let formatted = format!("{:.3}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" => "0".to_owned(),
+        _ => trimmed.to_owned(),
+    }
+}
+
+impl fmt::Display for PlotSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.title.is_empty() {
+            write!(f, "Plot")?;
+        } else {
+            write!(f, "{}", self.title)?;
+        }
+        if let Some(limits) = &self.limits {
+            write!(
+                f,
+                ": x from {} to {}, y from {} to {}",
+                format_value(limits.X.Min),
+                format_value(limits.X.Max),
+                format_value(limits.Y.Min),
+                format_value(limits.Y.Max)
+            )?;
+        }
+        write!(f, ".")?;
+        if self.series.is_empty() {
+            return write!(f, " No data.");
+        }
+
+        for series in &self.series {
+            write!(f, " {}: ", series.name)?;
+            let (y_range, (latest_x, latest_y)) = match (series.y_range, series.latest) {
+                (Some(y_range), Some(latest)) => (y_range, latest),
+                _ => {
+                    write!(f, "no visible points.")?;
+                    continue;
+                }
+            };
+            let trend = match series.trend {
+                Trend::Rising => "rising",
+                Trend::Falling => "falling",
+                Trend::Flat => "flat",
+            };
+            write!(
+                f,
+                "{} point{}, values from {} to {}, latest {} at x = {}, {}.",
+                series.points,
+                if series.points == 1 { "" } else { "s" },
+                format_value(y_range.Min),
+                format_value(y_range.Max),
+                format_value(latest_y),
+                format_value(latest_x),
+                trend
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plot_summary_text() {
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let rising = [1.0, 2.0, 2.5, 4.0, 5.0];
+        let falling = [5.0, 4.0, 3.0, 2.0, f64::NAN];
+        let summary = PlotSummary::new("Prices##unique")
+            .with_limits(ImPlotRect::new([0.0, 3.0], [0.0, 6.0]))
+            .with_series("a", &x, &rising)
+            .with_series("b", &x, &falling)
+            .with_series("c", &[], &[]);
+
+        assert_eq!(summary.series()[0].points, 4);
+        assert_eq!(summary.series()[0].trend, Trend::Rising);
+        assert_eq!(summary.series()[1].trend, Trend::Falling);
+        assert_eq!(
+            summary.to_string(),
+            "Prices: x from 0 to 3, y from 0 to 6. \
+             a: 4 points, values from 1 to 4, latest 4 at x = 3, rising. \
+             b: 4 points, values from 2 to 5, latest 2 at x = 3, falling. \
+             c: no visible points."
+        );
+    }
+
+    #[test]
+    fn test_flat_trend() {
+        let summary = SeriesSummary::new(
+            "noise",
+            &[0.0, 1.0, 2.0, 3.0],
+            &[1.0, -1.0, -1.0, 1.0],
+            None,
+        );
+        assert_eq!(summary.trend, Trend::Flat);
+        assert_eq!(format_value(-0.0001), "0");
+        assert_eq!(format_value(2.5), "2.5");
+    }
+}
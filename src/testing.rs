@@ -0,0 +1,201 @@
+//! # Testing module
+//!
+//! This module (enabled by the `test-support` feature) runs imgui and implot frames without a
+//! window or renderer and captures the geometry they produce, so plotting code can be covered by
+//! regression tests. Nothing is rasterized: the captured draw data is what a renderer would be
+//! handed, and comparing it between runs shows whether plot calls still produce the same output:
+//! ```no_run
+//! # use implot::{testing::Harness, Plot, PlotLine};
+//! let mut harness = Harness::new([400.0, 300.0]);
+//! let capture = harness.frame(|_ui, plot_ui| {
+//!     Plot::new("Line").build(plot_ui, |_| {
+//!         PlotLine::new("values").plot(&[0.0, 1.0, 2.0], &[1.0, 0.0, 1.0]);
+//!     });
+//! });
+//! assert!(capture.vertex_count() > 0);
+//! // Compare against a value recorded in an earlier, known-good run
+//! println!("{:016x}", capture.fingerprint());
+//! ```
+//!
+//! Only one harness can exist at a time, since it creates both an imgui and an implot context.
+//! Fingerprints depend on the exact imgui and implot versions, the default font and the display
+//! size, so they are best recorded with the same versions the tests run against.
+
+use crate::{Context, PlotUi};
+use imgui::{Condition, DrawIdx, Ui};
+
+/// An offscreen imgui and implot setup for running frames in tests.
+pub struct Harness {
+    // Declared first so the implot context is destroyed before the imgui context
+    plot_context: Context,
+    imgui: imgui::Context,
+    display_size: [f32; 2],
+}
+
+impl Harness {
+    /// Create imgui and implot contexts with a display of the given size in pixels. The font
+    /// atlas is built but never uploaded, `imgui.ini` is neither read nor written, and the mouse
+    /// is kept outside the display, so frames do not depend on the environment.
+    ///
+    /// # Panics
+    /// Panics if an imgui or implot context is already active.
+    pub fn new(display_size: [f32; 2]) -> Self {
+        let mut imgui = imgui::Context::create();
+        imgui.set_ini_filename(None);
+        imgui.io_mut().display_size = display_size;
+        imgui.io_mut().delta_time = 1.0 / 60.0;
+        imgui.fonts().build_rgba32_texture();
+        let plot_context = Context::create();
+        Self {
+            plot_context,
+            imgui,
+            display_size,
+        }
+    }
+
+    /// The imgui context, e.g. for changing the style or feeding input events between frames.
+    pub fn imgui_mut(&mut self) -> &mut imgui::Context {
+        &mut self.imgui
+    }
+
+    /// The implot context, e.g. for changing the style between frames.
+    pub fn plot_context(&self) -> &Context {
+        &self.plot_context
+    }
+
+    /// Run one frame and capture the resulting draw data. `f` is called inside a window that
+    /// covers the whole display, so plots can be built in it directly.
+    pub fn frame<F: FnOnce(&Ui, &PlotUi)>(&mut self, f: F) -> FrameCapture {
+        let plot_ui = self.plot_context.get_plot_ui();
+        let ui = self.imgui.new_frame();
+        ui.window("##implot-test-harness")
+            .position([0.0, 0.0], Condition::Always)
+            .size(self.display_size, Condition::Always)
+            .build(|| f(ui, &plot_ui));
+        let draw_data = self.imgui.render();
+        FrameCapture {
+            draw_lists: draw_data
+                .draw_lists()
+                .map(|draw_list| DrawListCapture {
+                    positions: draw_list.vtx_buffer().iter().map(|v| v.pos).collect(),
+                    colors: draw_list.vtx_buffer().iter().map(|v| v.col).collect(),
+                    indices: draw_list.idx_buffer().to_vec(),
+                    command_count: draw_list.commands().count(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The geometry of one frame, as returned by [`Harness::frame`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameCapture {
+    /// The draw lists of the frame, in rendering order.
+    pub draw_lists: Vec<DrawListCapture>,
+}
+
+/// The geometry of one imgui draw list.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawListCapture {
+    /// Vertex positions, in pixels.
+    pub positions: Vec<[f32; 2]>,
+    /// Vertex colors, as RGBA bytes.
+    pub colors: Vec<[u8; 4]>,
+    /// Indices into the vertices, three per triangle.
+    pub indices: Vec<DrawIdx>,
+    /// Number of draw commands.
+    pub command_count: usize,
+}
+
+impl FrameCapture {
+    /// Total number of vertices over all draw lists.
+    pub fn vertex_count(&self) -> usize {
+        self.draw_lists
+            .iter()
+            .map(|list| list.positions.len())
+            .sum()
+    }
+
+    /// Total number of triangles over all draw lists.
+    pub fn triangle_count(&self) -> usize {
+        self.draw_lists
+            .iter()
+            .map(|list| list.indices.len() / 3)
+            .sum()
+    }
+
+    /// Whether the frame produced no geometry at all.
+    pub fn is_empty(&self) -> bool {
+        self.vertex_count() == 0
+    }
+
+    /// A 64-bit FNV-1a hash of all positions, colors and indices. Unlike `std`'s hashers, it
+    /// does not change between Rust versions, so it can be stored in tests as a snapshot of the
+    /// frame.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hash = Fnv1a::new();
+        for list in &self.draw_lists {
+            hash.write(&(list.positions.len() as u64).to_le_bytes());
+            for position in &list.positions {
+                hash.write(&position[0].to_bits().to_le_bytes());
+                hash.write(&position[1].to_bits().to_le_bytes());
+            }
+            for color in &list.colors {
+                hash.write(color);
+            }
+            hash.write(&(list.indices.len() as u64).to_le_bytes());
+            for &index in &list.indices {
+                hash.write(&u64::from(index).to_le_bytes());
+            }
+        }
+        hash.0
+    }
+}
+
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a_reference_values() {
+        let hash = |bytes: &[u8]| {
+            let mut hash = Fnv1a::new();
+            hash.write(bytes);
+            hash.0
+        };
+        assert_eq!(hash(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(hash(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_fingerprint_depends_on_geometry() {
+        let capture = |x| FrameCapture {
+            draw_lists: vec![DrawListCapture {
+                positions: vec![[0.0, 0.0], [x, 0.0], [0.0, 1.0]],
+                colors: vec![[255; 4]; 3],
+                indices: vec![0, 1, 2],
+                command_count: 1,
+            }],
+        };
+        assert_eq!(capture(1.0).fingerprint(), capture(1.0).fingerprint());
+        assert_ne!(capture(1.0).fingerprint(), capture(2.0).fingerprint());
+        assert_eq!(capture(1.0).triangle_count(), 1);
+        assert!(!capture(1.0).is_empty());
+    }
+}
@@ -0,0 +1,147 @@
+//! # Themes module
+//!
+//! This module contains complete themes for plots: each function sets up the current style
+//! (colors, line weights, grid) and registers a matching discrete colormap for the items, which
+//! it makes the current one. Call one of them once after creating the context:
+//! ```no_run
+//! # use implot::Context;
+//! let context = Context::create();
+//! let plot_ui = context.get_plot_ui();
+//! implot::themes::colorblind_safe(&plot_ui);
+//! ```
+//!
+//! The colormaps are registered under names starting with `implot-rs`, and are only added the
+//! first time a theme is applied, so switching between themes at runtime is fine.
+
+use crate::{ImVec4, PlotColorElement, PlotUi};
+
+/// Convert a color given as `0xRRGGBB` to an opaque `ImVec4`.
+fn rgb(hex: u32) -> ImVec4 {
+    let channel = |shift: u32| ((hex >> shift) & 0xff) as f32 / 255.0;
+    ImVec4 {
+        x: channel(16),
+        y: channel(8),
+        z: channel(0),
+        w: 1.0,
+    }
+}
+
+/// Same as [`rgb`], with the given alpha.
+fn rgba(hex: u32, alpha: f32) -> ImVec4 {
+    ImVec4 {
+        w: alpha,
+        ..rgb(hex)
+    }
+}
+
+/// Register a discrete colormap with the given name unless it exists already, and make it the
+/// colormap of the current style.
+fn use_colormap(plot_ui: &PlotUi, name: &str, colors: &[u32]) {
+    let colormap = plot_ui.get_colormap_index(name).unwrap_or_else(|| {
+        plot_ui.add_colormap_from_vec(name, colors.iter().map(|&hex| rgb(hex)).collect(), true)
+    });
+    let mut style = plot_ui.style();
+    style.set_colormap(colormap);
+    plot_ui.set_style(&style);
+}
+
+/// Line and marker sizes shared by the regular themes.
+fn default_sizes(plot_ui: &PlotUi) {
+    let mut style = plot_ui.style();
+    style.line_weight = 1.5;
+    style.marker_size = 4.0;
+    style.fill_alpha = 0.5;
+    style.minor_alpha = 0.25;
+    style.plot_border_size = 1.0;
+    plot_ui.set_style(&style);
+}
+
+/// A dark theme, following imgui's colors for frames and text, with bright item colors that
+/// stand out on dark backgrounds.
+pub fn dark(plot_ui: &PlotUi) {
+    plot_ui.style_colors_dark(None);
+    default_sizes(plot_ui);
+    use_colormap(
+        plot_ui,
+        "implot-rs dark",
+        &[
+            0x4c9be8, 0xf28e2b, 0x59c26b, 0xe15759, 0xb07aa1, 0xedc948, 0x76b7b2, 0xff9da7,
+        ],
+    );
+}
+
+/// A light theme, following imgui's colors for frames and text, with the Tableau 10 item
+/// colors.
+pub fn light(plot_ui: &PlotUi) {
+    plot_ui.style_colors_light(None);
+    default_sizes(plot_ui);
+    use_colormap(
+        plot_ui,
+        "implot-rs light",
+        &[
+            0x4e79a7, 0xf28e2b, 0xe15759, 0x76b7b2, 0x59a14f, 0xedc948, 0xb07aa1, 0xff9da7,
+            0x9c755f, 0xbab0ac,
+        ],
+    );
+}
+
+/// A high-contrast theme: white text, grid and borders on black, thick lines, and saturated
+/// item colors, e.g. for projectors or users with low vision.
+pub fn high_contrast(plot_ui: &PlotUi) {
+    plot_ui.style_colors_dark(None);
+    let mut style = plot_ui.style();
+    style.line_weight = 2.5;
+    style.marker_size = 5.0;
+    style.fill_alpha = 0.6;
+    style.minor_alpha = 0.5;
+    style.plot_border_size = 2.0;
+    let (black, white) = (rgb(0x000000), rgb(0xffffff));
+    style[PlotColorElement::FrameBg] = black;
+    style[PlotColorElement::PlotBg] = black;
+    style[PlotColorElement::PlotBorder] = white;
+    style[PlotColorElement::LegendBg] = black;
+    style[PlotColorElement::LegendBorder] = white;
+    style[PlotColorElement::LegendText] = white;
+    style[PlotColorElement::TitleText] = white;
+    style[PlotColorElement::InlayText] = white;
+    style[PlotColorElement::AxisText] = white;
+    style[PlotColorElement::AxisGrid] = rgba(0xffffff, 0.5);
+    style[PlotColorElement::AxisTick] = white;
+    style[PlotColorElement::Selection] = rgb(0xffff00);
+    style[PlotColorElement::Crosshairs] = white;
+    plot_ui.set_style(&style);
+    use_colormap(
+        plot_ui,
+        "implot-rs high contrast",
+        &[0xffff00, 0x00ffff, 0xff00ff, 0x00ff00, 0xff8000, 0xffffff],
+    );
+}
+
+/// A light theme with the Okabe-Ito palette as item colors, which stays distinguishable with
+/// the common forms of color blindness.
+pub fn colorblind_safe(plot_ui: &PlotUi) {
+    plot_ui.style_colors_light(None);
+    default_sizes(plot_ui);
+    use_colormap(
+        plot_ui,
+        "implot-rs okabe-ito",
+        &[
+            0xe69f00, 0x56b4e9, 0x009e73, 0xf0e442, 0x0072b2, 0xd55e00, 0xcc79a7, 0x000000,
+        ],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb() {
+        let color = rgb(0xff8000);
+        assert_eq!(
+            (color.x, color.y, color.z, color.w),
+            (1.0, 128.0 / 255.0, 0.0, 1.0)
+        );
+        assert_eq!(rgba(0x000000, 0.5).w, 0.5);
+    }
+}
@@ -0,0 +1,265 @@
+//! # Time module
+//!
+//! This module contains the date and time picker widgets ImPlot uses internally for time axes,
+//! exposed so that time-range selection UIs next to time-axis plots can use the same pickers.
+//! Times are given as [`ImPlotTime`], which converts to and from the seconds since the Unix
+//! epoch used for values on time axes.
+//!
+//! It also has helpers for time axes themselves, i.e. axes with the [`AxisScale::Time`] scale,
+//! which show values as dates and times:
+//! ```no_run
+//! # use implot::{AxisChoice, Context, Plot, PlotLine, TimeTickUnit, unix_timestamps};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! # let (timestamps, prices) = (vec![1_600_000_000i64, 1_600_086_400], vec![1.0, 2.0]);
+//! let x = unix_timestamps(&timestamps);
+//! Plot::new("Prices")
+//!     .with_time_axis(AxisChoice::X1)
+//!     .time_ticks(AxisChoice::X1, x[0], x[x.len() - 1], TimeTickUnit::Day, 1)
+//!     .build(&plot_ui, |_| {
+//!         PlotLine::new("price").plot(&x, &prices);
+//!     });
+//! ```
+
+use crate::{sys, AxisChoice, AxisScale, ImPlotTime, Plot, PlotUi};
+use std::ffi::CString;
+
+/// Which level of detail a date picker currently shows. Clicking the header of the picker
+/// zooms out a level, picking an entry zooms back in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DatePickerLevel {
+    /// Days of a month
+    Day,
+    /// Months of a year
+    Month,
+    /// Years of a range of years
+    Year,
+}
+
+impl Default for DatePickerLevel {
+    fn default() -> Self {
+        DatePickerLevel::Day
+    }
+}
+
+impl<'ui> PlotUi<'ui> {
+    /// Show a calendar style date picker. `level` is the state of the picker and has to be
+    /// kept between frames. If `range` is given, dates outside of it are shown as disabled.
+    /// Returns true if a date was picked this frame, in which case `time` holds the new date.
+    ///
+    /// # Panics
+    /// Will panic if the ID string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "ShowDatePicker"))]
+    pub fn show_date_picker(
+        &self,
+        id: &str,
+        level: &mut DatePickerLevel,
+        time: &mut ImPlotTime,
+        range: Option<(ImPlotTime, ImPlotTime)>,
+    ) -> bool {
+        let id = CString::new(id)
+            .unwrap_or_else(|_| panic!("ID string has internal null bytes: {}", id));
+        let mut raw_level = match level {
+            DatePickerLevel::Day => 0,
+            DatePickerLevel::Month => 1,
+            DatePickerLevel::Year => 2,
+        };
+        let (start, end) = match &range {
+            Some((start, end)) => (start as *const ImPlotTime, end as *const ImPlotTime),
+            None => (std::ptr::null(), std::ptr::null()),
+        };
+        let picked =
+            unsafe { sys::ImPlot_ShowDatePicker(id.as_ptr(), &mut raw_level, time, start, end) };
+        *level = match raw_level {
+            1 => DatePickerLevel::Month,
+            2 => DatePickerLevel::Year,
+            _ => DatePickerLevel::Day,
+        };
+        picked
+    }
+
+    /// Show a time of day picker. Returns true if the time was changed this frame, in which
+    /// case `time` holds the new time. The date part of `time` is kept.
+    ///
+    /// # Panics
+    /// Will panic if the ID string contains internal null bytes.
+    #[rustversion::attr(since(1.48), doc(alias = "ShowTimePicker"))]
+    pub fn show_time_picker(&self, id: &str, time: &mut ImPlotTime) -> bool {
+        let id = CString::new(id)
+            .unwrap_or_else(|_| panic!("ID string has internal null bytes: {}", id));
+        unsafe { sys::ImPlot_ShowTimePicker(id.as_ptr(), time) }
+    }
+}
+
+/// Granularity of the ticks placed by [`Plot::time_ticks`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeTickUnit {
+    /// Ticks at midnight
+    Day,
+    /// Ticks at midnight of the first day of a month
+    Month,
+}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Convert Unix timestamps in seconds to the values used on time axes. See
+/// [`unix_timestamps_into`] for reusing a buffer instead, e.g. when converting every frame.
+pub fn unix_timestamps(timestamps: &[i64]) -> Vec<f64> {
+    let mut values = Vec::with_capacity(timestamps.len());
+    unix_timestamps_into(timestamps, &mut values);
+    values
+}
+
+/// Convert Unix timestamps in seconds to the values used on time axes, replacing the contents
+/// of `values`. Its allocation is reused, so no allocations happen once it has grown.
+pub fn unix_timestamps_into(timestamps: &[i64], values: &mut Vec<f64>) {
+    values.clear();
+    values.extend(timestamps.iter().map(|t| *t as f64));
+}
+
+/// Label of a tick placed by [`Plot::time_ticks`], as an ISO 8601 date in UTC.
+fn time_tick_label(position: f64, unit: TimeTickUnit) -> String {
+    let (year, month, day) = civil_from_days((position / SECONDS_PER_DAY as f64).floor() as i64);
+    match unit {
+        TimeTickUnit::Day => format!("{:04}-{:02}-{:02}", year, month, day),
+        TimeTickUnit::Month => format!("{:04}-{:02}", year, month),
+    }
+}
+
+/// Positions of the ticks between the Unix timestamps `start` and `end` (inclusive), placed
+/// every `step` days or months. Boundaries are in UTC.
+pub fn time_tick_positions(start: f64, end: f64, unit: TimeTickUnit, step: u32) -> Vec<f64> {
+    let step = i64::from(step.max(1));
+    if start.is_nan() || end.is_nan() || start > end {
+        return Vec::new();
+    }
+    let first_day = (start / SECONDS_PER_DAY as f64).ceil() as i64;
+    let last_day = (end / SECONDS_PER_DAY as f64).floor() as i64;
+    match unit {
+        TimeTickUnit::Day => (first_day..=last_day)
+            .step_by(step as usize)
+            .map(|day| (day * SECONDS_PER_DAY) as f64)
+            .collect(),
+        TimeTickUnit::Month => {
+            let (year, month, day) = civil_from_days(first_day);
+            // Months are counted from year 0 to step through them easily
+            let mut months = year * 12 + i64::from(month) - 1;
+            if day != 1 {
+                months += 1;
+            }
+            let mut positions = Vec::new();
+            loop {
+                let day =
+                    days_from_civil(months.div_euclid(12), (months.rem_euclid(12) + 1) as u32);
+                if day > last_day {
+                    break;
+                }
+                positions.push((day * SECONDS_PER_DAY) as f64);
+                months += step;
+            }
+            positions
+        }
+    }
+}
+
+// Conversions between days since the Unix epoch and dates in the proleptic Gregorian
+// calendar, after http://howardhinnant.github.io/date_algorithms.html
+
+/// Days since the Unix epoch of the first day of the given month.
+fn days_from_civil(year: i64, month: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Year, month and day of the given day since the Unix epoch.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = (if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    }) as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+impl Plot {
+    /// Show the values of the given axis as dates and times, interpreting them as seconds
+    /// since the Unix epoch. Same as setting the [`AxisScale::Time`] scale.
+    #[inline]
+    pub fn with_time_axis(self, axis_choice: AxisChoice) -> Self {
+        self.with_axis_scale(axis_choice, &AxisScale::Time)
+    }
+
+    /// Place the ticks of a time axis every `step` days or months between the Unix timestamps
+    /// `start` and `end`, instead of letting ImPlot choose them. See [`time_tick_positions`]
+    /// for their positions. The ticks are labeled with their ISO 8601 date in UTC, e.g.
+    /// `2021-02-01`, or `2021-02` for months, regardless of the time settings of the style.
+    pub fn time_ticks(
+        self,
+        axis_choice: AxisChoice,
+        start: f64,
+        end: f64,
+        unit: TimeTickUnit,
+        step: u32,
+    ) -> Self {
+        let tick_labels: Vec<(f64, String)> = time_tick_positions(start, end, unit, step)
+            .into_iter()
+            .map(|position| (position, time_tick_label(position, unit)))
+            .collect();
+        self.axis_ticks_with_labels(axis_choice, &tick_labels, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_conversions() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(days_from_civil(1970, 1), 0);
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(days_from_civil(2024, 3) - 1), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_time_tick_positions() {
+        // 2021-01-15 12:00 to 2021-04-02 00:00 UTC
+        let (start, end) = (1_610_712_000.0, 1_617_321_600.0);
+        let months = time_tick_positions(start, end, TimeTickUnit::Month, 1);
+        assert_eq!(
+            months,
+            vec![1_612_137_600.0, 1_614_556_800.0, 1_617_235_200.0]
+        );
+
+        let days = time_tick_positions(start, start + 3.0 * 86_400.0, TimeTickUnit::Day, 2);
+        assert_eq!(days, vec![1_610_755_200.0, 1_610_928_000.0]);
+        assert!(time_tick_positions(end, start, TimeTickUnit::Day, 1).is_empty());
+    }
+
+    #[test]
+    fn test_time_tick_labels() {
+        assert_eq!(
+            time_tick_label(1_612_137_600.0, TimeTickUnit::Day),
+            "2021-02-01"
+        );
+        assert_eq!(
+            time_tick_label(1_612_137_600.0, TimeTickUnit::Month),
+            "2021-02"
+        );
+        assert_eq!(time_tick_label(-1.0, TimeTickUnit::Day), "1969-12-31");
+    }
+}
@@ -15,7 +15,7 @@ macro_rules! create_token {
         $v:vis struct $token_name:ident<'ui>;
 
         $(#[$end_meta:meta])*
-        drop { $on_drop:expr }
+        drop $on_drop:block
     ) => {
         #[must_use]
         $(#[$struct_meta])*
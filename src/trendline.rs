@@ -0,0 +1,297 @@
+//! # Trendline module
+//!
+//! This module fits least-squares lines or polynomials to data and draws them as dashed
+//! overlays. Only the points within the visible X range are fitted, so zooming into a region
+//! shows the trend of that region. The fit is returned, e.g. for annotating the plot with it:
+//! ```no_run
+//! # use implot::{Context, Plot, PlotScatter, PlotTrendline};
+//! # let context = Context::create();
+//! # let plot_ui = context.get_plot_ui();
+//! # let (x, y) = (vec![0.0, 1.0, 2.0, 3.0], vec![0.1, 0.9, 2.2, 2.9]);
+//! Plot::new("Measurements").build(&plot_ui, |_| {
+//!     PlotScatter::new("samples").plot(&x, &y);
+//!     if let Some(fit) = PlotTrendline::new("trend").plot(&x, &y) {
+//!         let (intercept, slope) = (fit.coefficients[0], fit.coefficients[1]);
+//!         // Show "y = {slope} x + {intercept}, R² = {fit.r_squared}" somewhere
+//!     }
+//! });
+//! ```
+
+use crate::{sys, ImPlotPoint, ImPlotRect, ImVec2, PlotLine, PlotLineFlags, IMPLOT_AUTO};
+use std::cmp::Ordering;
+
+/// A polynomial fitted to data with [`fit_polynomial`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolynomialFit {
+    /// Coefficients of the polynomial, lowest degree first, so a line is
+    /// `[intercept, slope]`.
+    pub coefficients: Vec<f64>,
+    /// Coefficient of determination, 1 for a perfect fit.
+    pub r_squared: f64,
+    /// The fit is computed and evaluated in `(x - center) / scale`, which keeps it accurate
+    /// for X values far from zero, e.g. timestamps.
+    centered: Vec<f64>,
+    center: f64,
+    scale: f64,
+}
+
+impl PolynomialFit {
+    /// Value of the polynomial at `x`.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let t = (x - self.center) / self.scale;
+        self.centered
+            .iter()
+            .rev()
+            .fold(0.0, |value, coefficient| value * t + coefficient)
+    }
+}
+
+/// Solve the linear system `matrix * x = rhs` with Gaussian elimination and partial pivoting.
+/// `None` if the system is (close to) singular.
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let size = rhs.len();
+    let tolerance = 1e-12
+        * matrix
+            .iter()
+            .flatten()
+            .fold(0.0f64, |max, value| max.max(value.abs()));
+    for column in 0..size {
+        let pivot = (column..size).max_by(|&a, &b| {
+            let (a, b) = (matrix[a][column].abs(), matrix[b][column].abs());
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        })?;
+        if matrix[pivot][column].abs() <= tolerance {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+        for row in column + 1..size {
+            let factor = matrix[row][column] / matrix[column][column];
+            let (pivot_rows, rows) = matrix.split_at_mut(row);
+            for (value, pivot_value) in rows[0][column..]
+                .iter_mut()
+                .zip(&pivot_rows[column][column..])
+            {
+                *value -= factor * pivot_value;
+            }
+            rhs[row] -= factor * rhs[column];
+        }
+    }
+    let mut solution = vec![0.0; size];
+    for row in (0..size).rev() {
+        let known: f64 = (row + 1..size).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (rhs[row] - known) / matrix[row][row];
+    }
+    Some(solution)
+}
+
+/// Binomial coefficient `n` choose `k`.
+fn binomial(n: usize, k: usize) -> f64 {
+    (0..k).fold(1.0, |value, i| value * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Fit a polynomial of the given degree (1 for a line) to the points `(xs[i], ys[i])` by least
+/// squares. Returns `None` if there are not enough distinct X values to determine it. High
+/// degrees are numerically fragile, so prefer degrees of at most 3 or 4.
+pub fn fit_polynomial(xs: &[f64], ys: &[f64], degree: usize) -> Option<PolynomialFit> {
+    let count = xs.len().min(ys.len());
+    let (xs, ys) = (&xs[..count], &ys[..count]);
+    if count <= degree {
+        return None;
+    }
+
+    let (x_min, x_max) = xs
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
+            (min.min(*x), max.max(*x))
+        });
+    let center = (x_min + x_max) / 2.0;
+    let scale = if x_max > x_min {
+        (x_max - x_min) / 2.0
+    } else {
+        1.0
+    };
+
+    // Normal equations: sums of t^(i + j) and of y * t^i
+    let size = degree + 1;
+    let mut power_sums = vec![0.0; 2 * degree + 1];
+    let mut rhs = vec![0.0; size];
+    for (x, y) in xs.iter().zip(ys) {
+        let t = (x - center) / scale;
+        let mut power = 1.0;
+        for (i, sum) in power_sums.iter_mut().enumerate() {
+            *sum += power;
+            if i < size {
+                rhs[i] += y * power;
+            }
+            power *= t;
+        }
+    }
+    let matrix = (0..size)
+        .map(|i| power_sums[i..i + size].to_vec())
+        .collect();
+    let centered = solve(matrix, rhs)?;
+
+    // Expand sum(b_k * ((x - center) / scale)^k) into powers of x
+    let mut coefficients = vec![0.0; size];
+    for (k, b) in centered.iter().enumerate() {
+        let b = b / scale.powi(k as i32);
+        for (j, coefficient) in coefficients.iter_mut().enumerate().take(k + 1) {
+            *coefficient += b * binomial(k, j) * (-center).powi((k - j) as i32);
+        }
+    }
+
+    let mut fit = PolynomialFit {
+        coefficients,
+        r_squared: 1.0,
+        centered,
+        center,
+        scale,
+    };
+    let mean_y = ys.iter().sum::<f64>() / count as f64;
+    let (mut residual, mut total) = (0.0, 0.0);
+    for (x, y) in xs.iter().zip(ys) {
+        residual += (y - fit.evaluate(*x)).powi(2);
+        total += (y - mean_y).powi(2);
+    }
+    if total > 0.0 {
+        fit.r_squared = 1.0 - residual / total;
+    }
+    Some(fit)
+}
+
+/// Struct to provide functionality for drawing trendlines, i.e. least-squares fits of the
+/// visible data drawn as dashed lines across the visible X range.
+pub struct PlotTrendline {
+    /// Label to show in the legend for this trendline
+    label: String,
+    degree: usize,
+    /// Length of the dashes and of the gaps between them, in pixels
+    dash_length: f32,
+    color: Option<[f32; 4]>,
+    line_weight: Option<f32>,
+}
+
+impl PlotTrendline {
+    /// Create a new linear trendline to be shown. Does not draw anything by itself, call
+    /// [`PlotTrendline::plot`] on the struct for that.
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_owned(),
+            degree: 1,
+            dash_length: 6.0,
+            color: None,
+            line_weight: None,
+        }
+    }
+
+    /// Fit a polynomial of the given degree instead of a line, see [`fit_polynomial`].
+    pub fn with_degree(mut self, degree: usize) -> Self {
+        self.degree = degree;
+        self
+    }
+
+    /// Set the length of the dashes and of the gaps between them, in pixels. Defaults to 6.
+    pub fn with_dash_length(mut self, dash_length: f32) -> Self {
+        self.dash_length = dash_length;
+        self
+    }
+
+    /// Set the color of the trendline, instead of taking the next one from the colormap.
+    pub fn with_color(mut self, color: [f32; 4]) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the line weight of the trendline, in pixels.
+    pub fn with_line_weight(mut self, weight: f32) -> Self {
+        self.line_weight = Some(weight);
+        self
+    }
+
+    /// Fit the points `(xs[i], ys[i])` within the visible X range of the current axes and draw
+    /// the fit across that range. The trendline does not take part in fitting the axes. Use
+    /// this in closures passed to [`Plot::build()`](struct.Plot.html#method.build). Returns the
+    /// fit, or `None` if there are too few visible points for it.
+    ///
+    /// # Panics
+    /// Will panic if the label contains internal null bytes.
+    pub fn plot(&self, xs: &[f64], ys: &[f64]) -> Option<PolynomialFit> {
+        let mut limits = ImPlotRect::new([0.0, 0.0], [0.0, 0.0]);
+        let mut plot_size = ImVec2 { x: 0.0, y: 0.0 };
+        unsafe {
+            sys::ImPlot_GetPlotLimits(&mut limits, IMPLOT_AUTO, IMPLOT_AUTO);
+            sys::ImPlot_GetPlotSize(&mut plot_size);
+        }
+        let (visible_x, visible_y): (Vec<f64>, Vec<f64>) = xs
+            .iter()
+            .zip(ys)
+            .filter(|(x, y)| limits.X.contains(**x) && y.is_finite())
+            .unzip();
+        let fit = fit_polynomial(&visible_x, &visible_y, self.degree)?;
+
+        // Each dash is drawn as one segment, followed by a gap of the same length
+        let period = 2.0 * self.dash_length.max(1.0) as f64;
+        let dashes = (plot_size.x as f64 / period).ceil().max(1.0) as usize;
+        let period_x = limits.X.size() / (plot_size.x as f64 / period).max(1.0);
+        let mut line = PlotLine::new(&self.label).with_flags(
+            PlotLineFlags::SEGMENTS | sys::ImPlotLineFlags_(sys::ImPlotItemFlags_::NO_FIT.0),
+        );
+        if let Some(color) = self.color {
+            line = line.with_color(color);
+        }
+        if let Some(weight) = self.line_weight {
+            line = line.with_line_weight(weight);
+        }
+        line.plot_with(2 * dashes, |index| {
+            let dash_start = limits.X.Min + (index / 2) as f64 * period_x;
+            let x = (dash_start + (index % 2) as f64 * period_x / 2.0).min(limits.X.Max);
+            ImPlotPoint {
+                x,
+                y: fit.evaluate(x),
+            }
+        });
+        Some(fit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_fit_line() {
+        let xs = [1.0, 2.0, 3.0, 4.0];
+        let ys: Vec<f64> = xs.iter().map(|x| 2.0 * x - 1.0).collect();
+        let fit = fit_polynomial(&xs, &ys, 1).unwrap();
+        assert_close(fit.coefficients[0], -1.0);
+        assert_close(fit.coefficients[1], 2.0);
+        assert_close(fit.r_squared, 1.0);
+        assert_close(fit.evaluate(10.0), 19.0);
+    }
+
+    #[test]
+    fn test_fit_parabola_far_from_zero() {
+        let xs: Vec<f64> = (0..10).map(|i| 1.0e6 + i as f64).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| (x - 1.0e6).powi(2) + 3.0).collect();
+        let fit = fit_polynomial(&xs, &ys, 2).unwrap();
+        assert_close(fit.evaluate(1.0e6 + 4.5), 4.5 * 4.5 + 3.0);
+        assert_close(fit.r_squared, 1.0);
+    }
+
+    #[test]
+    fn test_fit_needs_enough_points() {
+        assert!(fit_polynomial(&[1.0, 2.0], &[1.0, 2.0], 2).is_none());
+        assert!(fit_polynomial(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0], 1).is_none());
+    }
+
+    #[test]
+    fn test_r_squared_of_noisy_data() {
+        let fit = fit_polynomial(&[0.0, 1.0, 2.0, 3.0], &[0.0, 2.0, 1.0, 3.0], 1).unwrap();
+        assert!(fit.r_squared > 0.0 && fit.r_squared < 1.0);
+    }
+}
@@ -0,0 +1,57 @@
+//! Plotting through `#[derive(Plottable)]` from outside the crate, the way users of the `derive`
+//! feature do.
+#![cfg(all(feature = "derive", feature = "test-support"))]
+
+use implot::{testing::Harness, Plot, PlotLine, Plottable};
+
+#[derive(Plottable)]
+struct Sample {
+    #[plot]
+    time: f64,
+    #[plot]
+    speed: f64,
+    #[allow(dead_code)]
+    id: u32,
+}
+
+fn samples() -> Vec<Sample> {
+    (0..10)
+        .map(|id| Sample {
+            time: id as f64,
+            speed: (id as f64).sin(),
+            id,
+        })
+        .collect()
+}
+
+#[test]
+fn test_derived_fields() {
+    let samples = samples();
+    assert_eq!(Sample::plot_fields(), &["time", "speed"]);
+    assert!(Sample::plot_field(&samples, "id").is_none());
+
+    let speed = Sample::plot_field(&samples, "speed").unwrap();
+    assert_eq!(speed.stride(), std::mem::size_of::<Sample>());
+    assert!(speed.iter().eq(samples.iter().map(|sample| sample.speed)));
+    assert!(Sample::time_strided(&samples)
+        .iter()
+        .eq((0..10).map(f64::from)));
+}
+
+#[test]
+fn test_plot_derived_fields() {
+    let samples = samples();
+    let mut harness = Harness::new([400.0, 300.0]);
+    let empty = harness.frame(|_, plot_ui| {
+        Plot::new("Derived").build(plot_ui, |_| {});
+    });
+    let plotted = harness.frame(|_, plot_ui| {
+        Plot::new("Derived").build(plot_ui, |_| {
+            PlotLine::new("speed").plot_strided(
+                Sample::time_strided(&samples),
+                Sample::plot_field(&samples, "speed").unwrap(),
+            );
+        });
+    });
+    assert!(plotted.vertex_count() > empty.vertex_count());
+}